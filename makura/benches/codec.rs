@@ -0,0 +1,54 @@
+// encoding/decoding throughput of every base
+//
+// run with `cargo bench --bench codec`
+//
+// to compare against another implementation, e.g., a previous commit,
+// save its results with `cargo bench --bench codec -- --save-baseline <name>`
+// then run `cargo bench --bench codec -- --baseline <name>` on the new one
+//
+// the alphabet tables are compared against the match chains they replaced in `benches/lookup`
+use criterion::{BenchmarkId, Criterion, Throughput, black_box, criterion_group, criterion_main};
+use makura::{BASE16, BASE32, BASE32HEX, BASE45, BASE64, BASE64URL, Base};
+use makura::{Decoder, Encoder};
+
+const BASES: [Base; 6] = [BASE64, BASE64URL, BASE45, BASE32, BASE32HEX, BASE16];
+const SIZES: [usize; 2] = [64, 64 * 1024];
+
+fn input(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i * 31 + 7) as u8).collect()
+}
+
+fn encode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("encode");
+    for size in SIZES {
+        let input = input(size);
+        group.throughput(Throughput::Bytes(size as u64));
+        for base in BASES {
+            let enc = Encoder::from(base);
+            group.bench_with_input(BenchmarkId::new(base.to_string(), size), &input, |b, i| {
+                b.iter(|| enc.encode_bytes(black_box(i)))
+            });
+        }
+    }
+    group.finish();
+}
+
+fn decode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("decode");
+    for size in SIZES {
+        let input = input(size);
+        for base in BASES {
+            let encoded = Encoder::from(base).encode_bytes(&input);
+            group.throughput(Throughput::Bytes(encoded.len() as u64));
+            group.bench_with_input(
+                BenchmarkId::new(base.to_string(), size),
+                &encoded,
+                |b, e| b.iter(|| Decoder::decode(black_box(e), base).unwrap()),
+            );
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, encode, decode);
+criterion_main!(benches);
@@ -0,0 +1,315 @@
+// the match chain lookups that the alphabet tables replaced, kept here to compare both
+use makura::{Base, DecodeError};
+
+// first 26 values of base encoding table are the uppercase alphabet letters A -> Z
+fn alpha_26(base: &Base) -> bool {
+    base == &Base::_64 || base == &Base::_64URL || base == &Base::_32
+}
+
+// first 16 values in the base encoding table are the base 16 numbers 0 -> F
+fn hex_16(base: &Base) -> bool {
+    base == &Base::_32HEX || base == &Base::_16
+}
+
+// base is 64 or 64 url
+fn is_any_64(base: &Base) -> bool {
+    base == &Base::_64 || base == &Base::_64URL
+}
+
+// base is strictly 32
+fn is_32(base: &Base) -> bool {
+    base == &Base::_32
+}
+
+// base is strictly 32 hex
+fn is_32_hex(base: &Base) -> bool {
+    base == &Base::_32HEX
+}
+
+fn is_45(base: &Base) -> bool {
+    base == &Base::_45
+}
+
+// table index -> char, through the match chain
+pub fn char_from_idx(idx: u8, base: &Base) -> char {
+    match idx {
+        // alpha
+        0 if alpha_26(base) => 'A',
+        1 if alpha_26(base) => 'B',
+        2 if alpha_26(base) => 'C',
+        3 if alpha_26(base) => 'D',
+        4 if alpha_26(base) => 'E',
+        5 if alpha_26(base) => 'F',
+        6 if alpha_26(base) => 'G',
+        7 if alpha_26(base) => 'H',
+        8 if alpha_26(base) => 'I',
+        9 if alpha_26(base) => 'J',
+        10 if alpha_26(base) => 'K',
+        11 if alpha_26(base) => 'L',
+        12 if alpha_26(base) => 'M',
+        13 if alpha_26(base) => 'N',
+        14 if alpha_26(base) => 'O',
+        15 if alpha_26(base) => 'P',
+        16 if alpha_26(base) => 'Q',
+        17 if alpha_26(base) => 'R',
+        18 if alpha_26(base) => 'S',
+        19 if alpha_26(base) => 'T',
+        20 if alpha_26(base) => 'U',
+        21 if alpha_26(base) => 'V',
+        22 if alpha_26(base) => 'W',
+        23 if alpha_26(base) => 'X',
+        24 if alpha_26(base) => 'Y',
+        25 if alpha_26(base) => 'Z',
+
+        26 if is_any_64(base) => 'a',
+        27 if is_any_64(base) => 'b',
+        28 if is_any_64(base) => 'c',
+        29 if is_any_64(base) => 'd',
+        30 if is_any_64(base) => 'e',
+        31 if is_any_64(base) => 'f',
+        32 if is_any_64(base) => 'g',
+        33 if is_any_64(base) => 'h',
+        34 if is_any_64(base) => 'i',
+        35 if is_any_64(base) => 'j',
+        36 if is_any_64(base) => 'k',
+        37 if is_any_64(base) => 'l',
+        38 if is_any_64(base) => 'm',
+        39 if is_any_64(base) => 'n',
+        40 if is_any_64(base) => 'o',
+        41 if is_any_64(base) => 'p',
+        42 if is_any_64(base) => 'q',
+        43 if is_any_64(base) => 'r',
+        44 if is_any_64(base) => 's',
+        45 if is_any_64(base) => 't',
+        46 if is_any_64(base) => 'u',
+        47 if is_any_64(base) => 'v',
+        48 if is_any_64(base) => 'w',
+        49 if is_any_64(base) => 'x',
+        50 if is_any_64(base) => 'y',
+        51 if is_any_64(base) => 'z',
+        52 if is_any_64(base) => '0',
+        53 if is_any_64(base) => '1',
+        54 if is_any_64(base) => '2',
+        55 if is_any_64(base) => '3',
+        56 if is_any_64(base) => '4',
+        57 if is_any_64(base) => '5',
+        58 if is_any_64(base) => '6',
+        59 if is_any_64(base) => '7',
+        60 if is_any_64(base) => '8',
+        61 if is_any_64(base) => '9',
+
+        // NOTE base 64 is done with this
+        62 if base == &Base::_64 => '+',
+        63 if base == &Base::_64 => '/',
+
+        // NOTE base 64 url is done with this
+        62 if base == &Base::_64URL => '-',
+        63 if base == &Base::_64URL => '_',
+
+        // NOTE base 32 is done with tihs
+        26 if is_32(base) => '2',
+        27 if is_32(base) => '3',
+        28 if is_32(base) => '4',
+        29 if is_32(base) => '5',
+        30 if is_32(base) => '6',
+        31 if is_32(base) => '7',
+
+        // hex
+        // NOTE base 16 is done with this
+        0 if hex_16(base) | is_45(base) => '0',
+        1 if hex_16(base) | is_45(base) => '1',
+        2 if hex_16(base) | is_45(base) => '2',
+        3 if hex_16(base) | is_45(base) => '3',
+        4 if hex_16(base) | is_45(base) => '4',
+        5 if hex_16(base) | is_45(base) => '5',
+        6 if hex_16(base) | is_45(base) => '6',
+        7 if hex_16(base) | is_45(base) => '7',
+        8 if hex_16(base) | is_45(base) => '8',
+        9 if hex_16(base) | is_45(base) => '9',
+        10 if hex_16(base) | is_45(base) => 'A',
+        11 if hex_16(base) | is_45(base) => 'B',
+        12 if hex_16(base) | is_45(base) => 'C',
+        13 if hex_16(base) | is_45(base) => 'D',
+        14 if hex_16(base) | is_45(base) => 'E',
+        15 if hex_16(base) | is_45(base) => 'F',
+
+        // NOTE base 32 hex is done with this
+        16 if is_32_hex(base) | is_45(base) => 'G',
+        17 if is_32_hex(base) | is_45(base) => 'H',
+        18 if is_32_hex(base) | is_45(base) => 'I',
+        19 if is_32_hex(base) | is_45(base) => 'J',
+        20 if is_32_hex(base) | is_45(base) => 'K',
+        21 if is_32_hex(base) | is_45(base) => 'L',
+        22 if is_32_hex(base) | is_45(base) => 'M',
+        23 if is_32_hex(base) | is_45(base) => 'N',
+        24 if is_32_hex(base) | is_45(base) => 'O',
+        25 if is_32_hex(base) | is_45(base) => 'P',
+        26 if is_32_hex(base) | is_45(base) => 'Q',
+        27 if is_32_hex(base) | is_45(base) => 'R',
+        28 if is_32_hex(base) | is_45(base) => 'S',
+        29 if is_32_hex(base) | is_45(base) => 'T',
+        30 if is_32_hex(base) | is_45(base) => 'U',
+        31 if is_32_hex(base) | is_45(base) => 'V',
+
+        32 if is_45(base) => 'W',
+        33 if is_45(base) => 'X',
+        34 if is_45(base) => 'Y',
+        35 if is_45(base) => 'Z',
+        36 if is_45(base) => ' ',
+        37 if is_45(base) => '$',
+        38 if is_45(base) => '%',
+        39 if is_45(base) => '*',
+        40 if is_45(base) => '+',
+        41 if is_45(base) => '-',
+        42 if is_45(base) => '.',
+        43 if is_45(base) => '/',
+        44 if is_45(base) => ':',
+
+        // NOTE let this panic, since this crate is always in control of the u8 that
+        // this function receives, guarenteeing that this match arm is always unreachable
+        idx => unreachable!("got impossile table index {} for base {:?}", idx, base),
+    }
+}
+
+// char -> table index, through the match chain
+pub fn idx_from_char(chr: char, base: &Base) -> Result<u8, DecodeError> {
+    match chr {
+        // alpha
+        'A' if alpha_26(base) => Ok(0),
+        'B' if alpha_26(base) => Ok(1),
+        'C' if alpha_26(base) => Ok(2),
+        'D' if alpha_26(base) => Ok(3),
+        'E' if alpha_26(base) => Ok(4),
+        'F' if alpha_26(base) => Ok(5),
+        'G' if alpha_26(base) => Ok(6),
+        'H' if alpha_26(base) => Ok(7),
+        'I' if alpha_26(base) => Ok(8),
+        'J' if alpha_26(base) => Ok(9),
+        'K' if alpha_26(base) => Ok(10),
+        'L' if alpha_26(base) => Ok(11),
+        'M' if alpha_26(base) => Ok(12),
+        'N' if alpha_26(base) => Ok(13),
+        'O' if alpha_26(base) => Ok(14),
+        'P' if alpha_26(base) => Ok(15),
+        'Q' if alpha_26(base) => Ok(16),
+        'R' if alpha_26(base) => Ok(17),
+        'S' if alpha_26(base) => Ok(18),
+        'T' if alpha_26(base) => Ok(19),
+        'U' if alpha_26(base) => Ok(20),
+        'V' if alpha_26(base) => Ok(21),
+        'W' if alpha_26(base) => Ok(22),
+        'X' if alpha_26(base) => Ok(23),
+        'Y' if alpha_26(base) => Ok(24),
+        'Z' if alpha_26(base) => Ok(25),
+
+        'a' if is_any_64(base) => Ok(26),
+        'b' if is_any_64(base) => Ok(27),
+        'c' if is_any_64(base) => Ok(28),
+        'd' if is_any_64(base) => Ok(29),
+        'e' if is_any_64(base) => Ok(30),
+        'f' if is_any_64(base) => Ok(31),
+        'g' if is_any_64(base) => Ok(32),
+        'h' if is_any_64(base) => Ok(33),
+        'i' if is_any_64(base) => Ok(34),
+        'j' if is_any_64(base) => Ok(35),
+        'k' if is_any_64(base) => Ok(36),
+        'l' if is_any_64(base) => Ok(37),
+        'm' if is_any_64(base) => Ok(38),
+        'n' if is_any_64(base) => Ok(39),
+        'o' if is_any_64(base) => Ok(40),
+        'p' if is_any_64(base) => Ok(41),
+        'q' if is_any_64(base) => Ok(42),
+        'r' if is_any_64(base) => Ok(43),
+        's' if is_any_64(base) => Ok(44),
+        't' if is_any_64(base) => Ok(45),
+        'u' if is_any_64(base) => Ok(46),
+        'v' if is_any_64(base) => Ok(47),
+        'w' if is_any_64(base) => Ok(48),
+        'x' if is_any_64(base) => Ok(49),
+        'y' if is_any_64(base) => Ok(50),
+        'z' if is_any_64(base) => Ok(51),
+        '0' if is_any_64(base) => Ok(52),
+        '1' if is_any_64(base) => Ok(53),
+        '2' if is_any_64(base) => Ok(54),
+        '3' if is_any_64(base) => Ok(55),
+        '4' if is_any_64(base) => Ok(56),
+        '5' if is_any_64(base) => Ok(57),
+        '6' if is_any_64(base) => Ok(58),
+        '7' if is_any_64(base) => Ok(59),
+        '8' if is_any_64(base) => Ok(60),
+        '9' if is_any_64(base) => Ok(61),
+
+        // NOTE base 64 is done with this
+        '+' if base == &Base::_64 => Ok(62),
+        '/' if base == &Base::_64 => Ok(63),
+
+        // NOTE base 64 url is done with this
+        '-' if base == &Base::_64URL => Ok(62),
+        '_' if base == &Base::_64URL => Ok(63),
+
+        // NOTE base 32 is done with this
+        '2' if is_32(base) => Ok(26),
+        '3' if is_32(base) => Ok(27),
+        '4' if is_32(base) => Ok(28),
+        '5' if is_32(base) => Ok(29),
+        '6' if is_32(base) => Ok(30),
+        '7' if is_32(base) => Ok(31),
+
+        // hex
+        // NOTE base 16 is done with this
+        '0' if hex_16(base) | is_45(base) => Ok(0),
+        '1' if hex_16(base) | is_45(base) => Ok(1),
+        '2' if hex_16(base) | is_45(base) => Ok(2),
+        '3' if hex_16(base) | is_45(base) => Ok(3),
+        '4' if hex_16(base) | is_45(base) => Ok(4),
+        '5' if hex_16(base) | is_45(base) => Ok(5),
+        '6' if hex_16(base) | is_45(base) => Ok(6),
+        '7' if hex_16(base) | is_45(base) => Ok(7),
+        '8' if hex_16(base) | is_45(base) => Ok(8),
+        '9' if hex_16(base) | is_45(base) => Ok(9),
+        'A' if hex_16(base) | is_45(base) => Ok(10),
+        'B' if hex_16(base) | is_45(base) => Ok(11),
+        'C' if hex_16(base) | is_45(base) => Ok(12),
+        'D' if hex_16(base) | is_45(base) => Ok(13),
+        'E' if hex_16(base) | is_45(base) => Ok(14),
+        'F' if hex_16(base) | is_45(base) => Ok(15),
+
+        // NOTE base 32 hex is done with this
+        'G' if is_32_hex(base) | is_45(base) => Ok(16),
+        'H' if is_32_hex(base) | is_45(base) => Ok(17),
+        'I' if is_32_hex(base) | is_45(base) => Ok(18),
+        'J' if is_32_hex(base) | is_45(base) => Ok(19),
+        'K' if is_32_hex(base) | is_45(base) => Ok(20),
+        'L' if is_32_hex(base) | is_45(base) => Ok(21),
+        'M' if is_32_hex(base) | is_45(base) => Ok(22),
+        'N' if is_32_hex(base) | is_45(base) => Ok(23),
+        'O' if is_32_hex(base) | is_45(base) => Ok(24),
+        'P' if is_32_hex(base) | is_45(base) => Ok(25),
+        'Q' if is_32_hex(base) | is_45(base) => Ok(26),
+        'R' if is_32_hex(base) | is_45(base) => Ok(27),
+        'S' if is_32_hex(base) | is_45(base) => Ok(28),
+        'T' if is_32_hex(base) | is_45(base) => Ok(29),
+        'U' if is_32_hex(base) | is_45(base) => Ok(30),
+        'V' if is_32_hex(base) | is_45(base) => Ok(31),
+
+        'W' if is_45(base) => Ok(32),
+        'X' if is_45(base) => Ok(33),
+        'Y' if is_45(base) => Ok(34),
+        'Z' if is_45(base) => Ok(35),
+        ' ' if is_45(base) => Ok(36),
+        '$' if is_45(base) => Ok(37),
+        '%' if is_45(base) => Ok(38),
+        '*' if is_45(base) => Ok(39),
+        '+' if is_45(base) => Ok(40),
+        '-' if is_45(base) => Ok(41),
+        '.' if is_45(base) => Ok(42),
+        '/' if is_45(base) => Ok(43),
+        ':' if is_45(base) => Ok(44),
+
+        // _ => panic!("got impossile table char {} for base {:?}", chr, base),
+        ch => Err(DecodeError::InvalidChar {
+            char: ch,
+            base: *base,
+        }),
+    }
+}
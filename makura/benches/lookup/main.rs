@@ -0,0 +1,134 @@
+// per char lookups, the alphabet tables against the match chains they replaced
+//
+// run with `cargo bench --bench lookup`
+//
+// the tables are private to the crate, so they are copied here, built the same way
+mod legacy;
+
+use criterion::{BenchmarkId, Criterion, Throughput, black_box, criterion_group, criterion_main};
+use legacy::{char_from_idx, idx_from_char};
+use makura::{BASE16, BASE32, BASE32HEX, BASE45, BASE64, BASE64URL, Base};
+
+const BASES: [Base; 6] = [BASE64, BASE64URL, BASE45, BASE32, BASE32HEX, BASE16];
+const LEN: usize = 64 * 1024;
+
+// an encoding table and its reverse, as the crate's `Alphabet` lays them out
+struct Table {
+    encode: [u8; 64],
+    decode: [u8; 256],
+}
+
+impl Table {
+    const fn new(chars: &[u8]) -> Self {
+        let mut table = Self {
+            encode: [0; 64],
+            decode: [u8::MAX; 256],
+        };
+
+        let mut idx = 0;
+        while idx < chars.len() {
+            table.encode[idx] = chars[idx];
+            table.decode[chars[idx] as usize] = idx as u8;
+            idx += 1;
+        }
+
+        table
+    }
+}
+
+static BASE64_TABLE: Table =
+    Table::new(b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/");
+static BASE64URL_TABLE: Table =
+    Table::new(b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_");
+static BASE45_TABLE: Table = Table::new(b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:");
+static BASE32_TABLE: Table = Table::new(b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567");
+static BASE32HEX_TABLE: Table = Table::new(b"0123456789ABCDEFGHIJKLMNOPQRSTUV");
+static BASE16_TABLE: Table = Table::new(b"0123456789ABCDEF");
+
+fn table(base: &Base) -> &'static Table {
+    match *base {
+        BASE64 => &BASE64_TABLE,
+        BASE64URL => &BASE64URL_TABLE,
+        BASE45 => &BASE45_TABLE,
+        BASE32 => &BASE32_TABLE,
+        BASE32HEX => &BASE32HEX_TABLE,
+        _ => &BASE16_TABLE,
+    }
+}
+
+// table index -> char, through the table of base
+fn char_at(idx: u8, base: &Base) -> char {
+    table(base).encode[idx as usize] as char
+}
+
+// char -> table index, through the table of base
+fn idx_of(chr: u8, base: &Base) -> Option<u8> {
+    match table(base).decode[chr as usize] {
+        u8::MAX => None,
+        idx => Some(idx),
+    }
+}
+
+// LEN valid table indices of base
+fn indices(base: Base) -> Vec<u8> {
+    let radix = match base {
+        BASE64 | BASE64URL => 64,
+        BASE45 => 45,
+        BASE32 | BASE32HEX => 32,
+        _ => 16,
+    };
+
+    (0..LEN).map(|i| ((i * 31 + 7) % radix) as u8).collect()
+}
+
+fn char_lookup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("char_lookup");
+    group.throughput(Throughput::Bytes(LEN as u64));
+    for base in BASES {
+        let indices = indices(base);
+        group.bench_with_input(BenchmarkId::new("match_chain", base), &indices, |b, i| {
+            b.iter(|| {
+                black_box(i)
+                    .iter()
+                    .fold(0u32, |acc, idx| acc ^ char_from_idx(*idx, &base) as u32)
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("table", base), &indices, |b, i| {
+            b.iter(|| {
+                black_box(i)
+                    .iter()
+                    .fold(0u32, |acc, idx| acc ^ char_at(*idx, &base) as u32)
+            })
+        });
+    }
+    group.finish();
+}
+
+fn idx_lookup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("idx_lookup");
+    group.throughput(Throughput::Bytes(LEN as u64));
+    for base in BASES {
+        let chars = indices(base)
+            .into_iter()
+            .map(|idx| char_at(idx, &base))
+            .collect::<String>();
+        group.bench_with_input(BenchmarkId::new("match_chain", base), &chars, |b, c| {
+            b.iter(|| {
+                black_box(c).chars().fold(0u32, |acc, chr| {
+                    acc ^ idx_from_char(chr, &base).unwrap() as u32
+                })
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("table", base), &chars, |b, c| {
+            b.iter(|| {
+                black_box(c)
+                    .bytes()
+                    .fold(0u32, |acc, chr| acc ^ idx_of(chr, &base).unwrap() as u32)
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, char_lookup, idx_lookup);
+criterion_main!(benches);
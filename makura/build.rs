@@ -4,7 +4,7 @@ fn main() {
         .args(["--version"])
         .output()
     {
-        Err(e) => panic!("oohohohoho"),
+        Err(_e) => panic!("oohohohoho"),
         Ok(ver) => (String::from_utf8_lossy(&ver.stdout)).contains("nightly"),
     } {
         println!("cargo:rustc-cfg=feature=\"nightly\"");
@@ -0,0 +1,379 @@
+use crate::Base;
+use crate::PAD;
+use crate::{BASE16, BASE32, BASE58, BASE64, BASE85};
+
+// marks the chars that are not part of the alphabet in the decode table
+const INVALID: u8 = u8::MAX;
+
+// the number of chars of the biggest alphabets, i.e., the base85 ones
+const MAX_RADIX: usize = 85;
+
+// the standard encoding tables, built at compile time
+// NOTE encoding and decoding are simple array indexing into these,
+// no more matching through every char of a base for each encoded/decoded char
+static BASE64_TABLE: Alphabet = Alphabet::from_table(
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+    PAD as u8,
+);
+static BASE64URL_TABLE: Alphabet = Alphabet::from_table(
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_",
+    PAD as u8,
+);
+static BASE45_TABLE: Alphabet =
+    Alphabet::from_table(b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:", PAD as u8);
+static BASE32_TABLE: Alphabet =
+    Alphabet::from_table(b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567", PAD as u8);
+static BASE32HEX_TABLE: Alphabet =
+    Alphabet::from_table(b"0123456789ABCDEFGHIJKLMNOPQRSTUV", PAD as u8);
+static BASE16_TABLE: Alphabet = Alphabet::from_table(b"0123456789ABCDEF", PAD as u8);
+// the bitcoin alphabet, without 0, O, I and l
+// NOTE base58 has no padding, the pad is never used
+static BASE58_TABLE: Alphabet = Alphabet::from_table(
+    b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz",
+    PAD as u8,
+);
+// the adobe/btoa alphabet, every ascii char from '!' to 'u'
+// NOTE '=' is one of its chars and ascii85 has no padding, so the pad is never used;
+// it is the first char past the alphabet, as '~' and 'z'/'y' are taken by the delimiters and abbreviations
+static BASE85_TABLE: Alphabet = Alphabet::from_table(
+    b"!\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstu",
+    b'v',
+);
+// zeromq's alphabet, without the quotes, the backslash and the other chars that need escaping in strings
+// NOTE z85 has no padding either, '~' is one of the ascii chars left out of the alphabet
+static Z85_TABLE: Alphabet = Alphabet::from_table(
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#",
+    b'~',
+);
+// crockford's alphabet, without I, L, O and U
+// NOTE decoding is case insensitive, and I and L are read as 1, O as 0;
+// '=' is one of the check symbols, so the (unused) pad is another char
+static BASE32CROCKFORD_TABLE: Alphabet =
+    Alphabet::from_table(b"0123456789ABCDEFGHJKMNPQRSTVWXYZ", b'#').with_aliases(
+        b"abcdefghjkmnpqrstvwxyzIiLlOo",
+        b"ABCDEFGHJKMNPQRSTVWXYZ111100",
+    );
+// digits, then uppercase and lowercase letters, the gmp order
+// NOTE base62 has no padding, the pad is never used
+static BASE62_TABLE: Alphabet = Alphabet::from_table(
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz",
+    PAD as u8,
+);
+// digits then lowercase letters, e.g., java's `Long.toString(x, 36)`
+// NOTE decoding is case insensitive, base36 has no padding either
+static BASE36_TABLE: Alphabet =
+    Alphabet::from_table(b"0123456789abcdefghijklmnopqrstuvwxyz", PAD as u8)
+        .with_aliases(b"ABCDEFGHIJKLMNOPQRSTUVWXYZ", b"abcdefghijklmnopqrstuvwxyz");
+// z-base-32's alphabet, lowercase and ordered so that the easiest chars to read and write come first
+static ZBASE32_TABLE: Alphabet =
+    Alphabet::from_table(b"ybndrfg8ejkmcpqxot1uwisza345h769", PAD as u8);
+// the RFC 1924 alphabet, as used by git binary patches and python's b85encode
+// NOTE '.' is one of the ascii chars left out of the alphabet, there is no padding either
+static BASE85RFC_TABLE: Alphabet = Alphabet::from_table(
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz!#$%&()*+-;<=>?@^_`{|}~",
+    b'.',
+);
+
+/// an encoding table of 16, 32, 58, 64 or 85 ascii chars plus a padding char
+///
+/// a custom alphabet only replaces the chars of an encoding,
+/// the bits layout stays that of the base with the same number of chars,
+/// i.e., a 64 chars alphabet encodes like base64, a 32 chars one like base32...
+///
+/// a 58 chars alphabet encodes like base58, e.g., the ripple or flickr alphabets,
+/// its padding char is never used
+///
+/// a 85 chars alphabet encodes like ascii85, without the `z`/`y` abbreviations
+/// when the alphabet has those chars
+///
+/// ```
+/// use makura::{Alphabet, Decoder, Encoder};
+///
+/// // bcrypt's base64 table
+/// let bcrypt = Alphabet::new(
+///     "./ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789",
+///     '=',
+/// )
+/// .unwrap();
+///
+/// let enc = Encoder::custom(bcrypt);
+/// let encoded = enc.encode("foobar");
+/// assert_eq!(encoded, "Xk7tWkDw");
+///
+/// let dec = Decoder::new().with_alphabet(bcrypt);
+/// assert_eq!(dec.apply(encoded, *enc.base()).unwrap().as_bytes(), b"foobar");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Alphabet {
+    // index -> char
+    encode: [u8; MAX_RADIX],
+    // char -> index
+    decode: [u8; 256],
+    radix: u8,
+    pad: u8,
+}
+
+impl core::fmt::Debug for Alphabet {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Alphabet")
+            .field(
+                "chars",
+                &core::str::from_utf8(&self.encode[..self.radix as usize]).unwrap_or_default(),
+            )
+            .field("pad", &(self.pad as char))
+            .finish()
+    }
+}
+
+/// errors that can occur while building a custom `Alphabet`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AlphabetError {
+    /// alphabet is not made of exactly 16, 32, 58, 64 or 85 chars
+    InvalidLen(usize),
+    /// alphabet contains a non ascii or an ascii control char
+    InvalidChar(char),
+    /// the same char was found more than once in the alphabet
+    DuplicateChar(char),
+    /// padding char is not ascii, is a control char or is part of the alphabet
+    InvalidPad(char),
+}
+
+impl core::fmt::Display for AlphabetError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidLen(len) => write!(
+                f,
+                "alphabet must be 16, 32, 58, 64 or 85 chars long, got {} chars",
+                len
+            ),
+            Self::InvalidChar(c) => write!(
+                f,
+                "alphabet chars must be printable ascii chars, got {:?}",
+                c
+            ),
+            Self::DuplicateChar(c) => write!(f, "char {:?} appears more than once in alphabet", c),
+            Self::InvalidPad(c) => write!(
+                f,
+                "padding char must be a printable ascii char that is not in the alphabet, got {:?}",
+                c
+            ),
+        }
+    }
+}
+
+impl core::error::Error for AlphabetError {}
+
+impl Alphabet {
+    /// builds an alphabet out of the given chars, in encoding table order,
+    /// and the given padding char
+    ///
+    /// # Error
+    /// returns an `AlphabetError` when
+    /// * chars is not 16, 32, 58, 64 or 85 chars long
+    /// * chars contains a char that is not printable ascii or contains the same char twice
+    /// * pad is not printable ascii or is one of the alphabet chars
+    pub fn new(chars: &str, pad: char) -> Result<Self, AlphabetError> {
+        let len = chars.chars().count();
+        if ![16, 32, 58, 64, 85].contains(&len) {
+            return Err(AlphabetError::InvalidLen(len));
+        }
+
+        if !is_printable(pad) {
+            return Err(AlphabetError::InvalidPad(pad));
+        }
+
+        let mut alphabet = Self {
+            encode: [0; MAX_RADIX],
+            decode: [INVALID; 256],
+            radix: len as u8,
+            pad: pad as u8,
+        };
+
+        for (idx, chr) in chars.chars().enumerate() {
+            if !is_printable(chr) {
+                return Err(AlphabetError::InvalidChar(chr));
+            } else if alphabet.decode[chr as usize] != INVALID {
+                return Err(AlphabetError::DuplicateChar(chr));
+            } else if chr == pad {
+                return Err(AlphabetError::InvalidPad(pad));
+            }
+
+            alphabet.encode[idx] = chr as u8;
+            alphabet.decode[chr as usize] = idx as u8;
+        }
+
+        Ok(alphabet)
+    }
+
+    // builds an alphabet at compile time out of chars that are known to be valid
+    pub(crate) const fn from_table(chars: &[u8], pad: u8) -> Self {
+        let mut alphabet = Self {
+            encode: [0; MAX_RADIX],
+            decode: [INVALID; 256],
+            radix: chars.len() as u8,
+            pad,
+        };
+
+        let mut idx = 0;
+        while idx < chars.len() {
+            alphabet.encode[idx] = chars[idx];
+            alphabet.decode[chars[idx] as usize] = idx as u8;
+            idx += 1;
+        }
+
+        alphabet
+    }
+
+    // decodes every char of aliases like the char of chars at the same position,
+    // the aliases are never encoded
+    const fn with_aliases(mut self, aliases: &[u8], chars: &[u8]) -> Self {
+        let mut idx = 0;
+        while idx < aliases.len() {
+            self.decode[aliases[idx] as usize] = self.decode[chars[idx] as usize];
+            idx += 1;
+        }
+
+        self
+    }
+
+    // the standard encoding table of the given base
+    pub(crate) fn from_base(base: &Base) -> &'static Self {
+        match base {
+            Base::_64 => &BASE64_TABLE,
+            Base::_64URL => &BASE64URL_TABLE,
+            Base::_45 => &BASE45_TABLE,
+            Base::_32 => &BASE32_TABLE,
+            Base::_32HEX => &BASE32HEX_TABLE,
+            Base::_16 => &BASE16_TABLE,
+            Base::_58 => &BASE58_TABLE,
+            Base::_85 => &BASE85_TABLE,
+            Base::_Z85 => &Z85_TABLE,
+            Base::_85RFC => &BASE85RFC_TABLE,
+            Base::_32CROCKFORD => &BASE32CROCKFORD_TABLE,
+            Base::_Z32 => &ZBASE32_TABLE,
+            Base::_62 => &BASE62_TABLE,
+            Base::_36 => &BASE36_TABLE,
+        }
+    }
+
+    /// returns the number of chars in the alphabet
+    pub fn radix(&self) -> usize {
+        self.radix as usize
+    }
+
+    /// returns the padding char of the alphabet
+    pub fn pad(&self) -> char {
+        self.pad as char
+    }
+
+    /// returns the base whose bits layout is used with this alphabet;
+    /// one of `BASE85`, `BASE64`, `BASE58`, `BASE32` or `BASE16`
+    pub fn base(&self) -> Base {
+        match self.radix {
+            85 => BASE85,
+            64 => BASE64,
+            58 => BASE58,
+            32 => BASE32,
+            16 => BASE16,
+            radix => unreachable!("alphabet can not be built with {} chars", radix),
+        }
+    }
+
+    /// returns true if chr is one of the alphabet chars
+    pub fn contains(&self, chr: char) -> bool {
+        chr.is_ascii() && self.decode[chr as usize] != INVALID
+    }
+
+    // table index -> char
+    pub(crate) fn char_at(&self, idx: u8) -> char {
+        if idx >= self.radix {
+            // NOTE let this panic,
+            // this crate is always in control of the indices passed here
+            unreachable!("got impossile table index {} for alphabet {:?}", idx, self);
+        }
+
+        self.encode[idx as usize] as char
+    }
+
+    // char -> table index
+    pub(crate) fn idx_of(&self, chr: u8) -> Option<u8> {
+        match self.decode[chr as usize] {
+            INVALID => None,
+            idx => Some(idx),
+        }
+    }
+}
+
+fn is_printable(chr: char) -> bool {
+    chr.is_ascii() && !chr.is_ascii_control()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Alphabet, AlphabetError};
+    use crate::{BASE32HEX, BASE45, BASE64URL};
+
+    const CROCKFORD: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+    #[test]
+    fn test_new() {
+        let alphabet = Alphabet::new(CROCKFORD, '=').unwrap();
+
+        assert_eq!(alphabet.radix(), 32);
+        assert_eq!(alphabet.char_at(18), 'J');
+        assert_eq!(alphabet.idx_of(b'Z'), Some(31));
+        assert_eq!(alphabet.idx_of(b'U'), None);
+    }
+
+    #[test]
+    fn test_from_base() {
+        let alphabet = Alphabet::from_base(&BASE64URL);
+
+        assert_eq!(alphabet.char_at(63), '_');
+        assert_eq!(alphabet.idx_of(b'-'), Some(62));
+        assert_eq!(Alphabet::from_base(&BASE32HEX).idx_of(b'V'), Some(31));
+        assert_eq!(Alphabet::from_base(&BASE45).idx_of(b' '), Some(36));
+    }
+
+    #[test]
+    fn fail_len() {
+        assert_eq!(
+            Alphabet::new("0123456789", '='),
+            Err(AlphabetError::InvalidLen(10))
+        );
+    }
+
+    #[test]
+    fn fail_duplicate() {
+        assert_eq!(
+            Alphabet::new("0123456789ABCDEA", '='),
+            Err(AlphabetError::DuplicateChar('A'))
+        );
+    }
+
+    #[test]
+    fn fail_pad() {
+        assert_eq!(
+            Alphabet::new("0123456789ABCDEF", 'A'),
+            Err(AlphabetError::InvalidPad('A'))
+        );
+        assert_eq!(
+            Alphabet::new("0123456789ABCDEF", '\n'),
+            Err(AlphabetError::InvalidPad('\n'))
+        );
+    }
+
+    #[test]
+    fn fail_char() {
+        assert_eq!(
+            Alphabet::new("0123456789ABCDEé", '='),
+            Err(AlphabetError::InvalidChar('é'))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn fail_char_at() {
+        Alphabet::new(CROCKFORD, '=').unwrap().char_at(32);
+    }
+}
@@ -0,0 +1,209 @@
+#![cfg(all(
+    feature = "alloc",
+    feature = "base64",
+    feature = "encoding",
+    feature = "decoding"
+))]
+//! OpenPGP ASCII armor (RFC 4880 section 6), PEM like blocks of base64 encoded data
+//! followed by a `=XXXX` CRC-24 checksum line, e.g., messages, keys and signatures
+//!
+//! the armor headers, e.g., `Version` or `Comment`, are exposed as `key: value` pairs
+//!
+//! ```
+//! use makura::LineEnding;
+//! use makura::armor::Armor;
+//!
+//! let armor = Armor::new("PGP MESSAGE", b"foobar".to_vec()).with_header("Comment", "makura");
+//! let encoded = armor.encode(LineEnding::Lf);
+//! assert_eq!(
+//!     encoded,
+//!     "-----BEGIN PGP MESSAGE-----\nComment: makura\n\nZm9vYmFy\n=czTe\n-----END PGP MESSAGE-----\n"
+//! );
+//!
+//! let parsed = Armor::parse(&encoded).unwrap();
+//! assert_eq!(parsed.header("Comment"), Some("makura"));
+//! assert_eq!(parsed.contents(), b"foobar");
+//! ```
+use crate::makura_alloc::{String, Vec};
+
+use crate::pem::{BEGIN, DASHES, END, Headers, LINE_WIDTH, PemError, RawBlock};
+use crate::pem::{decode_body, read_block};
+use crate::{BASE64, DecodeError, Decoder, Encoder, LineEnding};
+
+// RFC 4880 section 6.1
+const CRC24_INIT: u32 = 0xB704CE;
+const CRC24_POLY: u32 = 0x1864CFB;
+
+/// computes the CRC-24 checksum of data, as defined in RFC 4880 section 6.1
+///
+/// ```
+/// assert_eq!(makura::armor::crc24(b"foobar"), 0x7334DE);
+/// ```
+pub fn crc24(data: &[u8]) -> u32 {
+    data.iter().fold(CRC24_INIT, |crc, byte| {
+        (0..8).fold(crc ^ ((*byte as u32) << 16), |crc, _| {
+            let crc = crc << 1;
+            if crc & 0x1000000 != 0 {
+                crc ^ CRC24_POLY
+            } else {
+                crc
+            }
+        })
+    }) & 0xFFFFFF
+}
+
+/// an OpenPGP armored block; a label, the armor headers and the decoded contents
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Armor {
+    label: String,
+    headers: Headers,
+    contents: Vec<u8>,
+}
+
+impl Armor {
+    /// creates a new block without armor headers
+    pub fn new<T: Into<String>>(label: T, contents: Vec<u8>) -> Self {
+        Self {
+            label: label.into(),
+            headers: Vec::new(),
+            contents,
+        }
+    }
+
+    /// appends a `key: value` armor header to the block's headers
+    pub fn with_header<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.headers.push((key.into(), value.into()));
+
+        self
+    }
+
+    /// returns the label of the block, e.g., `PGP PUBLIC KEY BLOCK`
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// returns the armor headers of the block, in order
+    pub fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+
+    /// returns the value of the first armor header with the given key
+    pub fn header(&self, key: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// returns the decoded contents of the block
+    pub fn contents(&self) -> &[u8] {
+        &self.contents
+    }
+
+    /// returns the owned decoded contents of the block
+    pub fn into_contents(self) -> Vec<u8> {
+        self.contents
+    }
+
+    /// parses the first armored block of value
+    ///
+    /// any text before the begin line and after the end line is ignored;
+    /// the checksum line is optional, but when present it is verified against the decoded contents
+    ///
+    /// # Error
+    /// returns a `PemError` when no block is found, or when the first block is invalid,
+    /// a wrong checksum is reported as `PemError::Decode(DecodeError::ChecksumMismatch { .. })`
+    pub fn parse<T: AsRef<str>>(value: T) -> Result<Self, PemError> {
+        let mut lines = value.as_ref().lines();
+        let Some(RawBlock {
+            label,
+            headers,
+            body,
+        }) = read_block(&mut lines)?
+        else {
+            return Err(PemError::MissingBegin);
+        };
+        let mut body = &body[..];
+
+        // NOTE base64 lines never start with a '=',
+        // the 64 chars lines are always whole quanta, so the padding can't wrap on its own line
+        let checksum = match body.iter().rposition(|l| !l.trim().is_empty()) {
+            Some(idx) if body[idx].starts_with('=') => {
+                let line = body[idx].trim();
+                body = &body[..idx];
+
+                Some(decode_checksum(&line[1..])?)
+            }
+            _ => None,
+        };
+
+        let contents = decode_body(body)?;
+
+        if let Some(expected) = checksum {
+            let found = crc24(&contents);
+            if found != expected {
+                return Err(DecodeError::ChecksumMismatch { expected, found }.into());
+            }
+        }
+
+        Ok(Self {
+            label: label.into(),
+            headers,
+            contents,
+        })
+    }
+
+    /// encodes the block, wrapping its base64 body at 64 columns and appending its CRC-24 line
+    ///
+    /// every line, the end line included, is terminated by ending
+    pub fn encode(&self, ending: LineEnding) -> String {
+        let eol = ending.as_str();
+
+        let mut encoded = String::new();
+        encoded.push_str(BEGIN);
+        encoded.push_str(&self.label);
+        encoded.push_str(DASHES);
+        encoded.push_str(eol);
+
+        self.headers.iter().for_each(|(k, v)| {
+            encoded.push_str(k);
+            encoded.push_str(": ");
+            encoded.push_str(v);
+            encoded.push_str(eol);
+        });
+        // RFC 4880 section 6.2, the blank line is there even when there are no headers
+        encoded.push_str(eol);
+
+        if !self.contents.is_empty() {
+            let body = Encoder::base64()
+                .with_wrap(LINE_WIDTH, ending)
+                .encode_bytes(&self.contents);
+            encoded.push_str(&body);
+            encoded.push_str(eol);
+        }
+
+        let crc = crc24(&self.contents).to_be_bytes();
+        encoded.push('=');
+        encoded.push_str(&Encoder::base64().encode_bytes(&crc[1..]));
+        encoded.push_str(eol);
+
+        encoded.push_str(END);
+        encoded.push_str(&self.label);
+        encoded.push_str(DASHES);
+        encoded.push_str(eol);
+
+        encoded
+    }
+}
+
+// decodes the 4 base64 chars of a checksum line into the 24 bits crc
+fn decode_checksum(value: &str) -> Result<u32, DecodeError> {
+    let decoded = Decoder::decode(value, BASE64)?;
+    match decoded.as_bytes() {
+        [a, b, c] => Ok(u32::from_be_bytes([0, *a, *b, *c])),
+        _ => Err(DecodeError::InvalidLen {
+            len: value.len(),
+            base: BASE64,
+        }),
+    }
+}
@@ -0,0 +1,107 @@
+#![cfg(all(
+    feature = "alloc",
+    feature = "base58check",
+    feature = "encoding",
+    feature = "decoding"
+))]
+//! Base58Check, bitcoin's base58 with a leading version byte and a trailing checksum,
+//! the first 4 bytes of the double SHA-256 of the version and payload
+//!
+//! e.g., bitcoin addresses and WIF private keys
+//!
+//! ```
+//! use makura::base58check::Base58Check;
+//!
+//! let payload = [
+//!     0x62, 0xe9, 0x07, 0xb1, 0x5c, 0xbf, 0x27, 0xd5, 0x42, 0x53,
+//!     0x99, 0xeb, 0xf6, 0xf0, 0xfb, 0x50, 0xeb, 0xb8, 0x8f, 0x18,
+//! ];
+//! let address = Base58Check::new(0, payload.to_vec());
+//! assert_eq!(address.encode(), "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa");
+//!
+//! let decoded = Base58Check::decode("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa").unwrap();
+//! assert_eq!(decoded.version(), 0);
+//! assert_eq!(decoded.payload(), payload);
+//! ```
+use crate::makura_alloc::{String, Vec};
+
+use crate::sha256::sha256;
+use crate::{BASE58, DecodeError, Decoder, Encoder};
+
+// number of checksum bytes appended to the version and payload
+const CHECKSUM_LEN: usize = 4;
+
+/// a version byte and its payload, e.g., a bitcoin address' network byte and public key hash
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Base58Check {
+    version: u8,
+    payload: Vec<u8>,
+}
+
+impl Base58Check {
+    /// creates a new value out of a version byte and a payload
+    pub fn new(version: u8, payload: Vec<u8>) -> Self {
+        Self { version, payload }
+    }
+
+    /// returns the version byte, e.g., 0x00 for p2pkh and 0x05 for p2sh bitcoin addresses
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// returns the payload, without its version and checksum
+    pub fn payload(&self) -> &[u8] {
+        &self.payload
+    }
+
+    /// returns the owned payload
+    pub fn into_payload(self) -> Vec<u8> {
+        self.payload
+    }
+
+    /// encodes the version, payload and checksum into base58
+    pub fn encode(&self) -> String {
+        let mut data = Vec::with_capacity(1 + self.payload.len() + CHECKSUM_LEN);
+        data.push(self.version);
+        data.extend_from_slice(&self.payload);
+        data.extend_from_slice(&checksum(&data));
+
+        Encoder::base58().encode_bytes(data)
+    }
+
+    /// decodes a base58check value and verifies its checksum
+    ///
+    /// # Error
+    /// returns the base58 `DecodeError`s of `Decoder::decode`, plus
+    /// * `DecodeError::InvalidLen` when the value is too short to hold a version and a checksum
+    /// * `DecodeError::ChecksumMismatch` when the checksum doesn't match the version and payload
+    pub fn decode<T: AsRef<[u8]>>(value: T) -> Result<Self, DecodeError> {
+        let value = value.as_ref();
+        let decoded = Decoder::decode(value, BASE58)?.into_bytes();
+        if decoded.len() < 1 + CHECKSUM_LEN {
+            return Err(DecodeError::InvalidLen {
+                len: value.len(),
+                base: BASE58,
+            });
+        }
+
+        let (data, carried) = decoded.split_at(decoded.len() - CHECKSUM_LEN);
+        let expected = u32::from_be_bytes([carried[0], carried[1], carried[2], carried[3]]);
+        let found = u32::from_be_bytes(checksum(data));
+        if expected != found {
+            return Err(DecodeError::ChecksumMismatch { expected, found });
+        }
+
+        Ok(Self {
+            version: data[0],
+            payload: data[1..].into(),
+        })
+    }
+}
+
+// the first 4 bytes of the double SHA-256 of data
+fn checksum(data: &[u8]) -> [u8; CHECKSUM_LEN] {
+    let hash = sha256(&sha256(data));
+
+    [hash[0], hash[1], hash[2], hash[3]]
+}
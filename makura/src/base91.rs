@@ -0,0 +1,157 @@
+#![cfg(all(
+    feature = "alloc",
+    feature = "base91",
+    feature = "encoding",
+    feature = "decoding"
+))]
+//! basE91, a denser alternative to base64 (about 23% overhead instead of 33%)
+//!
+//! unlike the other bases, basE91 has no fixed quanta,
+//! every 2 chars hold either 13 or 14 bits of the input,
+//! depending on whether the 13 bits value is past 88
+//!
+//! its 91 chars are every printable ascii char but the space, '-', '\'' and '\\'
+//!
+//! ```
+//! use makura::base91;
+//!
+//! let encoded = base91::encode("test");
+//! assert_eq!(encoded, "fPNKd");
+//!
+//! assert_eq!(base91::decode(encoded).unwrap(), b"test");
+//! ```
+use crate::makura_alloc::{String, Vec};
+
+const ALPHABET: &[u8; 91] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789!#$%&()*+,./:;<=>?@[]^_`{|}~\"";
+
+// marks the chars that are not part of the alphabet in the decode table
+const INVALID: u8 = u8::MAX;
+
+// char -> index, built at compile time
+const DECODE: [u8; 256] = {
+    let mut table = [INVALID; 256];
+    let mut idx = 0;
+    while idx < ALPHABET.len() {
+        table[ALPHABET[idx] as usize] = idx as u8;
+        idx += 1;
+    }
+
+    table
+};
+
+// the 13 bits values up to this one take 14 bits instead,
+// since 2 chars can hold up to 91 * 91 = 8281 values, i.e., 8192 + 89
+const MAX_14BITS: u32 = 88;
+
+/// errors that can occur while decoding a basE91 value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Base91Error {
+    /// a char of the value is out of the basE91 alphabet, idx is its position in the value
+    InvalidChar { char: char, idx: usize },
+}
+
+impl core::fmt::Display for Base91Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidChar { char, idx } => {
+                write!(f, "invalid basE91 char {:?} at {}", char, idx)
+            }
+        }
+    }
+}
+
+impl core::error::Error for Base91Error {}
+
+/// encodes data into basE91
+///
+/// same as the reference implementation, without its line wrapping
+pub fn encode<T: AsRef<[u8]>>(data: T) -> String {
+    let data = data.as_ref();
+    // NOTE every 2 chars hold at least 13 bits
+    let mut encoded = String::with_capacity((data.len() * 16).div_ceil(13) + 1);
+    let mut push = |value: u32| {
+        encoded.push(ALPHABET[(value % 91) as usize] as char);
+        encoded.push(ALPHABET[(value / 91) as usize] as char);
+    };
+
+    // the pending bits, least significant first
+    let mut queue = 0u32;
+    let mut bits = 0;
+    data.iter().for_each(|byte| {
+        queue |= (*byte as u32) << bits;
+        bits += 8;
+        if bits > 13 {
+            let mut value = queue & 0x1fff;
+            if value > MAX_14BITS {
+                queue >>= 13;
+                bits -= 13;
+            } else {
+                value = queue & 0x3fff;
+                queue >>= 14;
+                bits -= 14;
+            }
+            push(value);
+        }
+    });
+
+    // the last bits take a single char when they fit in it
+    if bits > 0 {
+        encoded.push(ALPHABET[(queue % 91) as usize] as char);
+        if bits > 7 || queue > 90 {
+            encoded.push(ALPHABET[(queue / 91) as usize] as char);
+        }
+    }
+
+    encoded
+}
+
+/// decodes a basE91 value, ascii whitespace is skipped wherever it is, e.g., line breaks
+///
+/// # Error
+/// returns `Base91Error::InvalidChar` when value has a char out of the basE91 alphabet
+pub fn decode<T: AsRef<[u8]>>(value: T) -> Result<Vec<u8>, Base91Error> {
+    let value = value.as_ref();
+    // NOTE every 2 chars hold at most 14 bits
+    let mut decoded = Vec::with_capacity(value.len() * 14 / 16 + 1);
+
+    // the pending bits, least significant first
+    let mut queue = 0u32;
+    let mut bits = 0;
+    // the first char of a pair, until the second one comes
+    let mut first = None;
+    for (idx, chr) in value.iter().enumerate() {
+        if chr.is_ascii_whitespace() {
+            continue;
+        }
+        let digit = match DECODE[*chr as usize] {
+            INVALID => {
+                return Err(Base91Error::InvalidChar {
+                    char: *chr as char,
+                    idx,
+                });
+            }
+            digit => digit as u32,
+        };
+
+        let Some(low) = first.take() else {
+            first = Some(digit);
+            continue;
+        };
+        let value = low + digit * 91;
+        queue |= value << bits;
+        bits += if value & 0x1fff > MAX_14BITS { 13 } else { 14 };
+        while bits > 7 {
+            decoded.push(queue as u8);
+            queue >>= 8;
+            bits -= 8;
+        }
+    }
+
+    // a last lone char holds the last bits
+    if let Some(low) = first {
+        decoded.push((queue | low << bits) as u8);
+    }
+
+    Ok(decoded)
+}
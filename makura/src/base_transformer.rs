@@ -1,46 +1,223 @@
-#![cfg(feature = "base45")]
-use crate::makura_alloc::Vec;
+#![cfg(any(
+    feature = "base45",
+    feature = "base58",
+    feature = "base62",
+    feature = "base36",
+    feature = "ascii85",
+    feature = "z85",
+    feature = "rfc1924"
+))]
 use crate::makura_core::ops;
 
+#[cfg(any(feature = "base58", feature = "base62", feature = "base36"))]
+use crate::{Alphabet, DecodeError};
+
+// a u64 has at most 64 digits, in base 2
+const MAX_DIGITS: usize = 64;
+
+#[cfg(any(
+    feature = "base45",
+    feature = "ascii85",
+    feature = "z85",
+    feature = "rfc1924"
+))]
 pub(crate) struct BaseTransformer {
     base: u8,
     value: u64,
-    seq: Vec<u8>,
+    seq: [u8; MAX_DIGITS],
+    len: usize,
 }
 
+#[cfg(any(
+    feature = "base45",
+    feature = "ascii85",
+    feature = "z85",
+    feature = "rfc1924"
+))]
 impl BaseTransformer {
     pub(crate) fn new<T: ops::Shl + ops::MulAssign + Into<u64>>(base: u8, value: T) -> Self {
         Self {
             base,
             value: value.into(),
-            seq: Vec::new(),
-        }
-    }
-
-    // does only 1 division of the whole transform sequence
-    // returns owned partially transformed self
-    pub(crate) fn transform(mut self) -> Self {
-        if self.value % self.base as u64 != 0 {
-            self.value = self.value / self.base as u64;
-            self.seq.push(self.value as u8);
+            seq: [0; MAX_DIGITS],
+            len: 0,
         }
-
-        self
     }
 
     // does the whole transform sequence all at once
     // modifies self in place
-    // reverses the seq vector as a bonus
+    // the residuals are pushed least significant first
+    //
+    // NOTE this used to stop at the first residual of 0
+    // which dropped every 0 digit that came before a non 0 one, e.g., 45 -> [] instead of [0, 1]
     pub(crate) fn transform_all(&mut self) {
-        while self.value % self.base as u64 != 0 {
+        while self.value != 0 {
             let res = self.value % self.base as u64;
-            self.value = self.value / self.base as u64;
-            self.seq.push(res as u8);
+            self.value /= self.base as u64;
+            self.seq[self.len] = res as u8;
+            self.len += 1;
         }
     }
 
-    // returns the reversed sequence of residual values
+    // fills the most significant end of the sequence with 0 digits
+    // until it is exactly width digits long
+    //
+    // NOTE the sequence is never allocated, so this can't be used to grow it past 64 digits
+    pub(crate) fn pad_to(&mut self, width: usize) {
+        self.seq[self.len.min(width)..width].fill(0);
+        self.len = width;
+    }
+
+    // returns the sequence of residual values, least significant first
     pub(crate) fn sequence(&self) -> &[u8] {
-        self.seq.as_slice()
+        &self.seq[..self.len]
     }
 }
+
+// the max number of digits of a u128, in the smallest radix bases, i.e., base36
+#[cfg(all(
+    feature = "alloc",
+    any(feature = "base58", feature = "base62", feature = "base36")
+))]
+pub(crate) const MAX_INT_DIGITS: usize = 25;
+
+// writes the digits of value in radix to out, least significant first,
+// a 0 value is a single 0 digit
+//
+// NOTE the fast path of the radix bases, a whole u128 fits in a register,
+// so there is no need for convert_radix's digit by digit carrying
+//
+// returns the number of digits written to out
+#[cfg(all(
+    feature = "alloc",
+    any(feature = "base58", feature = "base62", feature = "base36")
+))]
+pub(crate) fn int_to_digits(mut value: u128, radix: u8, out: &mut [u8; MAX_INT_DIGITS]) -> usize {
+    let mut len = 0;
+    loop {
+        out[len] = (value % radix as u128) as u8;
+        value /= radix as u128;
+        len += 1;
+        if value == 0 {
+            return len;
+        }
+    }
+}
+
+// the value of digits in radix, most significant first,
+// or None when it doesn't fit in a u128
+#[cfg(any(feature = "base58", feature = "base62", feature = "base36"))]
+pub(crate) fn digits_to_int(digits: impl Iterator<Item = u8>, radix: u8) -> Option<u128> {
+    digits.into_iter().try_fold(0u128, |value, digit| {
+        value.checked_mul(radix as u128)?.checked_add(digit as u128)
+    })
+}
+
+// converts the digits of a big number of any size from one radix into another,
+// for the bases that aren't bit aligned, e.g., base58, where the whole input is a single number
+//
+// digits are the digits of the number in from radix, most significant first,
+// the digits in to radix are written to out least significant first
+//
+// returns the number of digits written to out,
+// or None when out can't hold them all
+//
+// NOTE this is quadratic in the number of digits,
+// every new input digit is carried through every output digit found so far
+pub(crate) fn convert_radix(
+    digits: impl Iterator<Item = u8>,
+    from: u32,
+    to: u32,
+    out: &mut [u8],
+) -> Option<usize> {
+    let mut len = 0;
+    for digit in digits {
+        let mut carry = digit as u32;
+        out[..len].iter_mut().for_each(|o| {
+            carry += *o as u32 * from;
+            *o = (carry % to) as u8;
+            carry /= to;
+        });
+
+        while carry > 0 {
+            *out.get_mut(len)? = (carry % to) as u8;
+            carry /= to;
+            len += 1;
+        }
+    }
+
+    Some(len)
+}
+
+// encodes the whole value into out, as a single big endian number of radix digits,
+// for the radix bases, i.e., base58, base62 and base36
+//
+// every leading 0 byte is encoded as a leading 0th char of the alphabet,
+// since they don't change the value of the number
+//
+// out has to hold at least the base's encoded len of value.len() bytes,
+// returns the number of chars written to out
+#[cfg(any(feature = "base58", feature = "base62", feature = "base36"))]
+pub(crate) fn radix_encode(value: &[u8], out: &mut [u8], alphabet: &Alphabet, radix: u8) -> usize {
+    let zeros = value.iter().take_while(|b| **b == 0).count();
+    let digits = convert_radix(
+        value[zeros..].iter().copied(),
+        256,
+        radix as u32,
+        &mut out[zeros..],
+    )
+    .expect("out was sized with the base's encoded len");
+
+    let len = zeros + digits;
+    out[..zeros].fill(0);
+    // NOTE the digits are least significant first
+    out[zeros..len].reverse();
+    out[..len]
+        .iter_mut()
+        .for_each(|o| *o = alphabet.char_at(*o) as u8);
+
+    len
+}
+
+// decodes the len chars of a radix base value into out, as a single big endian number
+//
+// every leading 0th char of the alphabet is decoded as a leading 0 byte,
+// so the decoded len can only be bound, with decoded_len, once they are counted
+//
+// chars must have been validated against the alphabet,
+// returns the number of bytes written to out
+//
+// NOTE the exact decoded len is only known once decoded,
+// so out is only too small when the decoded digits actually overflow it,
+// in which case it holds part of them
+#[cfg(any(feature = "base58", feature = "base62", feature = "base36"))]
+pub(crate) fn radix_decode(
+    chars: impl Iterator<Item = u8> + Clone,
+    len: usize,
+    out: &mut [u8],
+    alphabet: &Alphabet,
+    radix: u8,
+    decoded_len: fn(usize) -> usize,
+) -> Result<usize, DecodeError> {
+    let indices = chars.map(|c| alphabet.idx_of(c).expect("chars were validated"));
+    let zeros = indices.clone().take_while(|idx| *idx == 0).count();
+
+    let too_small = DecodeError::OutputTooSmall {
+        len: out.len(),
+        required: zeros + decoded_len(len - zeros),
+    };
+    if out.len() < zeros {
+        return Err(too_small);
+    }
+
+    let Some(digits) = convert_radix(indices.skip(zeros), radix as u32, 256, &mut out[zeros..])
+    else {
+        return Err(too_small);
+    };
+    let len = zeros + digits;
+    out[..zeros].fill(0);
+    // NOTE the digits are least significant first
+    out[zeros..len].reverse();
+
+    Ok(len)
+}
@@ -0,0 +1,367 @@
+#![cfg(all(
+    feature = "alloc",
+    feature = "bech32",
+    feature = "encoding",
+    feature = "decoding"
+))]
+//! bech32 (BIP 173) and bech32m (BIP 350), a human readable part (hrp), a '1' separator,
+//! then 5 bits groups and a 6 chars BCH checksum, all out of a 32 chars alphabet
+//!
+//! e.g., segwit addresses, lightning invoices, nostr keys and age recipients
+//!
+//! the two variants only differ by the constant their checksum is xored with
+//!
+//! ```
+//! use makura::bech32::{Bech32, Variant};
+//!
+//! let encoded = Bech32::new("abc", b"makura".to_vec(), Variant::Bech32m).encode().unwrap();
+//! let decoded = Bech32::decode(&encoded).unwrap();
+//!
+//! assert_eq!(decoded.hrp(), "abc");
+//! assert_eq!(decoded.data(), b"makura");
+//! assert_eq!(decoded.variant(), Variant::Bech32m);
+//! ```
+//!
+//! values that aren't made of whole bytes, e.g., segwit addresses' witness version,
+//! go through `encode_groups` and `decode_groups`
+use crate::makura_alloc::{String, Vec, vec};
+
+use crate::decoders::from_5bits_groups;
+use crate::encoders::into_5bits_groups;
+
+/// the max number of chars of a bech32 value, as defined in BIP 173
+///
+/// lightning invoices go past it, see `Bech32::decode_with_limit`
+pub const MAX_LEN: usize = 90;
+
+// the max number of chars of the hrp, so that a value with an empty data part fits MAX_LEN
+const MAX_HRP_LEN: usize = 83;
+
+const SEPARATOR: u8 = b'1';
+const CHECKSUM_LEN: usize = 6;
+
+const ALPHABET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+// the generator of the BCH code
+const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+/// the checksum variant of a value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Variant {
+    /// BIP 173, e.g., segwit v0 addresses, lightning invoices and nostr keys
+    Bech32,
+    /// BIP 350, e.g., segwit v1+ (taproot) addresses
+    Bech32m,
+}
+
+impl Variant {
+    // the constant the checksum is xored with
+    const fn constant(self) -> u32 {
+        match self {
+            Self::Bech32 => 1,
+            Self::Bech32m => 0x2bc830a3,
+        }
+    }
+
+    // the variant whose constant is the polymod of a valid value
+    fn from_residue(residue: u32) -> Option<Self> {
+        [Self::Bech32, Self::Bech32m]
+            .into_iter()
+            .find(|variant| variant.constant() == residue)
+    }
+}
+
+/// errors that can occur while encoding or decoding a bech32 value
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Bech32Error {
+    /// the value mixes lowercase and uppercase chars
+    MixedCase,
+    /// the value is longer than the allowed max number of chars
+    TooLong { len: usize, max: usize },
+    /// the value has no '1' separator
+    MissingSeparator,
+    /// the hrp is empty, longer than 83 chars or contains chars out of ascii 33 to 126
+    InvalidHrp(String),
+    /// a char of the data part is out of the bech32 alphabet, idx is its position in the value
+    InvalidChar { char: char, idx: usize },
+    /// the data part is shorter than the 6 chars checksum
+    TooShort { len: usize },
+    /// the checksum matches neither bech32 nor bech32m
+    InvalidChecksum,
+    /// the 5 bits groups have more than 4 padding bits, or non zero padding bits
+    InvalidPadding,
+}
+
+impl core::fmt::Display for Bech32Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::MixedCase => write!(f, "bech32 value mixes lowercase and uppercase chars"),
+            Self::TooLong { len, max } => {
+                write!(f, "bech32 value of {} chars is longer than {}", len, max)
+            }
+            Self::MissingSeparator => write!(f, "bech32 value has no '1' separator"),
+            Self::InvalidHrp(hrp) => write!(f, "invalid bech32 hrp {:?}", hrp),
+            Self::InvalidChar { char, idx } => {
+                write!(f, "invalid bech32 char {:?} at {}", char, idx)
+            }
+            Self::TooShort { len } => {
+                write!(f, "bech32 data part of {} chars can't hold a checksum", len)
+            }
+            Self::InvalidChecksum => write!(f, "invalid bech32 checksum"),
+            Self::InvalidPadding => write!(f, "invalid bech32 padding bits"),
+        }
+    }
+}
+
+impl core::error::Error for Bech32Error {}
+
+/// an hrp and its data bytes, and the checksum variant they are encoded with
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Bech32 {
+    hrp: String,
+    data: Vec<u8>,
+    variant: Variant,
+}
+
+impl Bech32 {
+    /// creates a new value out of an hrp and data bytes
+    pub fn new<T: Into<String>>(hrp: T, data: Vec<u8>, variant: Variant) -> Self {
+        Self {
+            hrp: hrp.into(),
+            data,
+            variant,
+        }
+    }
+
+    /// returns the hrp, e.g., `bc` for bitcoin addresses or `npub` for nostr public keys
+    pub fn hrp(&self) -> &str {
+        &self.hrp
+    }
+
+    /// returns the data bytes, without their checksum
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// returns the owned data bytes
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+
+    /// returns the checksum variant
+    pub fn variant(&self) -> Variant {
+        self.variant
+    }
+
+    /// encodes the hrp, data and checksum into a lowercase value of at most `MAX_LEN` chars
+    ///
+    /// # Error
+    /// * `Bech32Error::InvalidHrp` when the hrp is empty, too long or has invalid chars
+    /// * `Bech32Error::MixedCase` when the hrp mixes lowercase and uppercase chars
+    /// * `Bech32Error::TooLong` when the value would be longer than `MAX_LEN`
+    pub fn encode(&self) -> Result<String, Bech32Error> {
+        self.encode_with_limit(MAX_LEN)
+    }
+
+    /// same as `encode`, with max chars instead of `MAX_LEN`
+    pub fn encode_with_limit(&self, max: usize) -> Result<String, Bech32Error> {
+        encode_groups(&self.hrp, &to_groups(&self.data), self.variant, max)
+    }
+
+    /// decodes a value of at most `MAX_LEN` chars and verifies its checksum
+    ///
+    /// # Error
+    /// returns the `Bech32Error`s of `decode_groups`, plus
+    /// * `Bech32Error::InvalidPadding` when the data part isn't made of whole bytes
+    pub fn decode<T: AsRef<[u8]>>(value: T) -> Result<Self, Bech32Error> {
+        Self::decode_with_limit(value, MAX_LEN)
+    }
+
+    /// same as `decode`, with max chars instead of `MAX_LEN`
+    pub fn decode_with_limit<T: AsRef<[u8]>>(value: T, max: usize) -> Result<Self, Bech32Error> {
+        let (hrp, groups, variant) = decode_groups(value, max)?;
+
+        Ok(Self {
+            hrp,
+            data: from_groups(&groups)?,
+            variant,
+        })
+    }
+}
+
+/// converts bytes into 5 bits groups, the last group is padded with 0 bits
+///
+/// ```
+/// assert_eq!(makura::bech32::to_groups(&[0xff]), [31, 28]);
+/// ```
+pub fn to_groups(data: &[u8]) -> Vec<u8> {
+    let mut groups = vec![0; (data.len() * 8).div_ceil(5)];
+    into_5bits_groups(data, data.len() * 8, &mut groups);
+
+    groups
+}
+
+/// converts 5 bits groups back into bytes
+///
+/// # Error
+/// returns `Bech32Error::InvalidPadding` when the groups end with 5 or more padding bits,
+/// or when the padding bits aren't 0s
+pub fn from_groups(groups: &[u8]) -> Result<Vec<u8>, Bech32Error> {
+    let bits = groups.len() * 5;
+    let pad = bits % 8;
+    if pad >= 5
+        || groups
+            .last()
+            .is_some_and(|group| group & ((1 << pad) - 1) != 0)
+    {
+        return Err(Bech32Error::InvalidPadding);
+    }
+
+    let mut data = vec![0; bits / 8];
+    from_5bits_groups(groups, bits / 8 * 8, &mut data);
+
+    Ok(data)
+}
+
+/// encodes an hrp and 5 bits groups, then appends their checksum
+///
+/// # Error
+/// see `Bech32::encode`
+///
+/// # Panics
+/// panics when a group is bigger than 31
+pub fn encode_groups(
+    hrp: &str,
+    groups: &[u8],
+    variant: Variant,
+    max: usize,
+) -> Result<String, Bech32Error> {
+    let hrp = valid_hrp(hrp.as_bytes())?;
+    let len = hrp.len() + 1 + groups.len() + CHECKSUM_LEN;
+    if len > max {
+        return Err(Bech32Error::TooLong { len, max });
+    }
+    assert!(
+        groups.iter().all(|group| *group < 32),
+        "bech32 groups are 5 bits"
+    );
+
+    let mut encoded = String::with_capacity(len);
+    encoded.push_str(&hrp);
+    encoded.push(SEPARATOR as char);
+    groups
+        .iter()
+        .copied()
+        .chain(checksum(hrp.as_bytes(), groups, variant))
+        .for_each(|group| encoded.push(ALPHABET[group as usize] as char));
+
+    Ok(encoded)
+}
+
+/// decodes a value of at most max chars into its lowercase hrp, its 5 bits groups
+/// and the variant its checksum was computed with
+///
+/// # Error
+/// * `Bech32Error::TooLong` when the value is longer than max chars
+/// * `Bech32Error::MixedCase` when the value mixes lowercase and uppercase chars
+/// * `Bech32Error::MissingSeparator` when the value has no '1'
+/// * `Bech32Error::InvalidHrp` when the hrp is empty, too long or has invalid chars
+/// * `Bech32Error::TooShort` when the data part can't hold a checksum
+/// * `Bech32Error::InvalidChar` when the data part has a char out of the bech32 alphabet
+/// * `Bech32Error::InvalidChecksum` when the checksum is neither bech32 nor bech32m
+pub fn decode_groups<T: AsRef<[u8]>>(
+    value: T,
+    max: usize,
+) -> Result<(String, Vec<u8>, Variant), Bech32Error> {
+    let value = value.as_ref();
+    if value.len() > max {
+        return Err(Bech32Error::TooLong {
+            len: value.len(),
+            max,
+        });
+    }
+    if value.iter().any(u8::is_ascii_lowercase) && value.iter().any(u8::is_ascii_uppercase) {
+        return Err(Bech32Error::MixedCase);
+    }
+
+    // NOTE the hrp can contain '1's, the separator is the last one
+    let sep = value
+        .iter()
+        .rposition(|c| *c == SEPARATOR)
+        .ok_or(Bech32Error::MissingSeparator)?;
+    let hrp = valid_hrp(&value[..sep])?;
+
+    let chars = &value[sep + 1..];
+    if chars.len() < CHECKSUM_LEN {
+        return Err(Bech32Error::TooShort { len: chars.len() });
+    }
+    let groups = chars
+        .iter()
+        .enumerate()
+        .map(|(idx, chr)| {
+            ALPHABET
+                .iter()
+                .position(|c| *c == chr.to_ascii_lowercase())
+                .map(|group| group as u8)
+                .ok_or(Bech32Error::InvalidChar {
+                    char: *chr as char,
+                    idx: sep + 1 + idx,
+                })
+        })
+        .collect::<Result<Vec<u8>, Bech32Error>>()?;
+
+    let variant = Variant::from_residue(polymod(
+        expand_hrp(hrp.as_bytes()).chain(groups.iter().copied()),
+    ))
+    .ok_or(Bech32Error::InvalidChecksum)?;
+
+    Ok((hrp, groups[..groups.len() - CHECKSUM_LEN].to_vec(), variant))
+}
+
+// checks that hrp is 1 to 83 chars of ascii 33 to 126 and lowercases it
+fn valid_hrp(hrp: &[u8]) -> Result<String, Bech32Error> {
+    if hrp.is_empty() || hrp.len() > MAX_HRP_LEN || hrp.iter().any(|c| !(33..=126).contains(c)) {
+        return Err(Bech32Error::InvalidHrp(
+            String::from_utf8_lossy(hrp).into_owned(),
+        ));
+    }
+    if hrp.iter().any(u8::is_ascii_lowercase) && hrp.iter().any(u8::is_ascii_uppercase) {
+        return Err(Bech32Error::MixedCase);
+    }
+
+    // NOTE the chars are ascii
+    Ok(String::from_utf8(hrp.to_ascii_lowercase()).expect("hrp is ascii"))
+}
+
+// the high bits of every hrp char, a 0, then their low bits
+fn expand_hrp(hrp: &[u8]) -> impl Iterator<Item = u8> {
+    hrp.iter()
+        .map(|c| c >> 5)
+        .chain([0])
+        .chain(hrp.iter().map(|c| c & 31))
+}
+
+// the remainder of the BCH code polynomial division of the 5 bits values
+fn polymod(values: impl Iterator<Item = u8>) -> u32 {
+    values.fold(1, |chk, value| {
+        let top = chk >> 25;
+        GENERATOR
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| (top >> idx) & 1 == 1)
+            .fold(((chk & 0x1ffffff) << 5) ^ value as u32, |chk, (_, g)| {
+                chk ^ g
+            })
+    })
+}
+
+// the 6 checksum groups of hrp and groups
+fn checksum(hrp: &[u8], groups: &[u8], variant: Variant) -> [u8; CHECKSUM_LEN] {
+    let residue = polymod(
+        expand_hrp(hrp)
+            .chain(groups.iter().copied())
+            .chain([0; CHECKSUM_LEN]),
+    ) ^ variant.constant();
+
+    core::array::from_fn(|idx| ((residue >> (5 * (5 - idx))) & 31) as u8)
+}
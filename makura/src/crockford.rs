@@ -0,0 +1,64 @@
+#![cfg(feature = "base32_crockford")]
+// crockford's base32 check symbol, the value of the encoded symbols modulo 37
+//
+// the check symbols past the 32 of the alphabet
+const CHECK_SYMBOLS: &[u8; 5] = b"*~$=U";
+
+// the value modulo 37 of the number made of the passed symbol values, most significant first
+pub(crate) fn checksum(indices: impl Iterator<Item = u8>) -> u8 {
+    checksum_from(0, indices)
+}
+
+// same as checksum, carrying on from the checksum sum of the previous symbols,
+// so that a value can be summed up one chunk at a time
+pub(crate) fn checksum_from(sum: u8, indices: impl Iterator<Item = u8>) -> u8 {
+    indices.fold(sum as u32, |sum, idx| (sum * 32 + idx as u32) % 37) as u8
+}
+
+// the check symbol of a checksum, the first 32 values being the alphabet chars
+pub(crate) fn check_symbol(checksum: u8, alphabet: &crate::Alphabet) -> u8 {
+    match checksum {
+        0..32 => alphabet.char_at(checksum) as u8,
+        _ => CHECK_SYMBOLS[checksum as usize - 32],
+    }
+}
+
+// the checksum of a check symbol, case insensitive
+pub(crate) fn check_value(chr: u8, alphabet: &crate::Alphabet) -> Option<u8> {
+    alphabet.idx_of(chr).or_else(|| {
+        CHECK_SYMBOLS
+            .iter()
+            .position(|c| *c == chr.to_ascii_uppercase())
+            .map(|idx| idx as u8 + 32)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_symbol, check_value, checksum};
+    use crate::BASE32CROCKFORD;
+
+    #[test]
+    fn test_checksum() {
+        // 1234 is "16J", 1234 % 37 = 13 -> 'D'
+        let alphabet = BASE32CROCKFORD.alphabet();
+        let sum = checksum(b"16J".iter().map(|c| alphabet.idx_of(*c).unwrap()));
+
+        assert_eq!(sum, 13);
+        assert_eq!(check_symbol(sum, alphabet), b'D');
+    }
+
+    #[test]
+    fn test_check_symbols() {
+        let alphabet = BASE32CROCKFORD.alphabet();
+
+        (0..37).for_each(|sum| {
+            assert_eq!(
+                check_value(check_symbol(sum, alphabet), alphabet),
+                Some(sum)
+            );
+        });
+        assert_eq!(check_value(b'u', alphabet), Some(36));
+        assert_eq!(check_value(b'#', alphabet), None);
+    }
+}
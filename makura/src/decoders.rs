@@ -1,29 +1,62 @@
 #![cfg(feature = "decoding")]
+#[cfg(feature = "alloc")]
 use crate::makura_alloc::{BTreeSet, Cow, String, Vec, vec};
 use crate::makura_core::Utf8Error;
 use crate::makura_core::ops;
 
-use super::{Base, idx_from_char};
+use super::{Alphabet, Base, Padding};
 
+mod ascii85;
 mod base16;
 mod base32;
+mod base36;
 mod base45;
+mod base58;
+mod base62;
 mod base64;
+mod reader;
+mod rfc1924;
+mod z85;
+mod zbase32;
 
+#[cfg(feature = "std")]
+pub use reader::{DecoderReader, StreamDecodeError};
+
+use ascii85::{
+    ascii85_decode, ascii85_decoded_len, chars_are_85, is_valid_85_len, strip_delimiters,
+};
 use base16::{base16_decode, chars_are_16, is_valid_16_len};
-use base32::{base32_decode, chars_are_32, is_valid_32_len, is_valid_32_padding};
-use base32::{base32_hex_decode, chars_are_32hex, is_valid_32hex_padding};
+#[cfg(all(feature = "alloc", feature = "bech32"))]
+pub(crate) use base32::from_5bits_groups;
+use base32::{base32_crockford_decode, chars_are_32crockford, is_valid_32crockford_len};
+use base32::{base32_decode, chars_are_32, is_valid_32_len, is_valid_32_padding, missing_32_pads};
+use base32::{base32_hex_decode, chars_are_32hex, zbase32_decode};
+use base36::{base36_decoded_len, chars_are_36, is_valid_36_len};
 use base45::{base45_decode, chars_are_45, is_valid_45_len};
-use base64::{base64_decode, chars_are_64, is_valid_64_len, is_valid_64_padding};
-use base64::{base64_url_decode, chars_are_64url, is_valid_64url_padding};
-
-use crate::{BASE16, BASE32, BASE32HEX, BASE45, BASE64, BASE64URL};
-
+use base58::{base58_decoded_len, chars_are_58, is_valid_58_len};
+use base62::{base62_decoded_len, chars_are_62, is_valid_62_len};
+use base64::{base64_decode, chars_are_64, is_valid_64_len, is_valid_64_padding, missing_64_pads};
+use base64::{base64_url_decode, chars_are_64url};
+use rfc1924::{chars_are_rfc1924, is_valid_rfc1924_len, rfc1924_decode};
+use z85::{chars_are_z85, is_valid_z85_len, z85_decode};
+#[cfg(all(feature = "alloc", feature = "zbase32"))]
+pub(crate) use zbase32::zbase32_decode_bits;
+use zbase32::{chars_are_z32, is_valid_z32_len};
+
+#[cfg(any(feature = "base58", feature = "base62", feature = "base36"))]
+use crate::base_transformer::{digits_to_int, radix_decode};
+#[cfg(feature = "base32_crockford")]
+use crate::crockford::{check_value, checksum};
+use crate::{BASE16, BASE32, BASE32CROCKFORD, BASE32HEX, BASE45, BASE58, BASE64, BASE64URL};
+use crate::{BASE36, BASE62, BASE85, BASE85RFC, Z85, ZBASE32};
+
+#[cfg(feature = "alloc")]
 #[derive(Debug, Clone, Default)]
 pub struct DecodeOutput {
     value: Vec<u8>,
 }
 
+#[cfg(feature = "alloc")]
 impl DecodeOutput {
     /// turns the decoded bytes into an ascii string
     pub fn into_ascii(self) -> String {
@@ -57,7 +90,7 @@ impl DecodeOutput {
     }
 
     pub fn as_utf8(&self) -> Result<&str, DecodeError> {
-        core::str::from_utf8(self.value.as_slice()).map_err(|e| DecodeError::Utf8Error(e))
+        core::str::from_utf8(self.value.as_slice()).map_err(DecodeError::Utf8Error)
     }
 
     pub fn as_bytes(&self) -> &[u8] {
@@ -65,22 +98,13 @@ impl DecodeOutput {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl From<Vec<u8>> for DecodeOutput {
     fn from(value: Vec<u8>) -> Self {
         Self { value }
     }
 }
 
-struct DecodeOutputRef<'a> {
-    value: &'a [u8],
-}
-
-impl<'a> From<&'a [u8]> for DecodeOutputRef<'a> {
-    fn from(value: &'a [u8]) -> Self {
-        Self { value }
-    }
-}
-
 /// errors that can occur during the decoding process of some base encoded input value
 #[derive(Debug, PartialEq, Clone)]
 pub enum DecodeError {
@@ -99,6 +123,7 @@ pub enum DecodeError {
     ZeroValidEncodings,
     /// deducer has run all checks
     /// but more than one base encoding is valid
+    #[cfg(feature = "alloc")]
     TooManyValidEncodings { bases: Vec<Base> },
     /// occurs only on base64 and 32 encoding variants
     /// and only when there is padding on the encoded value
@@ -113,6 +138,21 @@ pub enum DecodeError {
     InvalidChar { char: char, base: Base },
     /// encded value's len doesn't match with passed base
     InvalidLen { len: usize, base: Base },
+    /// the decoder's custom alphabet doesn't have as many chars as the passed base's table,
+    /// e.g., a 32 chars alphabet was used to decode a base64 value
+    AlphabetMismatch { radix: usize, base: Base },
+    /// the output buffer passed to `Decoder::decode_to_slice` can't hold the whole decoded value
+    OutputTooSmall { len: usize, required: usize },
+    /// the checksum carried by the encoded value doesn't match the one computed over the decoded data,
+    /// e.g., the CRC-24 line of an OpenPGP armor
+    ChecksumMismatch { expected: u32, found: u32 },
+    /// a group of chars decodes into a value too big for the bytes it stands for,
+    /// e.g., an ascii85 quantum past `s8W-!` (u32::MAX),
+    /// or a number too big for the integer it is decoded into, see `Decoder::decode_u64`
+    Overflow { base: Base },
+    /// base doesn't encode values as a single number, so it can't decode integers,
+    /// see `Decoder::decode_u128`
+    NonRadixEncoding(Base),
 }
 
 impl core::fmt::Display for DecodeError {
@@ -126,69 +166,186 @@ impl core::fmt::Display for DecodeError {
 
 impl core::error::Error for DecodeError {}
 
-// takes input value bytes
+// takes input value bytes and the padding char, if any
 //
 // returns last byte, len with pads, padding length
-// TODO dont remove padding
-// just change it to 0
-// DOCS fuzzing panic when input = "="
-// -> substact with overflow
 //
-// FIXME fuzzing is revealing too many panics
-// related to the padding char '='
-// best to just strictly validate the padding presence in decode input
-fn input_meta(value: &mut &[u8]) -> (u8, usize, u8) {
-    let len = value.len();
-    let mut pads = 0u8;
-    // better just validate that pads < 6
-    while value[len - pads as usize - 1] == b'=' {
-        pads += 1;
-    }
-    let last = value[len - pads as usize - 1];
-
-    (last, len, pads)
+// NOTE an input made only of padding chars, e.g., "=", used to underflow here,
+// it now goes through the same bounds checked scan as input_meta_skipping
+fn input_meta(value: &[u8], pad: Option<u8>) -> (u8, usize, u8) {
+    input_meta_skipping(value, pad, |_| false)
 }
 
-// this only exists to match Encoder struct
-// otherwise a free function works fine
-pub struct Decoder;
+// same as input_meta, but leaves out the chars that skip returns true for
+//
+// returns last byte, len with pads, padding length
+fn input_meta_skipping(
+    value: &[u8],
+    pad: Option<u8>,
+    skip: impl Fn(&u8) -> bool,
+) -> (u8, usize, u8) {
+    let mut chars = value.iter().rev().filter(|c| !skip(c));
+    let len = chars.clone().count();
+    let pads = chars.clone().take_while(|c| Some(**c) == pad).count();
+    // NOTE an input made only of padding chars has no last char,
+    // the pad then fails the last char validation instead of panicking
+    let last = chars.nth(pads).copied().or(pad).unwrap_or_default();
+
+    (last, len, pads.min(u8::MAX as usize) as u8)
+}
+
+/// decodes base encoded values
+///
+/// the associated functions (`decode`, `decode_deduce`...) always use the default decoding options,
+/// to change them, build a decoder with `Decoder::new` and the `with_*` methods then call `apply`
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Decoder {
+    padding: Padding,
+    // None means the standard alphabet of the passed base
+    alphabet: Option<Alphabet>,
+    skip_whitespace: bool,
+    // crockford base32 only, whether the last char is a mod 37 check symbol
+    #[cfg(feature = "base32_crockford")]
+    check_symbol: bool,
+}
 
 impl Decoder {
-    // turns back chars from the encoding table to their table index values
-    fn into_table_idx(value: &[u8], base: &Base) -> Result<Vec<u8>, DecodeError> {
-        // TODO convert paddings into necessary 0 bytes
-        // in case they are not there
-        let mut err: Option<DecodeError> = None;
-        let val = value
-            .into_iter()
-            .map(|c| match *c as char {
-                '=' => {
-                    if base == &BASE16 || base == &BASE45 {
-                        // this error is no longer reachable
-                        Err(DecodeError::NonPaddableEncoding(*base))
-                    } else {
-                        Ok(0)
-                    }
-                }
-                val => idx_from_char(val, base),
-            })
-            .take_while(|res| {
-                if let Err(e) = res {
-                    err = Some(e.clone());
+    /// creates a new decoder with the default options
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-                    false
-                } else {
-                    true
-                }
-            })
-            .map(|res| res.unwrap())
-            .collect::<Vec<u8>>();
+    /// returns the padding policy of the decoder
+    pub fn padding(&self) -> &Padding {
+        &self.padding
+    }
+
+    /// sets the padding policy of the decoder
+    ///
+    /// * `Padding::Required` rejects unpadded base 64(url) and 32(hex) input
+    /// * `Padding::Omitted` rejects padded input
+    /// * `Padding::Indifferent` accepts both
+    pub fn with_padding(mut self, padding: Padding) -> Self {
+        self.padding = padding;
+
+        self
+    }
+
+    /// returns the custom alphabet of the decoder, if it has one
+    pub fn alphabet(&self) -> Option<&Alphabet> {
+        self.alphabet.as_ref()
+    }
+
+    /// sets a custom alphabet to decode with, instead of the standard alphabet of the passed base
+    ///
+    /// the base passed to `apply` still decides the bits layout,
+    /// so it has to have as many chars as the alphabet
+    pub fn with_alphabet(mut self, alphabet: Alphabet) -> Self {
+        self.alphabet = Some(alphabet);
+
+        self
+    }
+
+    /// returns true if the decoder skips ascii whitespace
+    pub fn skip_whitespace(&self) -> bool {
+        self.skip_whitespace
+    }
+
+    /// sets whether the decoder skips ascii whitespace (spaces, tabs, line breaks...)
+    /// found anywhere in the input, e.g., MIME bodies or PEM blocks wrapped at 76/64 columns
+    ///
+    /// lengths and padding are validated on the remaining chars only
+    ///
+    /// a whitespace char that is part of the alphabet is never skipped,
+    /// i.e., the spaces of a base45 value are still decoded
+    ///
+    /// ```
+    /// use makura::{BASE64, Decoder};
+    ///
+    /// let dec = Decoder::new().with_skip_whitespace(true);
+    /// assert_eq!(dec.apply("Zm9v\r\nYmFy\r\n", BASE64).unwrap().as_bytes(), b"foobar");
+    /// ```
+    pub fn with_skip_whitespace(mut self, skip: bool) -> Self {
+        self.skip_whitespace = skip;
 
-        if let Some(e) = err {
-            return Err(e);
+        self
+    }
+
+    /// returns true if the decoder expects crockford base32 values to end with a check symbol
+    #[cfg(feature = "base32_crockford")]
+    pub fn check_symbol(&self) -> bool {
+        self.check_symbol
+    }
+
+    /// sets whether the last char of crockford base32 values is a mod 37 check symbol,
+    /// which is then verified against the decoded value
+    ///
+    /// ignored by the other bases
+    ///
+    /// ```
+    /// use makura::{BASE32CROCKFORD, Decoder};
+    ///
+    /// let dec = Decoder::new().with_check_symbol(true);
+    /// assert_eq!(dec.apply("0k9-0q", BASE32CROCKFORD).unwrap().as_bytes(), [0x04, 0xd2]);
+    /// assert!(dec.apply("0k9-0r", BASE32CROCKFORD).is_err());
+    /// ```
+    #[cfg(feature = "base32_crockford")]
+    pub fn with_check_symbol(mut self, check_symbol: bool) -> Self {
+        self.check_symbol = check_symbol;
+
+        self
+    }
+
+    // whether chr is skipped when decoding values of base with alphabet
+    pub(crate) fn skips(&self, chr: u8, base: &Base, alphabet: &Alphabet) -> bool {
+        (self.skip_whitespace && chr.is_ascii_whitespace() && alphabet.idx_of(chr).is_none())
+            || base.is_ignored_char(chr)
+    }
+
+    // the alphabet used to decode values of the passed base
+    fn alphabet_for(&self, base: &Base) -> Result<Alphabet, DecodeError> {
+        match self.alphabet {
+            Some(alphabet) if alphabet.radix() != base.radix() as usize => {
+                Err(DecodeError::AlphabetMismatch {
+                    radix: alphabet.radix(),
+                    base: *base,
+                })
+            }
+            Some(alphabet) => Ok(alphabet),
+            None => Ok(*base.alphabet()),
         }
+    }
+
+    // turns back chars from the encoding table to their table index values
+    // indices has to be at least as long as value
+    fn into_table_idx(
+        value: &[u8],
+        base: &Base,
+        alphabet: &Alphabet,
+        indices: &mut [u8],
+    ) -> Result<(), DecodeError> {
+        let pad = alphabet.pad() as u8;
+        value
+            .iter()
+            .zip(indices.iter_mut())
+            .try_for_each(|(c, idx)| {
+                *idx = match alphabet.idx_of(*c) {
+                    Some(idx) => idx,
+                    None if *c == pad && base.is_paddable() => 0,
+                    // this error is no longer reachable
+                    None if *c == pad && base.is_rfc() => {
+                        return Err(DecodeError::NonPaddableEncoding(*base));
+                    }
+                    None => {
+                        return Err(DecodeError::InvalidChar {
+                            char: *c as char,
+                            base: *base,
+                        });
+                    }
+                };
 
-        Ok(val)
+                Ok(())
+            })
     }
 
     /// decodes a given string
@@ -210,8 +367,140 @@ impl Decoder {
     //
     // FIXME since the input chars correctness is not validated at first
     // the fn panics before it gets to invalidate some bad input value
+    #[cfg(feature = "alloc")]
     pub fn decode<T: AsRef<[u8]>>(value: T, base: Base) -> Result<DecodeOutput, DecodeError> {
-        let mut value = value.as_ref();
+        Self::new().apply(value, base)
+    }
+
+    /// decodes a given string using self's options
+    /// takes encoded string and user provided base of the string encoding
+    ///
+    /// returns a result of the decoded string value or a `DecodeError`
+    ///
+    /// ```
+    /// use makura::{BASE64URL, Decoder, Padding};
+    ///
+    /// let dec = Decoder::new().with_padding(Padding::Omitted);
+    /// assert_eq!(dec.apply("eyJhbGciOiJIUzI1NiJ9", BASE64URL).unwrap().as_bytes(), br#"{"alg":"HS256"}"#);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn apply<T: AsRef<[u8]>>(&self, value: T, base: Base) -> Result<DecodeOutput, DecodeError> {
+        let value = value.as_ref();
+        let mut decoded = vec![0; self.decoded_len_estimate(value.len(), base)];
+        let len = self.decode_to_slice(value, base, &mut decoded)?;
+        decoded.truncate(len);
+
+        Ok(decoded.into())
+    }
+
+    /// decodes a single number of base into a u64, e.g., short ids or url slugs
+    ///
+    /// # Error
+    /// returns the errors of `decode_u128`,
+    /// and `DecodeError::Overflow` when the number doesn't fit in a u64
+    #[cfg(any(feature = "base58", feature = "base62", feature = "base36"))]
+    pub fn decode_u64<T: AsRef<[u8]>>(&self, value: T, base: Base) -> Result<u64, DecodeError> {
+        u64::try_from(self.decode_u128(value, base)?).map_err(|_| DecodeError::Overflow { base })
+    }
+
+    /// decodes a single number of base, most significant digit first, using self's options
+    ///
+    /// the fast path of `apply` for integers, without its arbitrary precision conversion;
+    /// leading 0 digits don't change the number
+    ///
+    /// # Error
+    /// * `DecodeError::InvalidLen` when value has no digits
+    /// * `DecodeError::InvalidChar` when value has a char out of the alphabet
+    /// * `DecodeError::Overflow` when the number doesn't fit in a u128
+    /// * `DecodeError::NonRadixEncoding` when base doesn't encode values as a single number,
+    ///   i.e., isn't base58, base62 or base36
+    ///
+    /// ```
+    /// use makura::{BASE62, Decoder};
+    ///
+    /// assert_eq!(Decoder::new().decode_u128("1LY7VK", BASE62), Ok(1234567890));
+    /// ```
+    #[cfg(any(feature = "base58", feature = "base62", feature = "base36"))]
+    pub fn decode_u128<T: AsRef<[u8]>>(&self, value: T, base: Base) -> Result<u128, DecodeError> {
+        if !base.is_radix() {
+            return Err(DecodeError::NonRadixEncoding(base));
+        }
+
+        let alphabet = self.alphabet_for(&base)?;
+        let digits = value
+            .as_ref()
+            .iter()
+            .filter(|c| !self.skips(**c, &base, &alphabet));
+        if digits.clone().next().is_none() {
+            return Err(DecodeError::InvalidLen { len: 0, base });
+        }
+        if let Some(c) = digits.clone().find(|c| alphabet.idx_of(**c).is_none()) {
+            return Err(DecodeError::InvalidChar {
+                char: *c as char,
+                base,
+            });
+        }
+
+        digits_to_int(digits.filter_map(|c| alphabet.idx_of(*c)), base.radix())
+            .ok_or(DecodeError::Overflow { base })
+    }
+
+    /// returns the max length of the decoded value of an encoded input of len chars
+    ///
+    /// the estimate is exact for unpadded input made of whole quanta,
+    /// otherwise it is at most a quantum of bytes too long
+    ///
+    /// base58, base62 and base36 have no quanta, every char is counted as a byte,
+    /// since a leading '1' ('0' in base62 and base36) decodes to a whole 0 byte;
+    /// likewise, every ascii85 char is counted as 4 bytes, since a 'z' decodes to 4 0 bytes
+    ///
+    /// ```
+    /// use makura::{BASE64, Decoder};
+    ///
+    /// assert_eq!(Decoder::new().decoded_len_estimate(8, BASE64), 6);
+    /// assert_eq!(Decoder::new().decoded_len_estimate(6, BASE64), 6);
+    /// ```
+    pub fn decoded_len_estimate(&self, len: usize, base: Base) -> usize {
+        match base {
+            BASE58 | BASE62 | BASE36 => return len,
+            BASE85 => return ascii85_decoded_len(len),
+            _ => (),
+        }
+
+        len.div_ceil(base.encoded_quantum()) * base.quantum()
+    }
+
+    /// decodes a given string using self's options into the start of out, without allocating
+    ///
+    /// returns the number of decoded bytes written to out
+    ///
+    /// # Error
+    /// returns the same errors as `apply`,
+    /// plus `DecodeError::OutputTooSmall` when out is shorter than the decoded value,
+    /// in which case nothing is written to out
+    ///
+    /// the chars are only validated while decoding,
+    /// so out may hold part of the decoded value on an `InvalidChar` error
+    ///
+    /// the decoded len of a base58 value is only known once decoded,
+    /// so out may also hold part of it on an `OutputTooSmall` error,
+    /// whose required len is then an upper bound
+    ///
+    /// ```
+    /// use makura::{BASE64, Decoder};
+    ///
+    /// let mut out = [0u8; 8];
+    /// let len = Decoder::new().decode_to_slice("Zm9vYmE=", BASE64, &mut out).unwrap();
+    ///
+    /// assert_eq!(&out[..len], b"fooba");
+    /// ```
+    pub fn decode_to_slice<T: AsRef<[u8]>>(
+        &self,
+        value: T,
+        base: Base,
+        out: &mut [u8],
+    ) -> Result<usize, DecodeError> {
+        let value = value.as_ref();
 
         // fuzzing input = "=" panics
         // TODO remove this
@@ -221,39 +510,196 @@ impl Decoder {
         // }
 
         if value.is_empty() {
-            return Ok(Default::default());
+            return Ok(0);
         }
 
-        let (last, len, pads) = input_meta(&mut value);
+        #[cfg(feature = "base32_crockford")]
+        if self.has_check_symbol(&base) {
+            return self.decode_checked(value, base, out);
+        }
 
-        let valid = base.is_valid_len(len);
-        if valid.is_err() {
-            return valid.map(|_| Default::default());
+        let alphabet = self.alphabet_for(&base)?;
+        let skip = |c: &u8| self.skips(*c, &base, &alphabet);
+
+        // NOTE only the rfc bases have a padding char, the pad of the others is a placeholder
+        // out of their alphabet, which is rejected as any other invalid char
+        let pad = base.is_rfc().then_some(alphabet.pad() as u8);
+        let (last, len, pads) = if self.skip_whitespace || base.has_ignored_chars() {
+            input_meta_skipping(value, pad, skip)
+        } else {
+            input_meta(value, pad)
+        };
+
+        // only skipped chars
+        if len == 0 {
+            return Ok(0);
         }
 
-        let valid = base.is_valid_padding(last, pads);
-        if valid.is_err() {
-            return valid.map(|_| Default::default());
+        base.is_valid_len(len, self.padding)?;
+        base.is_valid_padding_in(last, pads, self.padding, &alphabet)?;
+        // whatever the policy, padded input is always made of whole quanta
+        if pads > 0 {
+            base.is_valid_len(len, Padding::Required)?;
         }
 
-        let indices = Self::into_table_idx(value, &base);
-        let indices = if indices.is_err() {
-            return indices.map(|_| Default::default());
-        } else {
-            indices.unwrap()
+        // an unpadded last quantum follows the same last char rules as a padded one
+        let missing = if pads == 0 { base.missing_pads(len) } else { 0 };
+        if missing > 0 {
+            base.is_valid_padding_in(last, missing, Padding::Required, &alphabet)?;
+        }
+
+        if base.has_variable_len() {
+            return Self::decode_variable(value, skip, len, &base, &alphabet, out);
+        }
+
+        // NOTE input_meta left the padding chars in value
+        let len = len - pads as usize;
+        let chars = base.encoded_quantum();
+        let required = len / chars * base.quantum() + base.partial_decoded_len(len % chars);
+        if out.len() < required {
+            return Err(DecodeError::OutputTooSmall {
+                len: out.len(),
+                required,
+            });
+        }
+
+        let mut quantum = [0u8; 8];
+        let mut filled = 0;
+        let mut outs = out[..required].chunks_mut(base.quantum());
+        for chr in value.iter().filter(|c| !skip(c)).take(len) {
+            quantum[filled] = *chr;
+            filled += 1;
+
+            if filled == chars {
+                let out = outs.next().expect("out was sized to hold every quantum");
+                Self::decode_quantum(&quantum[..filled], &base, &alphabet, out)?;
+                filled = 0;
+            }
+        }
+        if filled > 0 {
+            let out = outs.next().expect("out was sized to hold every quantum");
+            Self::decode_quantum(&quantum[..filled], &base, &alphabet, out)?;
+        }
+
+        Ok(required)
+    }
+
+    // whether the values of base end with a check symbol
+    #[cfg_attr(not(feature = "base32_crockford"), allow(unused_variables))]
+    pub(crate) fn has_check_symbol(&self, base: &Base) -> bool {
+        #[cfg(feature = "base32_crockford")]
+        return self.check_symbol && *base == BASE32CROCKFORD;
+        #[cfg(not(feature = "base32_crockford"))]
+        return false;
+    }
+
+    // decodes a crockford base32 value whose last char is its check symbol into out,
+    // then verifies the symbol against the decoded chars
+    #[cfg(feature = "base32_crockford")]
+    fn decode_checked(
+        &self,
+        value: &[u8],
+        base: Base,
+        out: &mut [u8],
+    ) -> Result<usize, DecodeError> {
+        let alphabet = self.alphabet_for(&base)?;
+        let Some(last) = value
+            .iter()
+            .rposition(|c| !self.skips(*c, &base, &alphabet))
+        else {
+            return Ok(0);
         };
+        let expected = check_value(value[last], &alphabet).ok_or(DecodeError::InvalidChar {
+            char: value[last] as char,
+            base,
+        })?;
+
+        let value = &value[..last];
+        let len = self
+            .with_check_symbol(false)
+            .decode_to_slice(value, base, out)?;
+        // NOTE the chars were validated by the decoding, only the skipped ones are filtered out
+        let found = checksum(value.iter().filter_map(|c| alphabet.idx_of(*c)));
+        if found != expected {
+            return Err(DecodeError::ChecksumMismatch {
+                expected: expected as u32,
+                found: found as u32,
+            });
+        }
 
-        Ok(match base {
-            BASE64 => base64_decode(indices),
-            BASE64URL => base64_url_decode(indices),
-            BASE45 => base45_decode(indices),
-            BASE32 => base32_decode(indices),
-            BASE32HEX => base32_hex_decode(indices),
-            BASE16 => base16_decode(indices),
+        Ok(len)
+    }
+
+    // decodes the len (skipped chars excluded) chars of a base whose encoded len depends on the value,
+    // e.g., base58 or ascii85, into out
+    //
+    // every char is validated before anything is written to out
+    fn decode_variable(
+        value: &[u8],
+        skip: impl Fn(&u8) -> bool,
+        len: usize,
+        base: &Base,
+        alphabet: &Alphabet,
+        out: &mut [u8],
+    ) -> Result<usize, DecodeError> {
+        match *base {
+            BASE58 | BASE62 | BASE36 => {
+                let chars = value.iter().filter(|c| !skip(c)).copied();
+                if let Some(c) = chars.clone().find(|c| alphabet.idx_of(*c).is_none()) {
+                    return Err(DecodeError::InvalidChar {
+                        char: c as char,
+                        base: *base,
+                    });
+                }
+
+                let decoded_len = match *base {
+                    BASE58 => base58_decoded_len,
+                    BASE62 => base62_decoded_len,
+                    _ => base36_decoded_len,
+                };
+
+                radix_decode(chars, len, out, alphabet, base.radix(), decoded_len)
+            }
+            BASE85 => {
+                let value = strip_delimiters(value, &skip);
+
+                ascii85_decode(value.iter().filter(|c| !skip(c)).copied(), out, alphabet)
+            }
+            _ => unreachable!("{} has a fixed encoded len", base),
         }
-        .into())
     }
 
+    // decodes a single, possibly partial, quantum of chars into out
+    fn decode_quantum(
+        quantum: &[u8],
+        base: &Base,
+        alphabet: &Alphabet,
+        out: &mut [u8],
+    ) -> Result<(), DecodeError> {
+        let chars = base.encoded_quantum();
+        // the chars that a partial quantum lacks are decoded as 0s, same as padding chars
+        let mut indices = [0u8; 8];
+        Self::into_table_idx(quantum, base, alphabet, &mut indices)?;
+        let indices = &indices[..chars];
+
+        match *base {
+            BASE64 => base64_decode(indices, out),
+            BASE64URL => base64_url_decode(indices, out),
+            BASE45 => base45_decode(&indices[..quantum.len()], out),
+            BASE32 => base32_decode(indices, out),
+            BASE32HEX => base32_hex_decode(indices, out),
+            BASE32CROCKFORD => base32_crockford_decode(indices, out),
+            ZBASE32 => zbase32_decode(indices, out),
+            BASE16 => base16_decode(indices, out),
+            Z85 => z85_decode(indices, out)?,
+            BASE85RFC => rfc1924_decode(&indices[..quantum.len()], out)?,
+            _ => unreachable!("{} has a variable encoded len", base),
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "alloc")]
     pub fn decode_deduce<T: AsRef<[u8]> + core::fmt::Debug>(
         value: T,
     ) -> Result<DecodeOutput, DecodeError> {
@@ -268,6 +714,7 @@ impl Decoder {
     }
 
     /// same as using decode -> unwrap -. into_ascii
+    #[cfg(feature = "alloc")]
     pub fn decode_ascii<T: AsRef<[u8]>>(value: T, base: Base) -> Result<String, DecodeError> {
         let res = Self::decode(value, base);
         if res.is_err() {
@@ -278,6 +725,7 @@ impl Decoder {
     }
 
     /// same as using decode -> unwrap -. into_utf8
+    #[cfg(feature = "alloc")]
     pub fn decode_utf8<T: AsRef<[u8]>>(value: T, base: Base) -> Result<String, DecodeError> {
         let res = Self::decode(value, base);
         if res.is_err() {
@@ -288,30 +736,39 @@ impl Decoder {
     }
 }
 
+#[cfg(feature = "alloc")]
 /// a set of bases (Base)
 ///
 /// uses a BTreeSet for its inner value
 #[derive(Debug, Clone)]
 pub struct Bases {
     bases: BTreeSet<Base>,
+    skip_whitespace: bool,
 }
 
+#[cfg(feature = "alloc")]
 impl Default for Bases {
     fn default() -> Self {
         Self {
-            bases: BTreeSet::from_iter([BASE32, BASE32HEX, BASE16, BASE45, BASE64, BASE64URL]),
+            bases: BTreeSet::from_iter([
+                BASE32, BASE32HEX, BASE16, BASE45, BASE64, BASE64URL, BASE58, Z85,
+            ]),
+            skip_whitespace: false,
         }
     }
 }
 
+#[cfg(feature = "alloc")]
 impl From<&[Base]> for Bases {
     fn from(value: &[Base]) -> Self {
         Self {
-            bases: value.into_iter().map(|b| *b).collect(),
+            bases: value.iter().copied().collect(),
+            skip_whitespace: false,
         }
     }
 }
 
+#[cfg(feature = "alloc")]
 impl From<&mut Bases> for Vec<Base> {
     fn from(value: &mut Bases) -> Self {
         let mut val = core::mem::take(value);
@@ -320,11 +777,39 @@ impl From<&mut Bases> for Vec<Base> {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl Bases {
     /// returns a new Bases with an empty BTreeSet
     pub fn new() -> Self {
         Self {
             bases: BTreeSet::new(),
+            skip_whitespace: false,
+        }
+    }
+
+    /// sets whether the deduction skips ascii whitespace found anywhere in the input,
+    /// same as `Decoder::with_skip_whitespace`
+    ///
+    /// NOTE spaces are skipped too,
+    /// so a base45 value containing spaces is deduced as if it had none
+    pub fn with_skip_whitespace(mut self, skip: bool) -> Self {
+        self.skip_whitespace = skip;
+
+        self
+    }
+
+    // the input value without the chars that self skips
+    fn skipped<'a>(&self, value: &'a [u8]) -> Cow<'a, [u8]> {
+        if self.skip_whitespace && value.iter().any(|c| c.is_ascii_whitespace()) {
+            Cow::Owned(
+                value
+                    .iter()
+                    .copied()
+                    .filter(|c| !c.is_ascii_whitespace())
+                    .collect(),
+            )
+        } else {
+            Cow::Borrowed(value)
         }
     }
 
@@ -376,6 +861,14 @@ impl Bases {
         &mut self.bases
     }
 
+    // drops the valid non rfc bases when an rfc 4648/9285 base is valid too,
+    // e.g., most hex values are also valid base58 and z85
+    fn rank(&mut self) {
+        if self.bases.iter().any(Base::is_rfc) {
+            self.bases.retain(Base::is_rfc);
+        }
+    }
+
     // DOCS encloding validation
     // * len matches check
     // * all chars match check
@@ -403,8 +896,13 @@ impl Bases {
     ///
     /// this method always returns an error if there is more than 1 valid base
     /// it doesnt do estimations or guesses, only definitive answers
+    ///
+    /// the bases outside of rfc 4648 and base45 (rfc 9285), e.g., base58 and z85,
+    /// are only considered when none of those is valid,
+    /// since their alphabets hold most of the rfc bases' chars
     pub fn deduce_encoding<T: AsRef<[u8]>>(&mut self, value: T) -> Result<Base, DecodeError> {
-        let mut value = value.as_ref();
+        let value = self.skipped(value.as_ref());
+        let value = value.as_ref();
 
         // fuzzing input = "=" panics
         // if value.iter().all(|b| *b == 61) {
@@ -415,19 +913,21 @@ impl Bases {
             return Ok(BASE64);
         }
 
-        let (last, len, pads) = input_meta(&mut value);
+        let (last, len, pads) = input_meta(value, Some(crate::PAD as u8));
 
         *self = Self {
             bases: self
                 .bases()
                 .into_iter()
                 .filter(|b| {
-                    b.is_valid_len(len).is_ok()
-                        && b.is_valid_padding(last, pads).is_ok()
+                    b.is_valid_len(len, Padding::Required).is_ok()
+                        && b.is_valid_padding(last, pads, Padding::Required).is_ok()
                         && b.are_valid_chars(value).is_ok()
                 })
                 .collect(),
+            skip_whitespace: self.skip_whitespace,
         };
+        self.rank();
 
         if self.is_empty() {
             return Err(DecodeError::ZeroValidEncodings);
@@ -449,7 +949,8 @@ impl Bases {
     /// basically this considers the passed bases to be sorted
     /// and the least values (bases[0], base[1]...) as the most likely correct answer
     pub fn deduce_sorted<T: AsRef<[u8]>>(&mut self, value: T) -> Result<Base, DecodeError> {
-        let mut value = value.as_ref();
+        let value = self.skipped(value.as_ref());
+        let value = value.as_ref();
 
         // fuzzing input = "=" panics
         // if value.iter().all(|b| *b == 61) {
@@ -460,19 +961,21 @@ impl Bases {
             return Ok(BASE64);
         }
 
-        let (last, len, pads) = input_meta(&mut value);
+        let (last, len, pads) = input_meta(value, Some(crate::PAD as u8));
 
         *self = Self {
             bases: self
                 .bases()
                 .into_iter()
                 .filter(|b| {
-                    b.is_valid_len(len).is_ok()
-                        && b.is_valid_padding(last, pads).is_ok()
+                    b.is_valid_len(len, Padding::Required).is_ok()
+                        && b.is_valid_padding(last, pads, Padding::Required).is_ok()
                         && b.are_valid_chars(value).is_ok()
                 })
                 .collect(),
+            skip_whitespace: self.skip_whitespace,
         };
+        self.rank();
 
         if self.is_empty() {
             return Err(DecodeError::ZeroValidEncodings);
@@ -492,7 +995,7 @@ impl Bases {
     }
 
     /// calls self's deduce_encoding on Self::default,
-    /// which is all 6 known bases
+    /// which is the rfc 4648 bases, base45, base58 and z85
     /// takes the input value to be analyzed
     pub fn deduce_default<T: AsRef<[u8]>>(value: T) -> Result<Base, DecodeError> {
         Self::default().deduce_sorted(value)
@@ -562,22 +1065,52 @@ impl Base {
     // a padding char to indicate that the last byte value was padded by least bits 00
     //
     // * if CL = 3 && NP = 0 => the last value can be any value in the base64 encoding table
-    pub fn is_valid_padding(&self, last_byte: u8, pads: u8) -> Result<(), DecodeError> {
+    //
+    // with `Padding::Omitted`, any padding char on a base 64(url) or 32(hex) input is invalid
+    pub fn is_valid_padding(
+        &self,
+        last_byte: u8,
+        pads: u8,
+        padding: Padding,
+    ) -> Result<(), DecodeError> {
+        // NOTE the pads are counted as '=' chars,
+        // which are a plain alphabet char of some non paddable bases, e.g., z85
+        if self.alphabet().contains(crate::PAD) {
+            return Ok(());
+        }
+
+        self.is_valid_padding_in(last_byte, pads, padding, self.alphabet())
+    }
+
+    // same as is_valid_padding
+    // but looks up the last char in the passed alphabet instead of self's standard one
+    pub(crate) fn is_valid_padding_in(
+        &self,
+        last_byte: u8,
+        pads: u8,
+        padding: Padding,
+        alphabet: &Alphabet,
+    ) -> Result<(), DecodeError> {
         if pads == 0 {
             return Ok(());
         }
 
+        if padding == Padding::Omitted && self.is_paddable() {
+            return Err(DecodeError::InvalidPadding { base: *self, pads });
+        }
+
         match pads {
-            1 if BASE64 == *self => is_valid_64_padding(last_byte, pads),
-            1 if BASE64URL == *self => is_valid_64url_padding(last_byte, pads),
-            1 if BASE32 == *self => is_valid_32_padding(last_byte, pads),
-            1 if BASE32HEX == *self => is_valid_32hex_padding(last_byte, pads),
-            2 if BASE64 == *self => is_valid_64_padding(last_byte, pads),
-            2 if BASE64URL == *self => is_valid_64url_padding(last_byte, pads),
+            1 | 2 if BASE64 == *self || BASE64URL == *self => {
+                is_valid_64_padding(last_byte, pads, alphabet, self)
+            }
+            1 if BASE32 == *self || BASE32HEX == *self => {
+                is_valid_32_padding(last_byte, pads, alphabet, self)
+            }
             1 | 2 => Err(DecodeError::NonPaddableEncoding(*self)),
-            3 | 4 | 6 if BASE32 == *self => is_valid_32_padding(last_byte, pads),
-            3 | 4 | 6 if BASE32HEX == *self => is_valid_32hex_padding(last_byte, pads),
-            _ if BASE45 == *self || BASE16 == *self => Err(DecodeError::NonPaddableEncoding(*self)),
+            3 | 4 | 6 if BASE32 == *self || BASE32HEX == *self => {
+                is_valid_32_padding(last_byte, pads, alphabet, self)
+            }
+            _ if !self.is_paddable() => Err(DecodeError::NonPaddableEncoding(*self)),
             _ => Err(DecodeError::InvalidPadding { base: *self, pads }),
         }
     }
@@ -586,12 +1119,34 @@ impl Base {
     // the len checks should go first  <- least costly
     // then the pad checks            <- in between
     // then finally the chars checks <- costliest
-    pub fn is_valid_len(&self, len: usize) -> Result<(), DecodeError> {
+    //
+    // len is the full input len, padding chars included;
+    // `Padding::Indifferent` accepts both the padded and the unpadded valid lengths
+    pub fn is_valid_len(&self, len: usize, padding: Padding) -> Result<(), DecodeError> {
         match *self {
-            BASE64 | BASE64URL => is_valid_64_len(len),
+            BASE64 | BASE64URL => is_valid_64_len(len, padding),
             BASE45 => is_valid_45_len(len),
-            BASE32 | BASE32HEX => is_valid_32_len(len),
+            BASE32 | BASE32HEX => is_valid_32_len(len, padding),
+            BASE32CROCKFORD => is_valid_32crockford_len(len),
+            ZBASE32 => is_valid_z32_len(len),
             BASE16 => is_valid_16_len(len),
+            BASE58 => is_valid_58_len(len),
+            BASE62 => is_valid_62_len(len),
+            BASE36 => is_valid_36_len(len),
+            BASE85 => is_valid_85_len(len),
+            Z85 => is_valid_z85_len(len),
+            BASE85RFC => is_valid_rfc1924_len(len),
+        }
+    }
+
+    // the number of padding chars that an unpadded input of len chars would have had
+    // expects len to be valid for self
+    fn missing_pads(&self, len: usize) -> u8 {
+        match *self {
+            BASE64 | BASE64URL => missing_64_pads(len),
+            BASE32 | BASE32HEX => missing_32_pads(len),
+            BASE45 | BASE16 | BASE58 | BASE85 | Z85 | BASE85RFC | BASE32CROCKFORD | ZBASE32
+            | BASE62 | BASE36 => 0,
         }
     }
 
@@ -605,7 +1160,15 @@ impl Base {
             BASE45 => chars_are_45(input),
             BASE32 => chars_are_32(input),
             BASE32HEX => chars_are_32hex(input),
+            BASE32CROCKFORD => chars_are_32crockford(input),
+            ZBASE32 => chars_are_z32(input),
             BASE16 => chars_are_16(input),
+            BASE58 => chars_are_58(input),
+            BASE62 => chars_are_62(input),
+            BASE36 => chars_are_36(input),
+            BASE85 => chars_are_85(input),
+            Z85 => chars_are_z85(input),
+            BASE85RFC => chars_are_rfc1924(input),
         }
     }
 }
@@ -650,9 +1213,9 @@ mod bench_deduce_encoding {
 /// this module tests that the decoding errors happen as intended when they are supposed to
 #[cfg(test)]
 mod test_errors {
-    use super::vec;
-    use super::{BASE16, BASE32, BASE32HEX, BASE64, BASE64URL};
+    use super::{BASE16, BASE32, BASE64, BASE64URL};
     use super::{DecodeError, Decoder};
+    use crate::makura_alloc::vec;
 
     #[test]
     fn zero_valid_encodings() {
@@ -687,7 +1250,9 @@ mod test_errors {
     #[test]
     fn non_paddable_encoding() {
         let input = "09==";
-        let Err(e) = Decoder::into_table_idx(input.as_bytes(), &BASE16) else {
+        let Err(e) =
+            Decoder::into_table_idx(input.as_bytes(), &BASE16, BASE16.alphabet(), &mut [0; 4])
+        else {
             unreachable!("input string is not proper base64 encoded, so how did it pass")
         };
 
@@ -764,7 +1329,7 @@ mod test_errors {
     fn utf8_error() {
         let input = [65, 66];
 
-        let Err(DecodeError::Utf8Error(e)) = Decoder::decode_utf8(input, BASE16) else {
+        let Err(DecodeError::Utf8Error(_)) = Decoder::decode_utf8(input, BASE16) else {
             unreachable!("input string is not proper base64 encoded, so how did it pass")
         };
     }
@@ -0,0 +1,162 @@
+#![cfg(any(feature = "ascii85", feature = "z85", feature = "rfc1924"))]
+use crate::{Alphabet, BASE85, Base};
+
+use super::DecodeError;
+
+const ZEROS: u8 = b'z';
+const SPACES: u8 = b'y';
+
+const BEGIN: &[u8] = b"<~";
+const END: &[u8] = b"~>";
+
+// the max number of bytes decoded out of len ascii85 chars,
+// every char can be a 'z' or 'y' that stands for 4 bytes
+pub fn ascii85_decoded_len(len: usize) -> usize {
+    len * 4
+}
+
+// the value without its adobe `<~` and `~>` delimiters, if it has them
+//
+// the chars that skip returns true for are left out of both ends first
+pub fn strip_delimiters(value: &[u8], skip: impl Fn(&u8) -> bool) -> &[u8] {
+    let start = value.iter().position(|c| !skip(c)).unwrap_or(value.len());
+    let end = value
+        .iter()
+        .rposition(|c| !skip(c))
+        .map_or(start, |idx| idx + 1);
+    let value = &value[start..end];
+    let value = value.strip_prefix(BEGIN).unwrap_or(value);
+
+    value.strip_suffix(END).unwrap_or(value)
+}
+
+// goes through the chars one quantum at a time, passing the decoded bytes of every quantum to emit
+//
+// a 'z' or 'y' is only valid in place of a whole quantum,
+// and a partial last quantum needs at least 2 chars
+fn walk(
+    chars: impl Iterator<Item = u8>,
+    alphabet: &Alphabet,
+    mut emit: impl FnMut(&[u8]),
+) -> Result<(), DecodeError> {
+    let zeros = !alphabet.contains(ZEROS as char);
+    let spaces = !alphabet.contains(SPACES as char);
+
+    let mut quantum = [0u8; 5];
+    let mut filled = 0;
+    let mut len = 0;
+    for chr in chars {
+        len += 1;
+        match chr {
+            ZEROS if zeros && filled == 0 => emit(&[0; 4]),
+            SPACES if spaces && filled == 0 => emit(b"    "),
+            _ => {
+                quantum[filled] = alphabet.idx_of(chr).ok_or(DecodeError::InvalidChar {
+                    char: chr as char,
+                    base: BASE85,
+                })?;
+                filled += 1;
+
+                if filled == 5 {
+                    emit(&ascii85_decode_quantum(&quantum, BASE85)?);
+                    filled = 0;
+                }
+            }
+        }
+    }
+
+    match filled {
+        0 => Ok(()),
+        1 => Err(DecodeError::InvalidLen { len, base: BASE85 }),
+        _ => {
+            // a partial quantum is padded with the last char of the alphabet ('u'),
+            // which rounds its value up to the bytes that were encoded
+            quantum[filled..].fill(84);
+            emit(&ascii85_decode_quantum(&quantum, BASE85)?[..filled - 1]);
+
+            Ok(())
+        }
+    }
+}
+
+// decodes a whole quantum of 5 indices into its 4 bytes,
+// base is only that of the overflow error
+pub fn ascii85_decode_quantum(indices: &[u8; 5], base: Base) -> Result<[u8; 4], DecodeError> {
+    let value = indices
+        .iter()
+        .fold(0u64, |value, idx| value * 85 + *idx as u64);
+
+    // NOTE 5 base 85 digits go up to 85^5 - 1, which is past u32::MAX
+    u32::try_from(value)
+        .map(u32::to_be_bytes)
+        .map_err(|_| DecodeError::Overflow { base })
+}
+
+// decodes the chars of an ascii85 value, without its delimiters, into out
+//
+// returns the number of bytes written to out
+pub fn ascii85_decode(
+    chars: impl Iterator<Item = u8> + Clone,
+    out: &mut [u8],
+    alphabet: &Alphabet,
+) -> Result<usize, DecodeError> {
+    // the chars are validated and the decoded len is counted before anything is written
+    let mut required = 0;
+    walk(chars.clone(), alphabet, |bytes| required += bytes.len())?;
+    if out.len() < required {
+        return Err(DecodeError::OutputTooSmall {
+            len: out.len(),
+            required,
+        });
+    }
+
+    let mut len = 0;
+    walk(chars, alphabet, |bytes| {
+        out[len..len + bytes.len()].copy_from_slice(bytes);
+        len += bytes.len();
+    })?;
+
+    Ok(len)
+}
+
+pub fn is_valid_85_len(_len: usize) -> Result<(), DecodeError> {
+    // NOTE the abbreviations and delimiters make the len alone meaningless,
+    // it is validated while decoding
+    Ok(())
+}
+
+pub fn chars_are_85(value: &[u8]) -> Result<(), DecodeError> {
+    match value
+        .iter()
+        .find(|c| BASE85.alphabet().idx_of(**c).is_none() && !BASE85.is_extra_char(**c))
+    {
+        Some(c) => Err(DecodeError::InvalidChar {
+            char: *c as char,
+            base: BASE85,
+        }),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod test_validators {
+    use super::{chars_are_85, strip_delimiters};
+
+    #[test]
+    fn test0_85() {
+        let output = "<~!\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstuzy~>";
+
+        assert_eq!(chars_are_85(output.as_bytes()), Ok(()));
+        assert!(chars_are_85(b"v").is_err());
+    }
+
+    #[test]
+    fn test_strip_delimiters() {
+        assert_eq!(strip_delimiters(b"<~87cURDZ~>", |_| false), b"87cURDZ");
+        assert_eq!(strip_delimiters(b"87cURDZ", |_| false), b"87cURDZ");
+        assert_eq!(
+            strip_delimiters(b"\n<~87cURDZ~>\r\n", |c| c.is_ascii_whitespace()),
+            b"87cURDZ"
+        );
+    }
+}
@@ -1,28 +1,22 @@
 #![cfg(feature = "base16")]
 use crate::BASE16;
-use crate::makura_alloc::Vec;
 
 use super::{
     DecodeError,
     chars_range::{HEX, NUM},
 };
 
-fn into_8bits_bytes(value: Vec<u8>) -> Vec<u8> {
-    value
-        .chunks(2)
-        .map(|b| {
-            let mut mask = 0u8;
-            mask |= b[0];
-            mask <<= 4;
-            mask |= b[1];
+fn into_8bits_byte(indices: &[u8]) -> u8 {
+    let mut mask = 0u8;
+    mask |= indices[0];
+    mask <<= 4;
+    mask |= indices[1];
 
-            mask
-        })
-        .collect()
+    mask
 }
 
-pub fn base16_decode(indices: Vec<u8>) -> Vec<u8> {
-    into_8bits_bytes(indices)
+pub fn base16_decode(indices: &[u8], out: &mut [u8]) {
+    out[0] = into_8bits_byte(indices);
 }
 
 pub fn is_valid_16_len(len: usize) -> Result<(), DecodeError> {
@@ -35,7 +29,7 @@ pub fn is_valid_16_len(len: usize) -> Result<(), DecodeError> {
 
 pub fn chars_are_16(value: &[u8]) -> Result<(), DecodeError> {
     if let Some(e) = value
-        .into_iter()
+        .iter()
         .map(|c| {
             if NUM.contains(c) || HEX.contains(c) {
                 Ok(())
@@ -1,11 +1,16 @@
-#![cfg(any(feature = "base32", feature = "base32_hex"))]
-use crate::makura_alloc::Vec;
-use crate::{BASE32, BASE32HEX};
+#![cfg(any(
+    feature = "base32",
+    feature = "base32_hex",
+    feature = "base32_crockford",
+    feature = "zbase32",
+    feature = "bech32"
+))]
+use crate::{Alphabet, Base};
+use crate::{BASE32, BASE32CROCKFORD, BASE32HEX, Padding};
 
 use super::{
     DecodeError,
     chars_range::{N32, NUM, PAD, UPC},
-    idx_from_char,
 };
 
 /// DOCS
@@ -21,110 +26,121 @@ use super::{
 /// (3) The final quantum of encoding input is exactly 16 bits; here, the
 ///     final unit of encoded output will be three characters followed by
 ///     one "=" padding character.
-
 // to implement the other decoders
 // only a different version of this function is needed
 // the other functions stay the same
-fn into_40bits_bytes(value: Vec<u8>) -> Vec<u64> {
-    // NOTE len must be an integra multiple of 4
-    value
-        .chunks(8)
-        .map(|b| {
-            let mut mask = 0u64;
-            mask |= b[0] as u64;
-            mask <<= 5;
-            mask |= b[1] as u64;
-            mask <<= 5;
-            mask |= b[2] as u64;
-            mask <<= 5;
-            mask |= b[3] as u64;
-            mask <<= 5;
-            mask |= b[4] as u64;
-            mask <<= 5;
-            mask |= b[5] as u64;
-            mask <<= 5;
-            mask |= b[6] as u64;
-            mask <<= 5;
-            mask |= b[7] as u64;
-
-            mask
-        })
-        .collect()
+fn into_40bits_chunk(indices: &[u8]) -> u64 {
+    // NOTE a quantum is always 8 indices, the missing ones being 0s
+    indices
+        .iter()
+        .take(8)
+        .fold(0u64, |mask, idx| (mask << 5) | *idx as u64)
 }
 
-// get back 8 bit bytes from the 24bits bytes
-fn into_8bits_bytes(value: Vec<u64>) -> Vec<u8> {
-    let mut bytes = value
-        .into_iter()
-        .flat_map(|b| {
-            [
-                // same as ( b >> 32 ) as u8
-                ((b & 0xff00000000) >> 32) as u8,
-                ((b & 0xff000000) >> 24) as u8,
-                ((b & 0xff0000) >> 16) as u8,
-                ((b & 0xff00) >> 8) as u8,
-                b as u8,
-            ]
-        })
-        .collect::<Vec<u8>>();
-    while let Some(0) = bytes.last() {
-        bytes.pop();
-    }
+// get back 8 bit bytes from the 40bits chunk
+//
+// out is exactly 5 bytes minus the bytes missing from the last quantum;
+// the decoded bytes themselves are never looked at,
+// so trailing 0 bytes of the original data survive the round trip
+fn into_8bits_bytes(chunk: u64, out: &mut [u8]) {
+    [
+        // same as ( chunk >> 32 ) as u8
+        ((chunk & 0xff00000000) >> 32) as u8,
+        ((chunk & 0xff000000) >> 24) as u8,
+        ((chunk & 0xff0000) >> 16) as u8,
+        ((chunk & 0xff00) >> 8) as u8,
+        chunk as u8,
+    ]
+    .into_iter()
+    .zip(out.iter_mut())
+    .for_each(|(b, o)| *o = b);
+}
 
-    bytes
+// unpacks the first bits bits of 5 bits groups into out, most significant first;
+// the bits of the last byte past bits are 0s, whatever the last group was
+//
+// out has to hold at least bits.div_ceil(8) bytes,
+// returns the number of bytes written to out
+#[cfg(all(feature = "alloc", any(feature = "zbase32", feature = "bech32")))]
+pub fn from_5bits_groups(groups: &[u8], bits: usize, out: &mut [u8]) -> usize {
+    let len = bits.div_ceil(8);
+    out[..len].fill(0);
+    groups.iter().enumerate().for_each(|(idx, group)| {
+        (idx * 5..idx * 5 + 5)
+            .zip((0..5).rev())
+            .filter(|(bit, shift)| *bit < bits && group & (1 << shift) != 0)
+            .for_each(|(bit, _)| out[bit / 8] |= 0x80 >> (bit % 8));
+    });
+
+    len
 }
 
 #[cfg(feature = "base32")]
-pub fn base32_decode(indices: Vec<u8>) -> Vec<u8> {
-    let bytes = into_40bits_bytes(indices);
-
-    into_8bits_bytes(bytes)
+pub fn base32_decode(indices: &[u8], out: &mut [u8]) {
+    into_8bits_bytes(into_40bits_chunk(indices), out)
 }
 
 #[cfg(feature = "base32_hex")]
-pub fn base32_hex_decode(indices: Vec<u8>) -> Vec<u8> {
-    let bytes = into_40bits_bytes(indices);
+pub fn base32_hex_decode(indices: &[u8], out: &mut [u8]) {
+    into_8bits_bytes(into_40bits_chunk(indices), out)
+}
+
+pub fn base32_crockford_decode(indices: &[u8], out: &mut [u8]) {
+    into_8bits_bytes(into_40bits_chunk(indices), out)
+}
 
-    into_8bits_bytes(bytes)
+#[cfg(feature = "zbase32")]
+pub fn zbase32_decode(indices: &[u8], out: &mut [u8]) {
+    into_8bits_bytes(into_40bits_chunk(indices), out)
 }
 
-pub fn is_valid_32_len(len: usize) -> Result<(), DecodeError> {
-    if len % 8 == 0 {
+pub fn is_valid_32_len(len: usize, padding: Padding) -> Result<(), DecodeError> {
+    // unpadded, the last quantum can only be 2, 4, 5, 7 or 8 chars long
+    if match padding {
+        Padding::Required => len % 8 == 0,
+        Padding::Omitted | Padding::Indifferent => [0, 2, 4, 5, 7].contains(&(len % 8)),
+    } {
         Ok(())
     } else {
         Err(DecodeError::InvalidLen { len, base: BASE32 })
     }
 }
 
-pub fn is_valid_32hex_padding(last_byte: u8, pads: u8) -> Result<(), DecodeError> {
-    let char = last_byte as char;
-    let last_byte = idx_from_char(char, &BASE32HEX);
-    if last_byte.is_err() {
-        return last_byte.map(|_| ());
+// crockford base32 is never padded, len excludes the hyphens
+pub fn is_valid_32crockford_len(len: usize) -> Result<(), DecodeError> {
+    if [0, 2, 4, 5, 7].contains(&(len % 8)) {
+        Ok(())
+    } else {
+        Err(DecodeError::InvalidLen {
+            len,
+            base: BASE32CROCKFORD,
+        })
     }
-    let last_byte = last_byte.unwrap();
+}
 
-    match pads {
-        1 if last_byte % 8 == 0 => Ok(()),
-        3 if last_byte % 2 == 0 => Ok(()),
-        4 if last_byte % 16 == 0 => Ok(()),
-        6 if last_byte % 4 == 0 => Ok(()),
-        1 | 3 | 4 | 6 => Err(DecodeError::InvalidLastCharForPadding {
-            char,
-            idx: last_byte,
-            pads,
-        }),
-        _ => unreachable!("both 0 and invalid values were checked before getting here"),
+// the number of padding chars that an unpadded input of len chars would have had
+pub fn missing_32_pads(len: usize) -> u8 {
+    match len % 8 {
+        0 => 0,
+        2 => 6,
+        4 => 4,
+        5 => 3,
+        7 => 1,
+        _ => unreachable!("len was validated before getting here"),
     }
 }
 
-pub fn is_valid_32_padding(last_byte: u8, pads: u8) -> Result<(), DecodeError> {
+// base32 and base32 hex only differ by their alphabets
+pub fn is_valid_32_padding(
+    last_byte: u8,
+    pads: u8,
+    alphabet: &Alphabet,
+    base: &Base,
+) -> Result<(), DecodeError> {
     let char = last_byte as char;
-    let last_byte = idx_from_char(char, &BASE32);
-    if last_byte.is_err() {
-        return last_byte.map(|_| ());
-    }
-    let last_byte = last_byte.unwrap();
+    let Some(last_byte) = alphabet.idx_of(last_byte) else {
+        return Err(DecodeError::InvalidChar { char, base: *base });
+    };
 
     match pads {
         1 if last_byte % 8 == 0 => Ok(()),
@@ -142,7 +158,7 @@ pub fn is_valid_32_padding(last_byte: u8, pads: u8) -> Result<(), DecodeError> {
 
 pub fn chars_are_32(value: &[u8]) -> Result<(), DecodeError> {
     if let Some(e) = value
-        .into_iter()
+        .iter()
         .map(|c| {
             if UPC.contains(c) || N32.contains(c) || *c == PAD {
                 Ok(())
@@ -163,7 +179,7 @@ pub fn chars_are_32(value: &[u8]) -> Result<(), DecodeError> {
 
 pub fn chars_are_32hex(value: &[u8]) -> Result<(), DecodeError> {
     if let Some(e) = value
-        .into_iter()
+        .iter()
         .map(|c| {
             if NUM.contains(c) || (b'A'..=b'V').contains(c) || *c == PAD {
                 Ok(())
@@ -182,9 +198,34 @@ pub fn chars_are_32hex(value: &[u8]) -> Result<(), DecodeError> {
     Ok(())
 }
 
+// crockford base32 chars are case insensitive, with I and L read as 1 and O as 0,
+// and hyphens can be anywhere
+pub fn chars_are_32crockford(value: &[u8]) -> Result<(), DecodeError> {
+    match value
+        .iter()
+        .find(|c| BASE32CROCKFORD.alphabet().idx_of(**c).is_none() && **c != b'-')
+    {
+        Some(c) => Err(DecodeError::InvalidChar {
+            char: *c as char,
+            base: BASE32CROCKFORD,
+        }),
+        None => Ok(()),
+    }
+}
+
 #[cfg(test)]
 mod test_validators {
-    use super::{chars_are_32, chars_are_32hex};
+    use super::{chars_are_32, chars_are_32crockford, chars_are_32hex};
+
+    #[test]
+    fn test_32crockford() {
+        let output = "0123456789ABCDEFGHJKMNPQRSTVWXYZ-abcdefghjkmnpqrstvwxyz-IiLlOo";
+
+        assert_eq!(chars_are_32crockford(output.as_bytes()), Ok(()));
+        ["U", "u", "=", "*"].into_iter().for_each(|c| {
+            assert!(chars_are_32crockford(c.as_bytes()).is_err());
+        });
+    }
 
     #[test]
     fn test_32hex() {
@@ -0,0 +1,46 @@
+#![cfg(feature = "base36")]
+use crate::BASE36;
+
+use super::DecodeError;
+
+// the max number of bytes decoded out of len base36 chars, leading '0's excluded
+//
+// every char holds log(36) / log(256) ~= 0.646 bytes
+pub fn base36_decoded_len(len: usize) -> usize {
+    (len * 647).div_ceil(1000)
+}
+
+pub fn is_valid_36_len(_len: usize) -> Result<(), DecodeError> {
+    // NOTE same as base58, any number of chars is a valid number
+    Ok(())
+}
+
+pub fn chars_are_36(value: &[u8]) -> Result<(), DecodeError> {
+    match value
+        .iter()
+        .find(|c| BASE36.alphabet().idx_of(**c).is_none())
+    {
+        Some(c) => Err(DecodeError::InvalidChar {
+            char: *c as char,
+            base: BASE36,
+        }),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod test_validators {
+    use super::chars_are_36;
+
+    #[test]
+    fn test0_36() {
+        let output = "0123456789abcdefghijklmnopqrstuvwxyz";
+
+        assert_eq!(chars_are_36(output.as_bytes()), Ok(()));
+        // case insensitive
+        assert_eq!(chars_are_36(output.to_uppercase().as_bytes()), Ok(()));
+        ["-", "_", "+", "/", "="].into_iter().for_each(|c| {
+            assert!(chars_are_36(c.as_bytes()).is_err());
+        });
+    }
+}
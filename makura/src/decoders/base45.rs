@@ -1,6 +1,5 @@
 #![cfg(feature = "base45")]
 use crate::BASE45;
-use crate::makura_alloc::Vec;
 
 use super::{
     DecodeError,
@@ -13,51 +12,31 @@ use super::{
 // recorded panic input cases:
 // `2JY`
 // `2.Y`
-fn into_base45_values(bytes: Vec<u8>) -> Vec<u32> {
-    let mut chunks = bytes.chunks(3);
-    let last = chunks.next_back().unwrap();
-
-    let mut values: Vec<u32> = chunks
-        // .inspect(|c| println!("{:?}", c))
-        .map(|b| b[2] as u32 * 45 * 45 + b[1] as u32 * 45 + b[0] as u32)
-        .collect();
-
-    let last = {
-        if last.len() == 3 {
-            last[2] as u32 * 45 * 45 + last[1] as u32 * 45 + last[0] as u32
-        } else if last.len() == 2 {
-            last[1] as u32 * 45 + last[0] as u32
-        } else {
-            unreachable!("last chunk len can only be 2 or 3");
-        }
-    };
-    values.push(last);
-
-    values
+fn into_base45_value(indices: &[u8]) -> u32 {
+    match *indices {
+        [one, two, three] => three as u32 * 45 * 45 + two as u32 * 45 + one as u32,
+        [one, two] => two as u32 * 45 + one as u32,
+        _ => unreachable!("quantum len can only be 2 or 3"),
+    }
 }
 
-// get back 8 bit bytes from the 24bits bytes
-fn into_base265_values(value: Vec<u32>) -> Vec<u8> {
-    let mut bytes = value.into_iter();
-    let last = bytes.next_back().unwrap();
-    let mut bytes = bytes
-        .flat_map(|b| [((b & 0xff00) >> 8) as u8, b as u8])
-        .collect::<Vec<u8>>();
-
-    if last < u8::MAX as u32 {
-        bytes.push(last as u8);
-    } else {
-        bytes.push((last >> 8) as u8);
-        bytes.push(last as u8);
+// get back 8 bit bytes from the base45 value
+//
+// a trailing quantum of 2 chars always holds a single byte,
+// even when that byte's value is u8::MAX
+fn into_base265_values(value: u32, out: &mut [u8]) {
+    match out {
+        [one] => *one = value as u8,
+        [one, two] => {
+            *one = ((value & 0xff00) >> 8) as u8;
+            *two = value as u8;
+        }
+        _ => unreachable!("a quantum decodes into 1 or 2 bytes"),
     }
-
-    bytes
 }
 
-pub fn base45_decode(indices: Vec<u8>) -> Vec<u8> {
-    let bytes = into_base45_values(indices);
-
-    into_base265_values(bytes)
+pub fn base45_decode(indices: &[u8], out: &mut [u8]) {
+    into_base265_values(into_base45_value(indices), out)
 }
 
 pub fn is_valid_45_len(len: usize) -> Result<(), DecodeError> {
@@ -70,7 +49,7 @@ pub fn is_valid_45_len(len: usize) -> Result<(), DecodeError> {
 
 pub fn chars_are_45(value: &[u8]) -> Result<(), DecodeError> {
     if let Some(e) = value
-        .into_iter()
+        .iter()
         .map(|c| {
             if NUM.contains(c)
                 || UPC.contains(c)
@@ -0,0 +1,45 @@
+#![cfg(feature = "base58")]
+use crate::BASE58;
+
+use super::DecodeError;
+
+// the max number of bytes decoded out of len base58 chars, leading '1's excluded
+//
+// every char holds log(58) / log(256) ~= 0.732 bytes
+pub fn base58_decoded_len(len: usize) -> usize {
+    (len * 733).div_ceil(1000)
+}
+
+pub fn is_valid_58_len(_len: usize) -> Result<(), DecodeError> {
+    // NOTE base58 isn't made of quanta, any number of chars is a valid number
+    Ok(())
+}
+
+pub fn chars_are_58(value: &[u8]) -> Result<(), DecodeError> {
+    match value
+        .iter()
+        .find(|c| BASE58.alphabet().idx_of(**c).is_none())
+    {
+        Some(c) => Err(DecodeError::InvalidChar {
+            char: *c as char,
+            base: BASE58,
+        }),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod test_validators {
+    use super::chars_are_58;
+
+    #[test]
+    fn test0_58() {
+        let output = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+        assert_eq!(chars_are_58(output.as_bytes()), Ok(()));
+        // no 0, O, I nor l
+        ["0", "O", "I", "l"].into_iter().for_each(|c| {
+            assert!(chars_are_58(c.as_bytes()).is_err());
+        });
+    }
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "base62")]
+use crate::BASE62;
+
+use super::DecodeError;
+
+// the max number of bytes decoded out of len base62 chars, leading '0's excluded
+//
+// every char holds log(62) / log(256) ~= 0.745 bytes
+pub fn base62_decoded_len(len: usize) -> usize {
+    (len * 745).div_ceil(1000)
+}
+
+pub fn is_valid_62_len(_len: usize) -> Result<(), DecodeError> {
+    // NOTE same as base58, any number of chars is a valid number
+    Ok(())
+}
+
+pub fn chars_are_62(value: &[u8]) -> Result<(), DecodeError> {
+    match value
+        .iter()
+        .find(|c| BASE62.alphabet().idx_of(**c).is_none())
+    {
+        Some(c) => Err(DecodeError::InvalidChar {
+            char: *c as char,
+            base: BASE62,
+        }),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod test_validators {
+    use super::chars_are_62;
+
+    #[test]
+    fn test0_62() {
+        let output = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+        assert_eq!(chars_are_62(output.as_bytes()), Ok(()));
+        ["-", "_", "+", "/", "="].into_iter().for_each(|c| {
+            assert!(chars_are_62(c.as_bytes()).is_err());
+        });
+    }
+}
@@ -1,11 +1,10 @@
 #![cfg(any(feature = "base64", feature = "base64_url"))]
-use crate::makura_alloc::Vec;
-use crate::{BASE64, BASE64URL};
+use crate::{Alphabet, Base};
+use crate::{BASE64, BASE64URL, Padding};
 
 use super::{
     DecodeError,
     chars_range::{LWC, NUM, PAD, UPC},
-    idx_from_char,
 };
 
 /// DOCS
@@ -21,71 +20,67 @@ use super::{
 /// (3) The final quantum of encoding input is exactly 16 bits; here, the
 ///     final unit of encoded output will be three characters followed by
 ///     one "=" padding character.
-
 // to implement the other decoders
 // only a different version of this function is needed
 // the other functions stay the same
-fn into_24bits_bytes(value: Vec<u8>) -> Vec<u32> {
-    // NOTE len must be an integra multiple of 4
-    value
-        .chunks(4)
-        // .inspect(|c| println!("{:?}", c))
-        .map(|b| {
-            let mut mask = 0u32;
-            mask |= b[0] as u32;
-            mask <<= 6;
-            mask |= b[1] as u32;
-            mask <<= 6;
-            mask |= b[2] as u32;
-            mask <<= 6;
-            mask |= b[3] as u32;
-
-            mask
-        })
-        .collect()
+fn into_24bits_chunk(indices: &[u8]) -> u32 {
+    // NOTE a quantum is always 4 indices, the missing ones being 0s
+    let mut mask = 0u32;
+    mask |= indices[0] as u32;
+    mask <<= 6;
+    mask |= indices[1] as u32;
+    mask <<= 6;
+    mask |= indices[2] as u32;
+    mask <<= 6;
+    mask |= indices[3] as u32;
+
+    mask
 }
 
-// get back 8 bit bytes from the 24bits bytes
-fn into_8bits_bytes(value: Vec<u32>) -> Vec<u8> {
-    let mut bytes = value
+// get back 8 bit bytes from the 24bits chunk
+//
+// out is exactly 3 bytes minus 1 byte per padding char (RFC 4648 section 4);
+// the decoded bytes themselves are never looked at,
+// so trailing 0 bytes of the original data survive the round trip
+fn into_8bits_bytes(chunk: u32, out: &mut [u8]) {
+    [(chunk >> 16) as u8, (chunk >> 8) as u8, chunk as u8]
         .into_iter()
-        .flat_map(|b| {
-            [
-                ((b & 0xff0000) >> 16) as u8,
-                ((b & 0xff00) >> 8) as u8,
-                b as u8,
-            ]
-        })
-        .collect::<Vec<u8>>();
-    while let Some(0) = bytes.last() {
-        bytes.pop();
-    }
-
-    bytes
+        .zip(out.iter_mut())
+        .for_each(|(b, o)| *o = b);
 }
 
 #[cfg(feature = "base64")]
-pub fn base64_decode(indices: Vec<u8>) -> Vec<u8> {
-    let bytes = into_24bits_bytes(indices);
-
-    into_8bits_bytes(bytes)
+pub fn base64_decode(indices: &[u8], out: &mut [u8]) {
+    into_8bits_bytes(into_24bits_chunk(indices), out)
 }
 
 #[cfg(feature = "base64_url")]
-pub fn base64_url_decode(indices: Vec<u8>) -> Vec<u8> {
-    let bytes = into_24bits_bytes(indices);
-
-    into_8bits_bytes(bytes)
+pub fn base64_url_decode(indices: &[u8], out: &mut [u8]) {
+    into_8bits_bytes(into_24bits_chunk(indices), out)
 }
 
-pub fn is_valid_64_len(len: usize) -> Result<(), DecodeError> {
-    if len % 4 == 0 {
+pub fn is_valid_64_len(len: usize, padding: Padding) -> Result<(), DecodeError> {
+    // unpadded, the last quantum can be 2 or 3 chars long but never 1
+    if match padding {
+        Padding::Required => len % 4 == 0,
+        Padding::Omitted | Padding::Indifferent => len % 4 != 1,
+    } {
         Ok(())
     } else {
         Err(DecodeError::InvalidLen { len, base: BASE64 })
     }
 }
 
+// the number of padding chars that an unpadded input of len chars would have had
+pub fn missing_64_pads(len: usize) -> u8 {
+    match len % 4 {
+        0 => 0,
+        2 => 2,
+        3 => 1,
+        _ => unreachable!("len was validated before getting here"),
+    }
+}
+
 // NOTE pad = 0 and pad = invalid value are both to be handled by the
 // only function calling these fns
 //
@@ -93,35 +88,18 @@ pub fn is_valid_64_len(len: usize) -> Result<(), DecodeError> {
 // otherwise, checking irrelevant (0, invalid) values at every is_valid_x_padding fn is a pain
 //
 // this fn expects pads to be a valid base64 padding value
-pub fn is_valid_64_padding(last_byte: u8, pads: u8) -> Result<(), DecodeError> {
-    let char = last_byte as char;
-    let last_byte = idx_from_char(char, &BASE64);
-    if last_byte.is_err() {
-        return last_byte.map(|_| ());
-    }
-    let last_byte = last_byte.unwrap();
-
-    match pads {
-        1 if last_byte % 4 == 0 => Ok(()),
-        2 if last_byte % 16 == 0 => Ok(()),
-        1 | 2 => Err(DecodeError::InvalidLastCharForPadding {
-            char,
-            idx: last_byte,
-            pads,
-        }),
-        _ => unreachable!("both 0 and invalid values were checked before getting here"),
-    }
-}
-
-// WARN validator fns should take a ref to the input then
-// do their checks on that
-pub fn is_valid_64url_padding(last_byte: u8, pads: u8) -> Result<(), DecodeError> {
+//
+// base64 and base64 url only differ by their alphabets
+pub fn is_valid_64_padding(
+    last_byte: u8,
+    pads: u8,
+    alphabet: &Alphabet,
+    base: &Base,
+) -> Result<(), DecodeError> {
     let char = last_byte as char;
-    let last_byte = idx_from_char(char, &BASE64URL);
-    if last_byte.is_err() {
-        return last_byte.map(|_| ());
-    }
-    let last_byte = last_byte.unwrap();
+    let Some(last_byte) = alphabet.idx_of(last_byte) else {
+        return Err(DecodeError::InvalidChar { char, base: *base });
+    };
 
     match pads {
         1 if last_byte % 4 == 0 => Ok(()),
@@ -137,7 +115,7 @@ pub fn is_valid_64url_padding(last_byte: u8, pads: u8) -> Result<(), DecodeError
 
 pub fn chars_are_64(value: &[u8]) -> Result<(), DecodeError> {
     if let Some(e) = value
-        .into_iter()
+        .iter()
         .map(|c| {
             if UPC.contains(c)
                 || LWC.contains(c)
@@ -163,7 +141,7 @@ pub fn chars_are_64(value: &[u8]) -> Result<(), DecodeError> {
 
 pub fn chars_are_64url(value: &[u8]) -> Result<(), DecodeError> {
     if let Some(e) = value
-        .into_iter()
+        .iter()
         .map(|c| {
             if UPC.contains(c)
                 || LWC.contains(c)
@@ -189,9 +167,7 @@ pub fn chars_are_64url(value: &[u8]) -> Result<(), DecodeError> {
 
 #[cfg(test)]
 mod test_validators {
-    use super::{
-        chars_are_64, chars_are_64url, is_valid_64_len, is_valid_64_padding, is_valid_64url_padding,
-    };
+    use super::{chars_are_64, chars_are_64url};
 
     #[test]
     fn test0_64url() {
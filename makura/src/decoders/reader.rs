@@ -0,0 +1,385 @@
+#![cfg(feature = "std")]
+use crate::makura_alloc::Vec;
+use crate::makura_std::io;
+
+use super::{Alphabet, BASE85, Base, DecodeError, Decoder};
+
+// number of encoded bytes read from the inner reader at once
+const READ_LEN: usize = 4096;
+
+/// the error of a `DecoderReader`,
+/// a decode error along with the absolute offset in the encoded stream where it occured
+///
+/// it is returned as the inner error of an `io::Error` of kind `InvalidData`
+#[derive(Debug, PartialEq, Clone)]
+pub struct StreamDecodeError {
+    offset: u64,
+    error: DecodeError,
+}
+
+impl StreamDecodeError {
+    /// the offset of the bad char in the encoded stream,
+    ///
+    /// for errors that are not about a single char, e.g., `InvalidLen`,
+    /// this is the offset of the last (partial) quantum of the stream
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// the underlying decode error
+    pub fn error(&self) -> &DecodeError {
+        &self.error
+    }
+}
+
+impl core::fmt::Display for StreamDecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} at offset {}", self.error, self.offset)
+    }
+}
+
+impl core::error::Error for StreamDecodeError {}
+
+impl From<StreamDecodeError> for io::Error {
+    fn from(value: StreamDecodeError) -> Self {
+        io::Error::new(io::ErrorKind::InvalidData, value)
+    }
+}
+
+/// decodes an encoded stream read from an inner reader
+///
+/// only whole quanta are decoded as they are read;
+/// the last quantum of the stream is held back until the inner reader is exhausted,
+/// since only it can hold padding
+///
+/// the ascii85 quanta are told apart by their 5 chars or their 'z'/'y' abbreviation,
+/// the stream can start with a `<~` delimiter and end with a `~>` one
+///
+/// base58, base62 and base36 have no quanta,
+/// so for those the whole stream is read before it is decoded,
+/// as it is for crockford base32 with a check symbol
+///
+/// decode errors are returned as `io::Error`s of kind `InvalidData` wrapping a `StreamDecodeError`,
+/// once an error occurs every following read returns it again
+///
+/// ```
+/// use std::io::Read;
+/// use makura::{BASE64, Decoder, DecoderReader};
+///
+/// let mut reader = DecoderReader::new(&b"Zm9vYmE="[..], BASE64, Decoder::new());
+/// let mut decoded = Vec::new();
+/// reader.read_to_end(&mut decoded).unwrap();
+///
+/// assert_eq!(decoded, b"fooba");
+/// ```
+pub struct DecoderReader<R: io::Read> {
+    decoder: Decoder,
+    base: Base,
+    inner: R,
+    // encoded bytes read but not yet decoded
+    pending: Vec<u8>,
+    // absolute offset of pending's first byte in the encoded stream
+    offset: u64,
+    // decoded bytes not yet read
+    out: Vec<u8>,
+    out_pos: usize,
+    done: bool,
+    error: Option<StreamDecodeError>,
+}
+
+impl<R: io::Read> DecoderReader<R> {
+    /// creates a new reader that decodes the base encoded inner stream using the decoder's padding and alphabet
+    pub fn new(inner: R, base: Base, decoder: Decoder) -> Self {
+        Self {
+            decoder,
+            base,
+            inner,
+            pending: Vec::new(),
+            offset: 0,
+            out: Vec::new(),
+            out_pos: 0,
+            done: false,
+            error: None,
+        }
+    }
+
+    /// returns the decoder of the reader
+    pub fn decoder(&self) -> &Decoder {
+        &self.decoder
+    }
+
+    /// returns the base of the decoded stream
+    pub fn base(&self) -> Base {
+        self.base
+    }
+
+    /// returns an immutable reference to the inner reader
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// returns a mutable reference to the inner reader
+    ///
+    /// reading directly from the inner reader will likely corrupt the decoded output
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// consumes the reader and returns the inner reader
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    // reads more encoded bytes and decodes what can be decoded into self.out
+    fn fill(&mut self) -> io::Result<()> {
+        let alphabet = self
+            .decoder
+            .alphabet_for(&self.base)
+            .map_err(|error| self.fail(error, self.offset))?;
+
+        let start = self.pending.len();
+        self.pending.resize(start + READ_LEN, 0);
+        let read = loop {
+            match self.inner.read(&mut self.pending[start..]) {
+                Ok(read) => break read,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => {
+                    self.pending.truncate(start);
+                    return Err(e);
+                }
+            }
+        };
+        self.pending.truncate(start + read);
+
+        if read == 0 {
+            self.done = true;
+            return self.decode_tail(&alphabet);
+        }
+
+        // NOTE the radix bases have no quanta, they can only be decoded once the whole stream is read;
+        // so is a check symbol, which can only be verified against the whole value
+        if self.base.is_radix() || self.decoder.has_check_symbol(&self.base) {
+            return Ok(());
+        }
+
+        let whole = match self.base {
+            BASE85 => self.ascii85_quanta_len(&alphabet),
+            _ => self.quanta_len(&alphabet),
+        };
+        if whole == 0 {
+            return Ok(());
+        }
+
+        let pad = alphabet.pad() as u8;
+        self.check_chars(&alphabet, 0, whole)?;
+        if let Some(idx) = self.pending[..whole].iter().position(|c| *c == pad) {
+            // padding is only valid at the end of the stream
+            let error = DecodeError::InvalidChar {
+                char: pad as char,
+                base: self.base,
+            };
+            return Err(self.fail(error, self.offset + idx as u64));
+        }
+
+        let decoded = self
+            .decoder
+            .apply(&self.pending[..whole], self.base)
+            .map_err(|error| self.fail(error, self.offset))?;
+        self.push_out(decoded.as_bytes());
+        self.pending.drain(..whole);
+        self.offset += whole as u64;
+
+        Ok(())
+    }
+
+    // the len of the start of pending made of whole quanta,
+    // the last quantum is held back, it may be the padded one
+    //
+    // NOTE skipped chars don't count towards quanta,
+    // but they stay in pending so that the offsets remain those of the encoded stream
+    fn quanta_len(&self, alphabet: &Alphabet) -> usize {
+        let quantum = self.base.encoded_quantum();
+        let chars = self
+            .pending
+            .iter()
+            .filter(|c| !self.decoder.skips(**c, &self.base, alphabet))
+            .count();
+        let whole = chars.saturating_sub(1) / quantum * quantum;
+        if whole == 0 {
+            return 0;
+        }
+
+        self.pending
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| !self.decoder.skips(**c, &self.base, alphabet))
+            .nth(whole - 1)
+            .map(|(idx, _)| idx + 1)
+            .expect("pending holds more than whole chars")
+    }
+
+    // same as quanta_len for ascii85, whose quanta are either 5 chars or a single 'z'/'y'
+    //
+    // the opening `<~` delimiter is only looked for at the start of the stream,
+    // and the quanta end at the first '~' past it, which can only be that of the closing one
+    fn ascii85_quanta_len(&self, alphabet: &Alphabet) -> usize {
+        let mut chars = self
+            .pending
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| !self.decoder.skips(**c, &self.base, alphabet));
+        if self.offset == 0
+            && let Some((idx, _)) = chars.clone().next()
+            && self.pending[idx..].starts_with(b"<~")
+        {
+            chars.nth(1);
+        }
+
+        let mut filled = 0;
+        let mut whole = 0;
+        for (idx, chr) in chars {
+            match chr {
+                b'~' => break,
+                b'z' | b'y' if filled == 0 && alphabet.idx_of(*chr).is_none() => whole = idx + 1,
+                _ if filled == 4 => {
+                    filled = 0;
+                    whole = idx + 1;
+                }
+                _ => filled += 1,
+            }
+        }
+
+        whole
+    }
+
+    // decodes the last quantum of the stream, the only one that can be padded
+    fn decode_tail(&mut self, alphabet: &Alphabet) -> io::Result<()> {
+        let len = self.pending.len();
+        // the check symbol isn't one of the base's chars
+        let checked = match self.decoder.has_check_symbol(&self.base) {
+            true => self
+                .pending
+                .iter()
+                .rposition(|c| !self.decoder.skips(*c, &self.base, alphabet))
+                .unwrap_or_default(),
+            false => len,
+        };
+        self.check_chars(alphabet, 0, checked)?;
+
+        let pad = alphabet.pad() as u8;
+        // the padding chars, and whatever chars are skipped in between them
+        let trailing = self
+            .pending
+            .iter()
+            .rev()
+            .take_while(|c| **c == pad || self.decoder.skips(**c, &self.base, alphabet))
+            .count();
+        if let Some(idx) = self.pending[..len - trailing]
+            .iter()
+            .position(|c| *c == pad)
+        {
+            let error = DecodeError::InvalidChar {
+                char: pad as char,
+                base: self.base,
+            };
+            return Err(self.fail(error, self.offset + idx as u64));
+        }
+
+        match self.decoder.apply(&self.pending, self.base) {
+            Ok(decoded) => {
+                self.push_out(decoded.as_bytes());
+                self.offset += len as u64;
+                self.pending.clear();
+
+                Ok(())
+            }
+            Err(error) => {
+                let offset = match error {
+                    // the last char before the padding
+                    DecodeError::InvalidLastCharForPadding { .. } => {
+                        let last = self.pending[..len - trailing]
+                            .iter()
+                            .rposition(|c| !self.decoder.skips(*c, &self.base, alphabet))
+                            .unwrap_or_default();
+
+                        self.offset + last as u64
+                    }
+                    _ => self.offset,
+                };
+
+                Err(self.fail(error, offset))
+            }
+        }
+    }
+
+    // validates the chars of pending[from..to]
+    fn check_chars(&mut self, alphabet: &Alphabet, from: usize, to: usize) -> io::Result<()> {
+        let chunk = &self.pending[from..to];
+        let pad = alphabet.pad() as u8;
+        let res = match self.decoder.alphabet() {
+            // skipped chars are not part of the base's chars
+            Some(_) => Ok(()),
+            None if self.decoder.skip_whitespace() => Ok(()),
+            None => self.base.are_valid_chars(chunk),
+        };
+        let bad = chunk.iter().position(|c| {
+            *c != pad
+                && alphabet.idx_of(*c).is_none()
+                && !self.base.is_extra_char(*c)
+                && !self.decoder.skips(*c, &self.base, alphabet)
+        });
+
+        match (res, bad) {
+            (Ok(()), None) => Ok(()),
+            (res, Some(idx)) => {
+                let error = res.err().unwrap_or(DecodeError::InvalidChar {
+                    char: chunk[idx] as char,
+                    base: self.base,
+                });
+
+                Err(self.fail(error, self.offset + (from + idx) as u64))
+            }
+            (Err(error), None) => Err(self.fail(error, self.offset + from as u64)),
+        }
+    }
+
+    fn push_out(&mut self, decoded: &[u8]) {
+        self.out.drain(..self.out_pos);
+        self.out_pos = 0;
+        self.out.extend_from_slice(decoded);
+    }
+
+    // records the error so that following reads keep failing
+    fn fail(&mut self, error: DecodeError, offset: u64) -> io::Error {
+        let error = StreamDecodeError { offset, error };
+        self.done = true;
+        self.error = Some(error.clone());
+
+        error.into()
+    }
+}
+
+impl<R: io::Read> io::Read for DecoderReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        while self.out_pos == self.out.len() {
+            if let Some(error) = &self.error {
+                return Err(error.clone().into());
+            }
+            if self.done {
+                return Ok(0);
+            }
+            self.fill()?;
+        }
+
+        let available = &self.out[self.out_pos..];
+        let len = available.len().min(buf.len());
+        buf[..len].copy_from_slice(&available[..len]);
+        self.out_pos += len;
+
+        Ok(len)
+    }
+}
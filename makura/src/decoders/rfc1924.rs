@@ -0,0 +1,65 @@
+#![cfg(feature = "rfc1924")]
+use crate::BASE85RFC;
+
+use super::DecodeError;
+use super::ascii85::ascii85_decode_quantum;
+
+// decodes a quantum of 2 to 5 table indices into the 1 to 4 bytes of out
+pub fn rfc1924_decode(indices: &[u8], out: &mut [u8]) -> Result<(), DecodeError> {
+    // a partial quantum is padded with the last char of the alphabet ('~'),
+    // which rounds its value up to the bytes that were encoded
+    let mut quantum = [84u8; 5];
+    quantum[..indices.len()].copy_from_slice(indices);
+    out.copy_from_slice(&ascii85_decode_quantum(&quantum, BASE85RFC)?[..out.len()]);
+
+    Ok(())
+}
+
+pub fn is_valid_rfc1924_len(len: usize) -> Result<(), DecodeError> {
+    // NOTE a single char can't hold a whole byte
+    if len % 5 != 1 {
+        Ok(())
+    } else {
+        Err(DecodeError::InvalidLen {
+            len,
+            base: BASE85RFC,
+        })
+    }
+}
+
+pub fn chars_are_rfc1924(value: &[u8]) -> Result<(), DecodeError> {
+    match value
+        .iter()
+        .find(|c| BASE85RFC.alphabet().idx_of(**c).is_none())
+    {
+        Some(c) => Err(DecodeError::InvalidChar {
+            char: *c as char,
+            base: BASE85RFC,
+        }),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod test_validators {
+    use super::{chars_are_rfc1924, is_valid_rfc1924_len};
+
+    #[test]
+    fn test0_rfc1924() {
+        let output =
+            "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz!#$%&()*+-;<=>?@^_`{|}~";
+
+        assert_eq!(chars_are_rfc1924(output.as_bytes()), Ok(()));
+        ["\"", "'", ",", ".", "/", ":", "[", "\\", "]", " "]
+            .into_iter()
+            .for_each(|c| {
+                assert!(chars_are_rfc1924(c.as_bytes()).is_err());
+            });
+    }
+
+    #[test]
+    fn test1_rfc1924() {
+        assert_eq!(is_valid_rfc1924_len(7), Ok(()));
+        assert!(is_valid_rfc1924_len(6).is_err());
+    }
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "z85")]
+use crate::Z85;
+
+use super::DecodeError;
+use super::ascii85::ascii85_decode_quantum;
+
+// decodes a whole quantum of 5 table indices into the 4 bytes of out
+pub fn z85_decode(indices: &[u8], out: &mut [u8]) -> Result<(), DecodeError> {
+    let indices = indices.try_into().expect("z85 only has whole quanta");
+    out.copy_from_slice(&ascii85_decode_quantum(indices, Z85)?);
+
+    Ok(())
+}
+
+pub fn is_valid_z85_len(len: usize) -> Result<(), DecodeError> {
+    // NOTE unlike ascii85, z85 has neither abbreviations nor partial quanta
+    if len % 5 == 0 {
+        Ok(())
+    } else {
+        Err(DecodeError::InvalidLen { len, base: Z85 })
+    }
+}
+
+pub fn chars_are_z85(value: &[u8]) -> Result<(), DecodeError> {
+    match value.iter().find(|c| Z85.alphabet().idx_of(**c).is_none()) {
+        Some(c) => Err(DecodeError::InvalidChar {
+            char: *c as char,
+            base: Z85,
+        }),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod test_validators {
+    use super::{chars_are_z85, is_valid_z85_len};
+
+    #[test]
+    fn test0_z85() {
+        let output =
+            "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+        assert_eq!(chars_are_z85(output.as_bytes()), Ok(()));
+        ["\"", "'", ",", ";", "\\", "_", "`", "|", "~", " "]
+            .into_iter()
+            .for_each(|c| {
+                assert!(chars_are_z85(c.as_bytes()).is_err());
+            });
+    }
+
+    #[test]
+    fn test1_z85() {
+        assert_eq!(is_valid_z85_len(10), Ok(()));
+        assert!(is_valid_z85_len(8).is_err());
+    }
+}
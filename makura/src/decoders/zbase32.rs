@@ -0,0 +1,81 @@
+#![cfg(feature = "zbase32")]
+#[cfg(feature = "alloc")]
+use crate::Alphabet;
+use crate::ZBASE32;
+#[cfg(feature = "alloc")]
+use crate::makura_alloc::Vec;
+
+use super::DecodeError;
+#[cfg(feature = "alloc")]
+use super::base32::from_5bits_groups;
+
+// decodes the bits bits of the chars into out, most significant first;
+// the bits of the last byte past bits are 0s, whatever the last char was
+//
+// out has to hold at least bits.div_ceil(8) bytes,
+// returns the number of bytes written to out
+#[cfg(feature = "alloc")]
+pub fn zbase32_decode_bits(
+    chars: &[u8],
+    bits: usize,
+    out: &mut [u8],
+    alphabet: &Alphabet,
+) -> Result<usize, DecodeError> {
+    if chars.len() != bits.div_ceil(5) {
+        return Err(DecodeError::InvalidLen {
+            len: chars.len(),
+            base: ZBASE32,
+        });
+    }
+
+    let indices = chars
+        .iter()
+        .map(|chr| {
+            alphabet.idx_of(*chr).ok_or(DecodeError::InvalidChar {
+                char: *chr as char,
+                base: ZBASE32,
+            })
+        })
+        .collect::<Result<Vec<u8>, DecodeError>>()?;
+
+    Ok(from_5bits_groups(&indices, bits, out))
+}
+
+pub fn is_valid_z32_len(len: usize) -> Result<(), DecodeError> {
+    // NOTE whole bytes values only, see `zbase32::decode_bits` for the others;
+    // unpadded, the last quantum can only be 2, 4, 5, 7 or 8 chars long
+    if [0, 2, 4, 5, 7].contains(&(len % 8)) {
+        Ok(())
+    } else {
+        Err(DecodeError::InvalidLen { len, base: ZBASE32 })
+    }
+}
+
+pub fn chars_are_z32(value: &[u8]) -> Result<(), DecodeError> {
+    match value
+        .iter()
+        .find(|c| ZBASE32.alphabet().idx_of(**c).is_none())
+    {
+        Some(c) => Err(DecodeError::InvalidChar {
+            char: *c as char,
+            base: ZBASE32,
+        }),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod test_validators {
+    use super::chars_are_z32;
+
+    #[test]
+    fn test0_z32() {
+        let output = "ybndrfg8ejkmcpqxot1uwisza345h769";
+
+        assert_eq!(chars_are_z32(output.as_bytes()), Ok(()));
+        // no 0, l, v nor 2, and no uppercase
+        ["0", "l", "v", "2", "Y"].into_iter().for_each(|c| {
+            assert!(chars_are_z32(c.as_bytes()).is_err());
+        });
+    }
+}
@@ -1,24 +1,122 @@
 #![cfg(feature = "encoding")]
-use crate::makura_alloc::String;
+#[cfg(feature = "alloc")]
+use crate::makura_alloc::{String, vec};
 
-use super::Base;
-use super::{BASE16, BASE32, BASE32HEX, BASE45, BASE64, BASE64URL};
+use super::{Alphabet, Base, Padding};
+use super::{BASE16, BASE32, BASE32CROCKFORD, BASE32HEX, BASE45, BASE58, BASE64, BASE64URL};
+use super::{BASE36, BASE62, BASE85, BASE85RFC, Z85, ZBASE32};
+#[cfg(any(feature = "base58", feature = "base62", feature = "base36"))]
+use crate::base_transformer::radix_encode;
+#[cfg(all(
+    feature = "alloc",
+    any(feature = "base58", feature = "base62", feature = "base36")
+))]
+use crate::base_transformer::{MAX_INT_DIGITS, int_to_digits};
+#[cfg(feature = "base32_crockford")]
+use crate::crockford::{check_symbol, checksum};
 
+mod ascii85;
 mod base16;
 mod base32;
+mod base36;
 mod base45;
+mod base58;
+mod base62;
 mod base64;
+mod rfc1924;
+mod writer;
+mod z85;
+mod zbase32;
 
+use ascii85::{ascii85_encode, ascii85_encoded_len};
 use base16::base16_encode;
 use base32::base32_encode;
-use base32::base32_hex_encode;
+#[cfg(all(feature = "alloc", feature = "bech32"))]
+pub(crate) use base32::into_5bits_groups;
+use base36::base36_encoded_len;
 use base45::base45_encode;
+use base58::base58_encoded_len;
+use base62::base62_encoded_len;
 use base64::base64_encode;
-use base64::base64_url_encode;
+use rfc1924::rfc1924_encode;
+use z85::z85_encode;
+#[cfg(all(feature = "alloc", feature = "zbase32"))]
+pub(crate) use zbase32::zbase32_encode_bits;
+
+#[cfg(feature = "std")]
+pub use writer::EncoderWriter;
+
+/// errors that can occur while encoding into a caller provided buffer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EncodeError {
+    /// the output buffer can't hold the whole encoded value
+    OutputTooSmall { len: usize, required: usize },
+    /// the input len can't be encoded with base, i.e., a z85 input that isn't made of whole 4 bytes quanta
+    InvalidLen { len: usize, base: Base },
+    /// base doesn't encode values as a single number, so it can't encode integers,
+    /// see `Encoder::encode_u128`
+    NonRadixEncoding(Base),
+}
+
+impl core::fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::OutputTooSmall { len, required } => write!(
+                f,
+                "output buffer of {} bytes is too small, encoded value needs {} bytes",
+                len, required
+            ),
+            Self::InvalidLen { len, base } => {
+                write!(f, "input of {} bytes can not be encoded with {}", len, base)
+            }
+            Self::NonRadixEncoding(base) => write!(f, "{} can not encode integers", base),
+        }
+    }
+}
+
+impl core::error::Error for EncodeError {}
+
+/// the line break inserted between the lines of a wrapped encoded value
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LineEnding {
+    /// `\n`, e.g., PEM files
+    #[default]
+    Lf,
+    /// `\r\n`, e.g., MIME bodies (RFC 2045)
+    CrLf,
+}
+
+impl LineEnding {
+    /// returns the bytes of the line ending
+    pub fn as_bytes(&self) -> &'static [u8] {
+        self.as_str().as_bytes()
+    }
+
+    // same as as_bytes, for the line oriented formats built as strings
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Self::Lf => "\n",
+            Self::CrLf => "\r\n",
+        }
+    }
+}
 
 /// exposes feature enabled base encodings
+#[derive(Debug, Clone, Copy)]
 pub struct Encoder {
     base: Base,
+    padding: Padding,
+    // None means the standard alphabet of base
+    alphabet: Option<Alphabet>,
+    // line width and line ending, None means no wrapping
+    wrap: Option<(usize, LineEnding)>,
+    // ascii85 only, the adobe `<~` and `~>` delimiters
+    delimiters: bool,
+    // ascii85 only, btoa's 'y' abbreviation of 4 spaces
+    fold_spaces: bool,
+    // crockford base32 only, the trailing mod 37 check symbol
+    #[cfg(feature = "base32_crockford")]
+    check_symbol: bool,
 }
 
 impl Encoder {
@@ -27,40 +125,468 @@ impl Encoder {
         &self.base
     }
 
+    /// returns the padding policy of the encoder
+    pub fn padding(&self) -> &Padding {
+        &self.padding
+    }
+
+    /// returns the custom alphabet of the encoder, if it has one
+    pub fn alphabet(&self) -> Option<&Alphabet> {
+        self.alphabet.as_ref()
+    }
+
+    /// returns the line width and line ending of the encoder, if it wraps its output
+    pub fn wrap(&self) -> Option<(usize, LineEnding)> {
+        self.wrap
+    }
+
+    /// wraps the encoded output into lines of width chars, separated by ending;
+    /// the last line is never followed by a line ending
+    ///
+    /// use a width of 76 for MIME (RFC 2045) and of 64 for PEM (RFC 7468),
+    /// a width of 0 disables the wrapping
+    ///
+    /// ```
+    /// use makura::{Encoder, LineEnding};
+    ///
+    /// let enc = Encoder::base64().with_wrap(4, LineEnding::CrLf);
+    /// assert_eq!(enc.encode("foobar"), "Zm9v\r\nYmFy");
+    /// ```
+    pub fn with_wrap(mut self, width: usize, ending: LineEnding) -> Self {
+        self.wrap = (width > 0).then_some((width, ending));
+
+        self
+    }
+
+    /// sets whether the ascii85 encoded output is enclosed in the adobe `<~` and `~>` delimiters,
+    /// e.g., for PostScript and PDF streams
+    ///
+    /// ignored by the other bases
+    ///
+    /// ```
+    /// use makura::Encoder;
+    ///
+    /// let enc = Encoder::ascii85().with_delimiters(true);
+    /// assert_eq!(enc.encode("hello"), "<~BOu!rDZ~>");
+    /// ```
+    pub fn with_delimiters(mut self, delimiters: bool) -> Self {
+        self.delimiters = delimiters;
+
+        self
+    }
+
+    /// sets whether every whole ascii85 quantum of 4 spaces is abbreviated into a 'y', as btoa does
+    ///
+    /// a whole quantum of 4 0 bytes is always abbreviated into a 'z',
+    /// ignored by the other bases
+    pub fn with_fold_spaces(mut self, fold_spaces: bool) -> Self {
+        self.fold_spaces = fold_spaces;
+
+        self
+    }
+
+    /// sets whether the crockford base32 encoded output ends with its mod 37 check symbol
+    ///
+    /// ignored by the other bases
+    ///
+    /// ```
+    /// use makura::Encoder;
+    ///
+    /// let enc = Encoder::base32_crockford().with_check_symbol(true);
+    /// assert_eq!(enc.encode_bytes([0x04, 0xd2]), "0K90Q");
+    /// ```
+    #[cfg(feature = "base32_crockford")]
+    pub fn with_check_symbol(mut self, check_symbol: bool) -> Self {
+        self.check_symbol = check_symbol;
+
+        self
+    }
+
+    /// sets the padding policy of the encoder
+    ///
+    /// `Padding::Omitted` drops the trailing padding chars of base 64(url) and 32(hex) encodings,
+    /// `Padding::Required` and `Padding::Indifferent` both keep them
+    pub fn with_padding(mut self, padding: Padding) -> Self {
+        self.padding = padding;
+
+        self
+    }
+
+    /// creates a new encoder that uses a custom alphabet
+    ///
+    /// the encoder's base is the one whose bits layout matches the alphabet's len,
+    /// read `Alphabet::base`
+    pub fn custom(alphabet: Alphabet) -> Self {
+        Self {
+            alphabet: Some(alphabet),
+            ..Self::with_base(alphabet.base())
+        }
+    }
+
+    // an encoder of base with the default options
+    const fn with_base(base: Base) -> Self {
+        Self {
+            base,
+            padding: Padding::Required,
+            alphabet: None,
+            wrap: None,
+            delimiters: false,
+            fold_spaces: false,
+            #[cfg(feature = "base32_crockford")]
+            check_symbol: false,
+        }
+    }
+
     #[cfg(feature = "base64")]
     /// creates a new base64 encoder
     pub fn base64() -> Self {
-        Self { base: Base::_64 }
+        Self::with_base(BASE64)
     }
 
     #[cfg(feature = "base64_url")]
     /// creates a new base64 url encoder
     pub fn base64_url() -> Self {
-        Self { base: Base::_64URL }
+        Self::with_base(BASE64URL)
     }
 
     #[cfg(feature = "base45")]
     /// creates a new base45 encoder
     pub fn base45() -> Self {
-        Self { base: Base::_45 }
+        Self::with_base(BASE45)
     }
 
     #[cfg(feature = "base32")]
     /// creates a new base32 encoder
     pub fn base32() -> Self {
-        Self { base: Base::_32 }
+        Self::with_base(BASE32)
+    }
+
+    #[cfg(feature = "base32_crockford")]
+    /// creates a new crockford base32 encoder
+    ///
+    /// the bits layout is that of base32, without padding, the padding policy is ignored
+    ///
+    /// ```
+    /// use makura::Encoder;
+    ///
+    /// assert_eq!(Encoder::base32_crockford().encode("foobar"), "CSQPYRK1E8");
+    /// ```
+    pub fn base32_crockford() -> Self {
+        Self::with_base(BASE32CROCKFORD).with_padding(Padding::Omitted)
+    }
+
+    #[cfg(feature = "zbase32")]
+    /// creates a new z-base-32 encoder, for whole bytes values
+    ///
+    /// the bits layout is that of base32, without padding, the padding policy is ignored;
+    /// see the `zbase32` module for values of any number of bits
+    ///
+    /// ```
+    /// use makura::Encoder;
+    ///
+    /// assert_eq!(Encoder::zbase32().encode_bytes([0xf0, 0xbf, 0xc7]), "6n9hq");
+    /// ```
+    pub fn zbase32() -> Self {
+        Self::with_base(ZBASE32).with_padding(Padding::Omitted)
     }
 
     #[cfg(feature = "base32_hex")]
     /// creates a new base32 hex encoder
     pub fn base32_hex() -> Self {
-        Self { base: Base::_32HEX }
+        Self::with_base(BASE32HEX)
     }
 
     #[cfg(feature = "base16")]
     /// creates a new base16 encoder
     pub fn base16() -> Self {
-        Self { base: Base::_16 }
+        Self::with_base(BASE16)
+    }
+
+    #[cfg(feature = "base58")]
+    /// creates a new base58 encoder, using the bitcoin alphabet
+    ///
+    /// base58 has no padding, the padding policy is ignored
+    ///
+    /// ```
+    /// use makura::Encoder;
+    ///
+    /// assert_eq!(Encoder::base58().encode_bytes([0, 0, 0x28, 0x7f, 0xb4, 0xcd]), "11233QC4");
+    /// ```
+    pub fn base58() -> Self {
+        Self::with_base(BASE58)
+    }
+
+    #[cfg(feature = "base62")]
+    /// creates a new base62 encoder, digits then uppercase then lowercase letters
+    ///
+    /// like base58, the whole value is a single number and every leading 0 byte is a leading '0';
+    /// base62 has no padding, the padding policy is ignored
+    ///
+    /// ```
+    /// use makura::Encoder;
+    ///
+    /// assert_eq!(Encoder::base62().encode("hello world"), "AAwf93rvy4aWQVw");
+    /// assert_eq!(Encoder::base62().encode_bytes([0, 0, 1]), "001");
+    /// ```
+    pub fn base62() -> Self {
+        Self::with_base(BASE62)
+    }
+
+    #[cfg(feature = "base36")]
+    /// creates a new base36 encoder, digits then lowercase letters
+    ///
+    /// like base58, the whole value is a single number and every leading 0 byte is a leading '0';
+    /// base36 has no padding, the padding policy is ignored
+    ///
+    /// ```
+    /// use makura::Encoder;
+    ///
+    /// assert_eq!(Encoder::base36().encode("hello"), "5pzcszu7");
+    /// assert_eq!(Encoder::base36().encode_u64(1295).unwrap(), "zz");
+    /// ```
+    pub fn base36() -> Self {
+        Self::with_base(BASE36)
+    }
+
+    #[cfg(feature = "ascii85")]
+    /// creates a new ascii85 encoder, abbreviating whole quanta of 0 bytes into 'z's
+    ///
+    /// ascii85 has no padding, the padding policy is ignored
+    ///
+    /// ```
+    /// use makura::Encoder;
+    ///
+    /// assert_eq!(Encoder::ascii85().encode_bytes([0, 0, 0, 0, b'h', b'i']), "zBP@");
+    /// ```
+    pub fn ascii85() -> Self {
+        Self::with_base(BASE85)
+    }
+
+    #[cfg(feature = "z85")]
+    /// creates a new z85 (ZeroMQ RFC 32) encoder
+    ///
+    /// z85 only encodes whole quanta of 4 bytes and has no padding,
+    /// the padding policy is ignored;
+    /// `encode`/`encode_bytes` panic on any other input len, use `try_encode`/`try_encode_bytes` instead
+    ///
+    /// ```
+    /// use makura::Encoder;
+    ///
+    /// let key = [0x86, 0x4f, 0xd2, 0x6f, 0xb5, 0x59, 0xf7, 0x5b];
+    /// assert_eq!(Encoder::z85().try_encode_bytes(key).unwrap(), "HelloWorld");
+    /// assert!(Encoder::z85().try_encode_bytes(&key[..5]).is_err());
+    /// ```
+    pub fn z85() -> Self {
+        Self::with_base(Z85)
+    }
+
+    #[cfg(feature = "rfc1924")]
+    /// creates a new encoder with the RFC 1924 base85 alphabet, as python's `base64.b85encode`
+    ///
+    /// a partial last quantum of n bytes is encoded into n + 1 chars,
+    /// there is no padding, the padding policy is ignored
+    ///
+    /// ```
+    /// use makura::Encoder;
+    ///
+    /// assert_eq!(Encoder::base85_rfc().encode("hello"), "Xk~0{Zv");
+    /// ```
+    pub fn base85_rfc() -> Self {
+        Self::with_base(BASE85RFC)
+    }
+
+    /// returns the exact length of the encoded value of an input of len bytes,
+    /// following self's base, padding policy and line wrapping
+    ///
+    /// the length of a base58 or ascii85 value depends on the value itself,
+    /// so for those this is only an upper bound
+    ///
+    /// ```
+    /// use makura::{Encoder, LineEnding, Padding};
+    ///
+    /// assert_eq!(Encoder::base64().encoded_len(4), 8);
+    /// assert_eq!(Encoder::base64().with_padding(Padding::Omitted).encoded_len(4), 6);
+    /// assert_eq!(Encoder::base64().with_wrap(4, LineEnding::CrLf).encoded_len(4), 10);
+    /// ```
+    pub fn encoded_len(&self, len: usize) -> usize {
+        self.wrapped_len(self.unwrapped_len(len))
+    }
+
+    // the len of len unwrapped chars once wrapped
+    fn wrapped_len(&self, len: usize) -> usize {
+        match self.wrap {
+            Some((width, ending)) if len > 0 => {
+                len + (len.div_ceil(width) - 1) * ending.as_bytes().len()
+            }
+            _ => len,
+        }
+    }
+
+    // same as encoded_len, without the line endings
+    fn unwrapped_len(&self, len: usize) -> usize {
+        match self.base {
+            BASE58 => return base58_encoded_len(len),
+            BASE62 => return base62_encoded_len(len),
+            BASE36 => return base36_encoded_len(len),
+            BASE85 => return ascii85_encoded_len(len) + if self.delimiters { 4 } else { 0 },
+            // the check symbol follows the encoded quanta
+            #[cfg(feature = "base32_crockford")]
+            BASE32CROCKFORD if self.check_symbol => return self.quanta_len(len) + 1,
+            _ => (),
+        }
+
+        self.quanta_len(len)
+    }
+
+    // the len of the encoded quanta of an input of len bytes, for the bases made of quanta
+    fn quanta_len(&self, len: usize) -> usize {
+        let quantum = self.base.quantum();
+        let rest = len % quantum;
+        let whole = len / quantum * self.base.encoded_quantum();
+
+        match self.base {
+            _ if rest == 0 => whole,
+            Base::_64 | Base::_64URL | Base::_32 | Base::_32HEX
+                if self.padding != Padding::Omitted =>
+            {
+                whole + self.base.encoded_quantum()
+            }
+            _ => whole + self.base.partial_encoded_len(rest),
+        }
+    }
+
+    /// encodes value into the start of out, without allocating
+    ///
+    /// returns the number of bytes written to out, which is always `self.encoded_len(value.len())`,
+    /// but for base58, base62, base36 and ascii85 where it can be less
+    ///
+    /// # Error
+    /// returns an `EncodeError::OutputTooSmall` when out is shorter than the encoded value,
+    /// in which case nothing is written to out
+    ///
+    /// returns an `EncodeError::InvalidLen` when self is a z85 encoder and value's len isn't a multiple of 4
+    ///
+    /// ```
+    /// use makura::Encoder;
+    ///
+    /// let mut out = [0u8; 16];
+    /// let len = Encoder::base32().encode_to_slice(b"foo", &mut out).unwrap();
+    ///
+    /// assert_eq!(&out[..len], b"MZXW6===");
+    /// ```
+    pub fn encode_to_slice(&self, value: &[u8], out: &mut [u8]) -> Result<usize, EncodeError> {
+        if self.base == Z85 && value.len() % self.base.quantum() != 0 {
+            return Err(EncodeError::InvalidLen {
+                len: value.len(),
+                base: self.base,
+            });
+        }
+
+        let required = self.encoded_len(value.len());
+        if out.len() < required {
+            return Err(EncodeError::OutputTooSmall {
+                len: out.len(),
+                required,
+            });
+        }
+
+        let alphabet = self.alphabet.as_ref().unwrap_or(self.base.alphabet());
+        let len = if self.base.has_variable_len() {
+            self.encode_variable(value, out, alphabet)
+        } else {
+            self.encode_quanta(value, out, alphabet)
+        };
+        let len = match self.base {
+            #[cfg(feature = "base32_crockford")]
+            Base::_32CROCKFORD if self.check_symbol => {
+                let sum = checksum(out[..len].iter().filter_map(|c| alphabet.idx_of(*c)));
+                out[len] = check_symbol(sum, alphabet);
+
+                len + 1
+            }
+            _ => len,
+        };
+
+        let required = self.wrapped_len(len);
+        if let Some((width, ending)) = self.wrap {
+            wrap_in_place(&mut out[..required], len, width, ending.as_bytes());
+        }
+
+        Ok(required)
+    }
+
+    // encodes value one quantum at a time into out, returns the number of chars written
+    fn encode_quanta(&self, value: &[u8], out: &mut [u8], alphabet: &Alphabet) -> usize {
+        let quantum = self.base.quantum();
+        let chars = self.base.encoded_quantum();
+
+        let mut chunks = value.chunks_exact(quantum);
+        chunks
+            .by_ref()
+            .zip(out.chunks_exact_mut(chars))
+            .for_each(|(chunk, out)| self.encode_quantum(chunk, out, alphabet));
+
+        let rest = chunks.remainder();
+        if !rest.is_empty() {
+            // the last quantum is encoded whole, padding included,
+            // then only what the padding policy keeps of it is copied
+            let mut last = [0u8; 8];
+            self.encode_quantum(rest, &mut last[..chars], alphabet);
+            let start = value.len() / quantum * chars;
+            let end = self.quanta_len(value.len());
+            out[start..end].copy_from_slice(&last[..end - start]);
+        }
+
+        self.quanta_len(value.len())
+    }
+
+    // encodes value with a base whose encoded len depends on the value,
+    // returns the number of chars written
+    fn encode_variable(&self, value: &[u8], out: &mut [u8], alphabet: &Alphabet) -> usize {
+        match self.base {
+            Base::_58 | Base::_62 | Base::_36 => {
+                radix_encode(value, out, alphabet, self.base.radix())
+            }
+            Base::_85 if self.delimiters => {
+                out[..2].copy_from_slice(b"<~");
+                let len = 2 + ascii85_encode(value, &mut out[2..], alphabet, self.fold_spaces);
+                out[len..len + 2].copy_from_slice(b"~>");
+
+                len + 2
+            }
+            Base::_85 => ascii85_encode(value, out, alphabet, self.fold_spaces),
+            _ => unreachable!("{} has a fixed encoded len", self.base),
+        }
+    }
+
+    // a copy of self that only outputs the encoded chars, without line wrapping,
+    // check symbol nor delimiters, which `EncoderWriter` applies to the whole stream
+    #[cfg(feature = "std")]
+    fn chars_only(&self) -> Self {
+        Self {
+            wrap: None,
+            delimiters: false,
+            #[cfg(feature = "base32_crockford")]
+            check_symbol: false,
+            ..*self
+        }
+    }
+
+    // encodes a single, possibly partial, quantum of value into out
+    fn encode_quantum(&self, quantum: &[u8], out: &mut [u8], alphabet: &Alphabet) {
+        match self.base {
+            Base::_64 | Base::_64URL => base64_encode(quantum, out, alphabet),
+            Base::_45 => base45_encode(quantum, out, alphabet),
+            Base::_32 | Base::_32HEX | Base::_32CROCKFORD | Base::_Z32 => {
+                base32_encode(quantum, out, alphabet)
+            }
+            Base::_16 => base16_encode(quantum, out, alphabet),
+            Base::_Z85 => z85_encode(quantum, out, alphabet),
+            Base::_85RFC => rfc1924_encode(quantum, out, alphabet),
+            Base::_58 | Base::_85 | Base::_62 | Base::_36 => {
+                unreachable!("{} has a variable encoded len", self.base)
+            }
+        }
     }
 
     /// Apply self's base encoding to passed value argument.
@@ -69,20 +595,131 @@ impl Encoder {
     ///
     /// This method always returns a string,
     /// passing an empty string results in a an empty `String` return value
-    // NOTE this doesnt fail
-    // but what if the input string is not a valid utf8
+    ///
+    /// this is a thin wrapper around `encode_bytes`
+    ///
+    /// # Panics
+    /// see `encode_bytes`
+    #[cfg(feature = "alloc")]
     pub fn encode<T: AsRef<str>>(&self, value: T) -> String {
-        match self.base {
-            Base::_64 => base64_encode(value),
-            Base::_64URL => base64_url_encode(value),
-            Base::_45 => base45_encode(value),
-            Base::_32 => base32_encode(value),
-            Base::_32HEX => base32_hex_encode(value),
-            Base::_16 => base16_encode(value),
+        self.encode_bytes(value.as_ref().as_bytes())
+    }
+
+    /// Apply self's base encoding to the passed bytes.
+    /// Value can be anything that implements `AsRef<[u8]>`;
+    /// including a `&[u8]`, a `Vec<u8>` or a `[u8; N]`
+    ///
+    /// unlike `encode`, the input doesn't need to be valid utf8,
+    /// so this is the method to use for arbitrary binary data (keys, images...)
+    ///
+    /// passing an empty slice results in a an empty `String` return value
+    ///
+    /// # Panics
+    /// panics when self is a z85 encoder and value's len isn't a multiple of 4,
+    /// z85 only encodes whole quanta of 4 bytes;
+    /// use `try_encode_bytes` to get an `EncodeError` instead
+    #[cfg(feature = "alloc")]
+    pub fn encode_bytes<T: AsRef<[u8]>>(&self, value: T) -> String {
+        self.try_encode_bytes(value)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// same as `encode`, but fails on the inputs self's base can't encode
+    ///
+    /// this is a thin wrapper around `try_encode_bytes`
+    ///
+    /// # Error
+    /// returns an `EncodeError::InvalidLen` when self is a z85 encoder and value's len isn't a multiple of 4
+    ///
+    /// ```
+    /// use makura::{EncodeError, Encoder, Z85};
+    ///
+    /// assert_eq!(Encoder::z85().try_encode("abcd").unwrap(), "vpA.S");
+    /// assert_eq!(
+    ///     Encoder::z85().try_encode("abc"),
+    ///     Err(EncodeError::InvalidLen { len: 3, base: Z85 })
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn try_encode<T: AsRef<str>>(&self, value: T) -> Result<String, EncodeError> {
+        self.try_encode_bytes(value.as_ref().as_bytes())
+    }
+
+    /// same as `encode_bytes`, but fails on the inputs self's base can't encode
+    ///
+    /// # Error
+    /// returns an `EncodeError::InvalidLen` when self is a z85 encoder and value's len isn't a multiple of 4
+    #[cfg(feature = "alloc")]
+    pub fn try_encode_bytes<T: AsRef<[u8]>>(&self, value: T) -> Result<String, EncodeError> {
+        let value = value.as_ref();
+        let mut encoded = vec![0; self.encoded_len(value.len())];
+        // NOTE the buffer is always large enough, the only error left is the input len
+        let len = self.encode_to_slice(value, &mut encoded)?;
+        encoded.truncate(len);
+
+        // NOTE every alphabet is made of ascii chars
+        Ok(String::from_utf8(encoded).expect("encoded value is ascii"))
+    }
+
+    /// encodes value as a single number of self's base, e.g., short ids or url slugs
+    ///
+    /// same as `encode_u128`
+    ///
+    /// # Error
+    /// see `encode_u128`
+    #[cfg(all(
+        feature = "alloc",
+        any(feature = "base58", feature = "base62", feature = "base36")
+    ))]
+    pub fn encode_u64(&self, value: u64) -> Result<String, EncodeError> {
+        self.encode_u128(value as u128)
+    }
+
+    /// encodes value as a single number of self's base, most significant digit first
+    ///
+    /// the fast path of `encode_bytes` for integers, without its arbitrary precision conversion;
+    /// there are no leading 0 digits, but for 0 itself which is a single 0 digit
+    ///
+    /// # Error
+    /// returns an `EncodeError::NonRadixEncoding` when self's base doesn't encode values
+    /// as a single number, i.e., isn't base58, base62 or base36
+    ///
+    /// ```
+    /// use makura::{BASE64, EncodeError, Encoder};
+    ///
+    /// assert_eq!(Encoder::base62().encode_u128(1234567890).unwrap(), "1LY7VK");
+    /// assert_eq!(Encoder::base62().encode_u128(0).unwrap(), "0");
+    /// assert_eq!(
+    ///     Encoder::base64().encode_u128(0),
+    ///     Err(EncodeError::NonRadixEncoding(BASE64))
+    /// );
+    /// ```
+    #[cfg(all(
+        feature = "alloc",
+        any(feature = "base58", feature = "base62", feature = "base36")
+    ))]
+    pub fn encode_u128(&self, value: u128) -> Result<String, EncodeError> {
+        if !self.base.is_radix() {
+            return Err(EncodeError::NonRadixEncoding(self.base));
         }
+
+        let alphabet = self.alphabet.as_ref().unwrap_or(self.base.alphabet());
+        let mut digits = [0u8; MAX_INT_DIGITS];
+        let len = int_to_digits(value, self.base.radix(), &mut digits);
+
+        // NOTE the digits are least significant first
+        Ok(digits[..len]
+            .iter()
+            .rev()
+            .map(|digit| alphabet.char_at(*digit))
+            .collect())
     }
 
     /// repeats self.encode <repeat> times
+    ///
+    /// # Panics
+    /// see `encode_bytes`
+    #[cfg(feature = "alloc")]
     pub fn encode_repeat<T: AsRef<str>>(&self, value: T, mut repeat: usize) -> String {
         let mut value = self.encode(value);
         while repeat > 0 {
@@ -94,16 +731,40 @@ impl Encoder {
     }
 
     /// encodes the given input string in sequence using the given bases
+    ///
+    /// # Panics
+    /// see `encode_bytes`
+    #[cfg(feature = "alloc")]
     pub fn encode_chain<T: AsRef<str>>(value: T, chain: &[Base]) -> String {
-        let mut value = value.as_ref().into();
-        chain.into_iter().for_each(|b| {
+        let mut value: String = value.as_ref().into();
+        chain.iter().for_each(|b| {
             value = Self::from(*b).encode(&value);
         });
 
-        value.into()
+        value
     }
 }
 
+// spreads the len unwrapped chars at the start of out into lines of width chars,
+// separated by ending
+//
+// out has to be exactly as long as the wrapped value
+fn wrap_in_place(out: &mut [u8], len: usize, width: usize, ending: &[u8]) {
+    let lines = len.div_ceil(width);
+    // NOTE going from the last line to the first,
+    // a line is only ever moved over chars that were already moved
+    (0..lines).rev().for_each(|line| {
+        let start = line * width;
+        let end = (start + width).min(len);
+        let target = line * (width + ending.len());
+        out.copy_within(start..end, target);
+
+        if line < lines - 1 {
+            out[target + width..target + width + ending.len()].copy_from_slice(ending);
+        }
+    });
+}
+
 impl From<Base> for Encoder {
     fn from(value: Base) -> Self {
         match value {
@@ -113,6 +774,15 @@ impl From<Base> for Encoder {
             BASE32 => Encoder::base32(),
             BASE32HEX => Encoder::base32_hex(),
             BASE16 => Encoder::base16(),
+            BASE58 => Encoder::base58(),
+            BASE62 => Encoder::base62(),
+            BASE36 => Encoder::base36(),
+            BASE85 => Encoder::ascii85(),
+            Z85 => Encoder::z85(),
+            BASE85RFC => Encoder::base85_rfc(),
+            // NOTE same as `Encoder::base32_crockford`, which only exists with its feature
+            BASE32CROCKFORD => Self::with_base(BASE32CROCKFORD).with_padding(Padding::Omitted),
+            ZBASE32 => Encoder::zbase32(),
         }
     }
 }
@@ -0,0 +1,67 @@
+#![cfg(any(feature = "ascii85", feature = "z85", feature = "rfc1924"))]
+use crate::Alphabet;
+
+// the abbreviation of a whole quantum of 4 0 bytes
+const ZEROS: u8 = b'z';
+// btoa's abbreviation of a whole quantum of 4 spaces
+const SPACES: u8 = b'y';
+
+// the max number of chars of the ascii85 encoding of len bytes, i.e., without abbreviations
+pub fn ascii85_encoded_len(len: usize) -> usize {
+    let rest = len % 4;
+
+    len / 4 * 5 + if rest > 0 { rest + 1 } else { 0 }
+}
+
+// encodes a quantum of 1 to 4 bytes into out as a single base 85 number, most significant digit first
+//
+// a partial quantum is padded with 0 bytes, then only its first n + 1 chars are kept
+// returns the number of chars written
+pub fn ascii85_encode_quantum(quantum: &[u8], out: &mut [u8], alphabet: &Alphabet) -> usize {
+    let mut bytes = [0u8; 4];
+    bytes[..quantum.len()].copy_from_slice(quantum);
+
+    let mut transformer = crate::BaseTransformer::new(85, u32::from_be_bytes(bytes));
+    transformer.transform_all();
+    transformer.pad_to(5);
+
+    // NOTE the sequence is least significant first
+    transformer
+        .sequence()
+        .iter()
+        .rev()
+        .take(quantum.len() + 1)
+        .zip(out.iter_mut())
+        .for_each(|(b, o)| *o = alphabet.char_at(*b) as u8);
+
+    quantum.len() + 1
+}
+
+// encodes value into out, abbreviating every whole quantum of 0 bytes into a 'z',
+// and, when fold_spaces is set, every whole quantum of spaces into a 'y'
+//
+// the abbreviations are left out when the alphabet has those chars, e.g., z85
+//
+// out has to hold at least ascii85_encoded_len(value.len()) bytes,
+// returns the number of chars written to out
+pub fn ascii85_encode(
+    value: &[u8],
+    out: &mut [u8],
+    alphabet: &Alphabet,
+    fold_spaces: bool,
+) -> usize {
+    let zeros = !alphabet.contains(ZEROS as char);
+    let spaces = fold_spaces && !alphabet.contains(SPACES as char);
+
+    value.chunks(4).fold(0, |len, quantum| match quantum {
+        [0, 0, 0, 0] if zeros => {
+            out[len] = ZEROS;
+            len + 1
+        }
+        b"    " if spaces => {
+            out[len] = SPACES;
+            len + 1
+        }
+        _ => len + ascii85_encode_quantum(quantum, &mut out[len..], alphabet),
+    })
+}
@@ -1,8 +1,11 @@
-#![cfg(any(feature = "base32", feature = "base32_hex"))]
-use crate::makura_alloc::{String, Vec};
-
-use crate::char_from_idx;
-use crate::{BASE32, BASE32HEX};
+#![cfg(any(
+    feature = "base32",
+    feature = "base32_hex",
+    feature = "base32_crockford",
+    feature = "zbase32",
+    feature = "bech32"
+))]
+use crate::Alphabet;
 
 /// DOCS
 /// Special processing is performed if fewer than 40 bits are available
@@ -33,145 +36,104 @@ use crate::{BASE32, BASE32HEX};
 /// (5) The final quantum of encoding input is exactly 32 bits; here, the
 ///     final unit of encoded output will be seven characters followed by
 ///     one "=" padding character.
-
-// separates the input string into chunks of 24bits
+// packs a quantum of 1 to 5 bytes into a 40bits chunk
+// the bytes that the quantum lacks are 0s
 // bytes_of_u40
-fn into_40bits_chunks(data: &str) -> Vec<u64> {
-    let mut bytes = data.as_bytes().chunks(5);
-    // println!("{:?}", bytes.clone().collect::<Vec<&[u8]>>());
-    let last = bytes.next_back().unwrap();
-
-    let mut bytes = bytes
-        .map(|b| {
-            let mut mask = 0u64;
-            mask |= b[0] as u64;
-            mask <<= 8;
-            mask |= b[1] as u64;
-            mask <<= 8;
-            mask |= b[2] as u64;
-            mask <<= 8;
-            mask |= b[3] as u64;
-            mask <<= 8;
-            mask |= b[4] as u64;
-
-            mask
-        })
-        .collect::<Vec<u64>>();
-    let last = {
-        let mut mask = 0u64;
-        mask |= last[0] as u64;
-        mask <<= 8;
-        mask |= if last.len() < 2 { 0u64 } else { last[1] as u64 };
-        mask <<= 8;
-        mask |= if last.len() < 3 { 0u64 } else { last[2] as u64 };
-        mask <<= 8;
-        mask |= if last.len() < 4 { 0u64 } else { last[3] as u64 };
-        mask <<= 8;
-        mask |= if last.len() < 5 { 0u64 } else { last[4] as u64 };
-
-        mask
+fn into_40bits_chunk(quantum: &[u8]) -> u64 {
+    let mut mask = 0u64;
+    mask |= quantum[0] as u64;
+    mask <<= 8;
+    mask |= if quantum.len() < 2 {
+        0u64
+    } else {
+        quantum[1] as u64
+    };
+    mask <<= 8;
+    mask |= if quantum.len() < 3 {
+        0u64
+    } else {
+        quantum[2] as u64
+    };
+    mask <<= 8;
+    mask |= if quantum.len() < 4 {
+        0u64
+    } else {
+        quantum[3] as u64
+    };
+    mask <<= 8;
+    mask |= if quantum.len() < 5 {
+        0u64
+    } else {
+        quantum[4] as u64
     };
-    bytes.push(last);
 
-    bytes
+    mask
 }
 
 // bytes_of_u5
-fn into_5bits_bytes(bytes: Vec<u64>) -> Vec<u8> {
-    let bytes = bytes.into_iter();
-    // let mut last = bytes.next_back().unwrap();
-
-    bytes
-        .flat_map(|b| {
-            [
-                // NOTE & 31 to take only the least 5 bits
-                (b >> 35) as u8 & 31,
-                (b >> 30) as u8 & 31,
-                (b >> 25) as u8 & 31,
-                (b >> 20) as u8 & 31,
-                (b >> 15) as u8 & 31,
-                (b >> 10) as u8 & 31,
-                (b >> 5) as u8 & 31,
-                b as u8 & 31,
-            ]
-        })
-        .collect()
-}
-
-fn into_base32(bytes: Vec<u8>) -> String {
-    let bytes = bytes.into_iter();
-
-    let mut cd = 6;
-    let mut pad = true;
-    bytes
-        .rev()
-        .map(|b| {
-            if cd > 0 && pad && b == 0 {
-                cd -= 1;
-                '='
-            } else {
-                pad = false;
-                char_from_idx(b, &BASE32)
-            }
-        })
-        .collect::<Vec<char>>()
-        .into_iter()
-        .rev()
-        .collect()
+fn into_5bits_bytes(chunk: u64) -> [u8; 8] {
+    [
+        // NOTE & 31 to take only the least 5 bits
+        (chunk >> 35) as u8 & 31,
+        (chunk >> 30) as u8 & 31,
+        (chunk >> 25) as u8 & 31,
+        (chunk >> 20) as u8 & 31,
+        (chunk >> 15) as u8 & 31,
+        (chunk >> 10) as u8 & 31,
+        (chunk >> 5) as u8 & 31,
+        chunk as u8 & 31,
+    ]
 }
 
-fn into_base32_hex(bytes: Vec<u8>) -> String {
-    let bytes = bytes.into_iter();
-
-    // FIXME the table needs to have all values
-    let mut cd = 6;
-    let mut pad = true;
-    bytes
-        .rev()
-        // .inspect(|b| println!("{}", b))
-        .map(|b| {
-            if cd > 0 && pad && b == 0 {
-                cd -= 1;
-                '='
-            } else {
-                pad = false;
-                char_from_idx(b, &BASE32HEX)
-            }
-        })
-        .collect::<Vec<char>>()
-        .into_iter()
-        .rev()
-        .collect()
+// packs the first bits bits of value into 5 bits groups, most significant first;
+// the bits that the last group lacks are 0s
+//
+// NOTE unlike base32 quanta, the groups don't have to add up to whole bytes,
+// e.g., z-base-32 values of 1 bit or bech32's 5 bits data part
+//
+// value has to hold at least bits bits and out at least bits.div_ceil(5) bytes,
+// returns the number of groups written to out
+#[cfg(all(feature = "alloc", any(feature = "zbase32", feature = "bech32")))]
+pub fn into_5bits_groups(value: &[u8], bits: usize, out: &mut [u8]) -> usize {
+    let groups = bits.div_ceil(5);
+    out[..groups].iter_mut().enumerate().for_each(|(idx, out)| {
+        *out = (idx * 5..idx * 5 + 5).fold(0u8, |group, bit| {
+            let set = bit < bits && value[bit / 8] & (0x80 >> (bit % 8)) != 0;
+            (group << 1) | set as u8
+        });
+    });
+
+    groups
 }
 
-#[cfg(feature = "base32")]
-pub fn base32_encode<T>(value: T) -> String
-where
-    T: AsRef<str>,
-{
-    let value = value.as_ref();
-    if value.is_empty() {
-        return "".into();
+// see cases (1) through (5) above
+fn pads_count(len: usize) -> usize {
+    match len % 5 {
+        0 => 0,
+        1 => 6,
+        2 => 4,
+        3 => 3,
+        4 => 1,
+        _ => unreachable!("remainder of a division by 5"),
     }
-
-    let chunks = into_40bits_chunks(value);
-    let bytes = into_5bits_bytes(chunks);
-
-    into_base32(bytes)
 }
 
-#[cfg(feature = "base32_hex")]
-pub fn base32_hex_encode<T>(value: T) -> String
-where
-    T: AsRef<str>,
-{
-    let value = value.as_ref();
-    if value.is_empty() {
-        return "".into();
-    }
-
-    let chunks = into_40bits_chunks(value);
-    let bytes = into_5bits_bytes(chunks);
+// encodes a quantum of 1 to 5 bytes into the 8 chars of out,
+// the chars that a partial quantum lacks are padding chars
+//
+// base32 and base32 hex only differ by their alphabets
+pub fn base32_encode(quantum: &[u8], out: &mut [u8], alphabet: &Alphabet) {
+    let chars = 8 - pads_count(quantum.len());
 
-    into_base32_hex(bytes)
+    into_5bits_bytes(into_40bits_chunk(quantum))
+        .into_iter()
+        .zip(out.iter_mut())
+        .enumerate()
+        .for_each(|(idx, (b, o))| {
+            *o = if idx < chars {
+                alphabet.char_at(b) as u8
+            } else {
+                alphabet.pad() as u8
+            }
+        });
 }
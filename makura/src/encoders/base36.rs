@@ -0,0 +1,8 @@
+#![cfg(feature = "base36")]
+// the max number of chars of the base36 encoding of len bytes
+//
+// every byte takes log(256) / log(36) ~= 1.547 chars,
+// a leading 0 byte takes a whole char, which is still less than the bound
+pub fn base36_encoded_len(len: usize) -> usize {
+    (len * 1548).div_ceil(1000)
+}
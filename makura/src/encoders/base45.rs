@@ -1,84 +1,34 @@
 #![cfg(feature = "base45")]
-use crate::makura_alloc::{String, Vec};
+use crate::Alphabet;
 
-use crate::BASE45;
-use crate::char_from_idx;
-
-/// separates the input string into chunks of 16bits
-// TODO rename chunk_and_cast
-fn into_16bits_chunks(data: &str) -> Vec<u16> {
-    let mut bytes = data.as_bytes().chunks(2);
-    // println!("{:?}", bytes.clone().collect::<Vec<&[u8]>>());
-    let last = bytes.next_back().unwrap();
-
-    let mut bytes = bytes
-        .map(|b| {
+/// packs a quantum of 1 or 2 bytes into a 16bits chunk
+fn into_16bits_chunk(quantum: &[u8]) -> u16 {
+    match *quantum {
+        [one] => one as u16,
+        [one, two] => {
             let mut mask = 0u16;
-            mask |= b[0] as u16;
+            mask |= one as u16;
             mask <<= 8;
-            mask |= b[1] as u16;
+            mask |= two as u16;
 
             mask
-        })
-        .collect::<Vec<u16>>();
-
-    let last = {
-        match *last {
-            [one] => one as u16,
-            [one, two] => {
-                let mut mask = 0u16;
-                mask |= one as u16;
-                mask <<= 8;
-                mask |= two as u16;
-
-                mask
-            }
-            _ => unreachable!("chunk can only be of length 1 or 2"),
         }
-    };
-    bytes.push(last);
-
-    bytes
-}
-
-fn into_base45_bytes(bytes: Vec<u16>) -> Vec<u8> {
-    let bytes = bytes.into_iter();
-    // let mut last = bytes.next_back().unwrap();
-
-    bytes
-        // .inspect(|b| println!("{}", b))
-        .flat_map(|b| {
-            let mut transformer = crate::BaseTransformer::new(45, b);
-            transformer.transform_all();
-
-            let mut seq = transformer.sequence().to_vec();
-            if seq.len() == 1 {
-                seq.push(0);
-            }
-
-            seq
-        })
-        // .inspect(|seq| println!("{:?}", seq))
-        .collect()
-}
-
-fn into_base45(bytes: Vec<u8>) -> String {
-    let bytes = bytes.into_iter();
-
-    bytes.map(|b| char_from_idx(b, &BASE45)).collect::<String>()
-}
-
-pub fn base45_encode<T>(value: T) -> String
-where
-    T: AsRef<str>,
-{
-    let value = value.as_ref();
-    if value.is_empty() {
-        return "".into();
+        _ => unreachable!("quantum can only be of length 1 or 2"),
     }
+}
 
-    let chunks = into_16bits_chunks(value);
-    let bytes = into_base45_bytes(chunks);
-
-    into_base45(bytes)
+// encodes a quantum of 1 or 2 bytes into out
+//
+// a 2 bytes quantum is encoded into exactly 3 chars
+// while a trailing 1 byte quantum is encoded into exactly 2 chars
+pub fn base45_encode(quantum: &[u8], out: &mut [u8], alphabet: &Alphabet) {
+    let mut transformer = crate::BaseTransformer::new(45, into_16bits_chunk(quantum));
+    transformer.transform_all();
+    transformer.pad_to(quantum.len() + 1);
+
+    transformer
+        .sequence()
+        .iter()
+        .zip(out.iter_mut())
+        .for_each(|(b, o)| *o = alphabet.char_at(*b) as u8);
 }
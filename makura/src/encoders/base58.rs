@@ -0,0 +1,8 @@
+#![cfg(feature = "base58")]
+// the max number of chars of the base58 encoding of len bytes
+//
+// every byte takes log(256) / log(58) ~= 1.366 chars,
+// a leading 0 byte takes a whole char, which is still less than the bound
+pub fn base58_encoded_len(len: usize) -> usize {
+    (len * 1366).div_ceil(1000)
+}
@@ -0,0 +1,8 @@
+#![cfg(feature = "base62")]
+// the max number of chars of the base62 encoding of len bytes
+//
+// every byte takes log(256) / log(62) ~= 1.344 chars,
+// a leading 0 byte takes a whole char, which is still less than the bound
+pub fn base62_encoded_len(len: usize) -> usize {
+    (len * 1344).div_ceil(1000)
+}
@@ -0,0 +1,10 @@
+#![cfg(feature = "rfc1924")]
+use crate::Alphabet;
+
+use super::ascii85::ascii85_encode_quantum;
+
+// encodes a quantum of 1 to 4 bytes into out, a partial quantum of n bytes is n + 1 chars;
+// the ascii85 math over the RFC 1924 alphabet, without abbreviations
+pub fn rfc1924_encode(quantum: &[u8], out: &mut [u8], alphabet: &Alphabet) {
+    ascii85_encode_quantum(quantum, out, alphabet);
+}
@@ -0,0 +1,272 @@
+#![cfg(feature = "std")]
+use crate::makura_alloc::{Vec, vec};
+use crate::makura_std::io;
+
+use super::{BASE85, Encoder, LineEnding};
+#[cfg(feature = "base32_crockford")]
+use crate::crockford::{check_symbol, checksum_from};
+
+// max number of whole quanta that get encoded into a single string before being written
+// this bounds the memory used by a single (possibly huge) write call
+const QUANTA_PER_WRITE: usize = 1024;
+
+/// encodes everything written to it and streams the encoded value into an inner writer
+///
+/// only a partial quantum is ever buffered;
+/// less than 3 bytes for base64(url), 5 for base32(hex), 2 for base45 and 4 for the base85s,
+/// every whole quantum is encoded and written through as soon as it is received
+///
+/// ascii85's `<~` delimiter is written by the first write, and its `~>` one by `finish`
+///
+/// the encoded len of base58, base62 and base36 depends on the whole value,
+/// so everything written to such a writer is buffered until `finish`
+///
+/// the last partial quantum, along with its padding, is written by `finish`,
+/// or when the writer is dropped.
+/// prefer `finish`, since errors can not be reported on drop
+///
+/// z85 has no partial quanta, `finish` fails with an `io::ErrorKind::InvalidInput` error
+/// when the written len isn't a multiple of 4
+///
+/// the encoder's line wrapping is applied over the whole stream,
+/// whatever the sizes of the writes are;
+/// so is crockford's check symbol, written once by `finish`
+///
+/// ```
+/// use std::io::Write;
+/// use makura::{Encoder, EncoderWriter};
+///
+/// let mut writer = EncoderWriter::new(Vec::new(), Encoder::base64());
+/// writer.write_all(b"foo").unwrap();
+/// writer.write_all(b"ba").unwrap();
+///
+/// assert_eq!(writer.finish().unwrap(), b"Zm9vYmE=");
+/// ```
+pub struct EncoderWriter<W: io::Write> {
+    encoder: Encoder,
+    // only None after finish was called
+    inner: Option<W>,
+    // the bytes of the partial quantum, or of the whole input for bases without quanta
+    pending: Vec<u8>,
+    // number of chars written to the current line, only used when wrapping
+    column: usize,
+    // the running check symbol checksum of the chars written so far
+    #[cfg(feature = "base32_crockford")]
+    checksum: u8,
+    // whether the opening ascii85 delimiter was written
+    opened: bool,
+}
+
+impl<W: io::Write> EncoderWriter<W> {
+    /// creates a new writer that encodes into inner with the given encoder's base, padding and alphabet
+    pub fn new(inner: W, encoder: Encoder) -> Self {
+        Self {
+            encoder,
+            inner: Some(inner),
+            pending: Vec::new(),
+            column: 0,
+            #[cfg(feature = "base32_crockford")]
+            checksum: 0,
+            opened: false,
+        }
+    }
+
+    /// returns the encoder of the writer
+    pub fn encoder(&self) -> &Encoder {
+        &self.encoder
+    }
+
+    /// returns an immutable reference to the inner writer
+    pub fn get_ref(&self) -> &W {
+        self.inner
+            .as_ref()
+            .expect("inner writer is only taken by finish")
+    }
+
+    /// returns a mutable reference to the inner writer
+    ///
+    /// writing directly to the inner writer will likely corrupt the encoded output
+    pub fn get_mut(&mut self) -> &mut W {
+        self.inner
+            .as_mut()
+            .expect("inner writer is only taken by finish")
+    }
+
+    /// encodes and writes the last partial quantum, if any, along with its padding,
+    /// the check symbol or the closing delimiter, flushes the inner writer then returns it
+    pub fn finish(mut self) -> io::Result<W> {
+        self.write_end()?;
+
+        let mut inner = self
+            .inner
+            .take()
+            .expect("inner writer is only taken by finish");
+        inner.flush()?;
+
+        Ok(inner)
+    }
+
+    // writes the partial quantum then the check symbol or the delimiters, once the input is over
+    fn write_end(&mut self) -> io::Result<()> {
+        self.write_pending()?;
+
+        // NOTE an empty input is still delimited
+        if self.is_delimited() {
+            self.write_opening()?;
+            self.write_delimiter(b"~>")?;
+        }
+
+        #[cfg(feature = "base32_crockford")]
+        if self.encoder.check_symbol
+            && let Some(inner) = self.inner.as_mut()
+        {
+            let alphabet = self
+                .encoder
+                .alphabet()
+                .unwrap_or(self.encoder.base().alphabet());
+            let symbol = check_symbol(self.checksum, alphabet);
+            write_wrapped(inner, &[symbol], &mut self.column, self.encoder.wrap)?;
+        }
+
+        Ok(())
+    }
+
+    // encodes the partial quantum, padding it if it is not whole
+    fn write_pending(&mut self) -> io::Result<()> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+
+        let encoder = self.encoder.chars_only();
+        let mut encoded = vec![0; encoder.encoded_len(self.pending.len())];
+        let len = encoder
+            .encode_to_slice(&self.pending, &mut encoded)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        self.pending.clear();
+
+        self.write_encoded(&encoded[..len])
+    }
+
+    fn is_delimited(&self) -> bool {
+        self.encoder.delimiters && *self.encoder.base() == BASE85
+    }
+
+    // writes the opening ascii85 delimiter, unless it already was
+    fn write_opening(&mut self) -> io::Result<()> {
+        if self.opened {
+            return Ok(());
+        }
+        self.opened = true;
+
+        self.write_delimiter(b"<~")
+    }
+
+    // writes an ascii85 delimiter, wrapped as the encoded chars are
+    fn write_delimiter(&mut self, delimiter: &[u8]) -> io::Result<()> {
+        let Some(inner) = self.inner.as_mut() else {
+            return Ok(());
+        };
+
+        write_wrapped(inner, delimiter, &mut self.column, self.encoder.wrap)
+    }
+
+    // writes encoded chars, wrapped, to inner and sums them up for the check symbol
+    fn write_encoded(&mut self, encoded: &[u8]) -> io::Result<()> {
+        let Some(inner) = self.inner.as_mut() else {
+            return Ok(());
+        };
+
+        #[cfg(feature = "base32_crockford")]
+        if self.encoder.check_symbol {
+            let alphabet = self
+                .encoder
+                .alphabet()
+                .unwrap_or(self.encoder.base().alphabet());
+            self.checksum = checksum_from(
+                self.checksum,
+                encoded.iter().filter_map(|c| alphabet.idx_of(*c)),
+            );
+        }
+
+        write_wrapped(inner, encoded, &mut self.column, self.encoder.wrap)
+    }
+}
+
+// writes the encoded chars to inner,
+// inserting a line ending every time a line of the wrap width is full and more chars follow
+fn write_wrapped<W: io::Write>(
+    inner: &mut W,
+    mut encoded: &[u8],
+    column: &mut usize,
+    wrap: Option<(usize, LineEnding)>,
+) -> io::Result<()> {
+    let Some((width, ending)) = wrap else {
+        return inner.write_all(encoded);
+    };
+
+    while !encoded.is_empty() {
+        if *column == width {
+            inner.write_all(ending.as_bytes())?;
+            *column = 0;
+        }
+
+        let take = (width - *column).min(encoded.len());
+        inner.write_all(&encoded[..take])?;
+        *column += take;
+        encoded = &encoded[take..];
+    }
+
+    Ok(())
+}
+
+impl<W: io::Write> io::Write for EncoderWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.encoder.base().is_radix() {
+            self.pending.extend_from_slice(buf);
+
+            return Ok(buf.len());
+        }
+        if self.is_delimited() {
+            self.write_opening()?;
+        }
+
+        let quantum = self.encoder.base().quantum();
+        let mut input = buf;
+
+        // complete the partial quantum first
+        if !self.pending.is_empty() {
+            let take = (quantum - self.pending.len()).min(input.len());
+            self.pending.extend_from_slice(&input[..take]);
+            input = &input[take..];
+
+            if self.pending.len() < quantum {
+                return Ok(buf.len());
+            }
+            self.write_pending()?;
+        }
+
+        let whole = input.len() - input.len() % quantum;
+        let encoder = self.encoder.chars_only();
+        for chunk in input[..whole].chunks(quantum * QUANTA_PER_WRITE) {
+            let encoded = encoder.encode_bytes(chunk);
+            self.write_encoded(encoded.as_bytes())?;
+        }
+
+        self.pending.extend_from_slice(&input[whole..]);
+
+        Ok(buf.len())
+    }
+
+    // NOTE this can not flush the partial quantum,
+    // as it can only be encoded once the input is known to be over
+    fn flush(&mut self) -> io::Result<()> {
+        self.get_mut().flush()
+    }
+}
+
+impl<W: io::Write> Drop for EncoderWriter<W> {
+    fn drop(&mut self) {
+        // errors are ignored, use finish to catch them
+        let _ = self.write_end();
+    }
+}
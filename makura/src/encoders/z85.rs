@@ -0,0 +1,10 @@
+#![cfg(feature = "z85")]
+use crate::Alphabet;
+
+use super::ascii85::ascii85_encode_quantum;
+
+// encodes a whole quantum of 4 bytes into out as 5 chars,
+// z85 is the ascii85 math over its own alphabet, without abbreviations nor partial quanta
+pub fn z85_encode(quantum: &[u8], out: &mut [u8], alphabet: &Alphabet) {
+    ascii85_encode_quantum(quantum, out, alphabet);
+}
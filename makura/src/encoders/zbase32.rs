@@ -0,0 +1,26 @@
+#![cfg(all(feature = "zbase32", feature = "alloc"))]
+use crate::Alphabet;
+
+use super::base32::into_5bits_groups;
+
+// encodes the first bits bits of value into out, 5 bits per char, most significant first;
+// the bits that the last char lacks are 0s
+//
+// NOTE unlike base32, z-base-32 values don't have to be made of whole bytes,
+// e.g., 1 bit is a single char
+//
+// value has to hold at least bits bits and out at least bits.div_ceil(5) bytes,
+// returns the number of chars written to out
+pub fn zbase32_encode_bits(
+    value: &[u8],
+    bits: usize,
+    out: &mut [u8],
+    alphabet: &Alphabet,
+) -> usize {
+    let chars = into_5bits_groups(value, bits, out);
+    out[..chars]
+        .iter_mut()
+        .for_each(|idx| *idx = alphabet.char_at(*idx) as u8);
+
+    chars
+}
@@ -0,0 +1,112 @@
+#![cfg(all(
+    feature = "alloc",
+    feature = "rfc1924",
+    feature = "encoding",
+    feature = "decoding"
+))]
+//! the base85 lines of git binary patches (`git diff --binary`)
+//!
+//! every line holds up to 52 bytes, prefixed with their count,
+//! 'A' to 'Z' for 1 to 26 bytes and 'a' to 'z' for 27 to 52 bytes,
+//! then encoded with the RFC 1924 alphabet in whole quanta of 5 chars
+//!
+//! ```
+//! use makura::git;
+//!
+//! let encoded = git::encode(b"hello world\n");
+//! assert_eq!(encoded, "LXk~0{Zy<MXa%^M@\n");
+//!
+//! assert_eq!(git::decode(encoded).unwrap(), b"hello world\n");
+//! ```
+use crate::makura_alloc::{String, Vec};
+
+use crate::{BASE85RFC, DecodeError, Decoder, Encoder};
+
+/// the max number of bytes in a single line
+pub const LINE_BYTES: usize = 52;
+
+// the chars that prefix every line with its number of bytes
+const SHORT: core::ops::RangeInclusive<u8> = b'A'..=b'Z';
+const LONG: core::ops::RangeInclusive<u8> = b'a'..=b'z';
+
+/// encodes data into lines of up to `LINE_BYTES` bytes, each ending with a '\n'
+///
+/// the last quantum of a line is padded with 0 bytes,
+/// its len prefix tells how many of them are data
+pub fn encode<T: AsRef<[u8]>>(data: T) -> String {
+    let enc = Encoder::base85_rfc();
+    let mut encoded = String::new();
+
+    data.as_ref().chunks(LINE_BYTES).for_each(|line| {
+        encoded.push(len_char(line.len()));
+
+        // NOTE git encodes whole quanta only
+        let mut quanta = [0u8; LINE_BYTES];
+        quanta[..line.len()].copy_from_slice(line);
+        encoded.push_str(&enc.encode_bytes(&quanta[..line.len().div_ceil(4) * 4]));
+        encoded.push('\n');
+    });
+
+    encoded
+}
+
+/// decodes the lines of a git binary hunk, e.g., the lines following a `literal <len>` line
+///
+/// empty lines are ignored, so are the '\r' of "\r\n" line endings
+///
+/// # Error
+/// returns the `Base85RFC` `DecodeError`s of `Decoder::decode`, plus
+/// * `DecodeError::InvalidChar` when a line doesn't start with a valid len char
+/// * `DecodeError::InvalidLen` when a line's chars don't match its len prefix
+pub fn decode<T: AsRef<[u8]>>(value: T) -> Result<Vec<u8>, DecodeError> {
+    let mut decoded = Vec::new();
+
+    value
+        .as_ref()
+        .split(|c| *c == b'\n')
+        .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+        .filter(|line| !line.is_empty())
+        .try_for_each(|line| decode_line(line, &mut decoded))?;
+
+    Ok(decoded)
+}
+
+// decodes a single non empty line, appending its bytes to decoded
+fn decode_line(line: &[u8], decoded: &mut Vec<u8>) -> Result<(), DecodeError> {
+    let len = line_len(line[0])?;
+    let chars = &line[1..];
+    if chars.len() != len.div_ceil(4) * 5 {
+        return Err(DecodeError::InvalidLen {
+            len: chars.len(),
+            base: BASE85RFC,
+        });
+    }
+
+    let start = decoded.len();
+    decoded.resize(start + chars.len() / 5 * 4, 0);
+    Decoder::new().decode_to_slice(chars, BASE85RFC, &mut decoded[start..])?;
+    // the padding bytes of the last quantum
+    decoded.truncate(start + len);
+
+    Ok(())
+}
+
+// the prefix char of a line of len bytes, len is in 1..=LINE_BYTES
+fn len_char(len: usize) -> char {
+    match len {
+        1..=26 => (b'A' + len as u8 - 1) as char,
+        _ => (b'a' + len as u8 - 27) as char,
+    }
+}
+
+// the number of bytes of a line prefixed with chr
+fn line_len(chr: u8) -> Result<usize, DecodeError> {
+    match chr {
+        _ if SHORT.contains(&chr) => Ok((chr - b'A') as usize + 1),
+        _ if LONG.contains(&chr) => Ok((chr - b'a') as usize + 27),
+        _ => Err(DecodeError::InvalidChar {
+            char: chr as char,
+            base: BASE85RFC,
+        }),
+    }
+}
@@ -1,29 +1,82 @@
 #![no_std]
-// #![cfg_attr(not(feature = "std"), no_std)]
 #![doc(html_playground_url = "https://play.rust-lang.org/?version=stable&mode=debug&edition=2024")]
 #![cfg_attr(feature = "nightly", feature(doc_auto_cfg))]
 #![cfg_attr(feature = "nightly", feature(test))]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod alphabet;
+pub use alphabet::{Alphabet, AlphabetError};
+
 mod base_transformer;
 pub(crate) use base_transformer::BaseTransformer;
 
+mod crockford;
 mod decoders;
 mod encoders;
-
+mod sha256;
+
+pub mod armor;
+pub mod base58check;
+pub mod base91;
+pub mod bech32;
+pub mod git;
+pub mod pem;
+pub mod quoted_printable;
+pub mod uuencode;
+pub mod zbase32;
+
+#[cfg(feature = "alloc")]
 pub use decoders::Bases;
 pub use decoders::DecodeError;
+#[cfg(feature = "alloc")]
 pub use decoders::DecodeOutput;
 pub use decoders::Decoder;
+#[cfg(feature = "std")]
+pub use decoders::{DecoderReader, StreamDecodeError};
+pub use encoders::EncodeError;
 pub use encoders::Encoder;
+#[cfg(feature = "std")]
+pub use encoders::EncoderWriter;
+pub use encoders::LineEnding;
 
 pub(crate) const PAD: char = '=';
 
+/// padding policy of an `Encoder` or a `Decoder`
+///
+/// only base 64(url) and 32(hex) have padding chars,
+/// the policy is ignored by the other bases
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Padding {
+    /// the encoded value always ends with its padding chars, as in RFC 4648;
+    /// the decoder rejects unpadded input
+    #[default]
+    Required,
+    /// the encoded value never has padding chars, e.g., JWTs (RFC 7515);
+    /// the decoder rejects padded input
+    Omitted,
+    /// the decoder accepts both padded and unpadded input;
+    /// the encoder behaves as with `Required`
+    Indifferent,
+}
+
 pub const BASE64: Base = Base::_64;
 pub const BASE64URL: Base = Base::_64URL;
 pub const BASE32: Base = Base::_32;
 pub const BASE32HEX: Base = Base::_32HEX;
 pub const BASE16: Base = Base::_16;
 pub const BASE45: Base = Base::_45;
+pub const BASE58: Base = Base::_58;
+pub const BASE85: Base = Base::_85;
+pub const Z85: Base = Base::_Z85;
+pub const BASE85RFC: Base = Base::_85RFC;
+pub const BASE32CROCKFORD: Base = Base::_32CROCKFORD;
+pub const ZBASE32: Base = Base::_Z32;
+pub const BASE62: Base = Base::_62;
+pub const BASE36: Base = Base::_36;
 
 #[derive(PartialEq, Default, Clone, Copy, Ord, PartialOrd, Eq, Hash)]
 // #[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
@@ -35,6 +88,23 @@ pub enum Base {
     _32,
     _32HEX,
     _16,
+    // NOTE comes last, so that `Bases::deduce_sorted` only picks it
+    // when none of the rfc bases are valid
+    _58,
+    // ascii85 (adobe/btoa)
+    _85,
+    // zeromq's base85 (RFC 32)
+    _Z85,
+    // the RFC 1924 alphabet, e.g., git binary patches
+    _85RFC,
+    // crockford's base32, for human entered codes
+    _32CROCKFORD,
+    // z-base-32, the permuted lowercase base32 of tahoe-lafs
+    _Z32,
+    // 0-9A-Za-z, e.g., url slugs and ids
+    _62,
+    // 0-9a-z, case insensitive, e.g., reddit ids
+    _36,
 }
 
 impl core::fmt::Debug for Base {
@@ -49,6 +119,14 @@ impl core::fmt::Debug for Base {
                 Self::_32 => "Base32",
                 Self::_32HEX => "Base32HEX",
                 Self::_16 => "Base16",
+                Self::_58 => "Base58",
+                Self::_85 => "Ascii85",
+                Self::_Z85 => "Z85",
+                Self::_85RFC => "Base85RFC",
+                Self::_32CROCKFORD => "Base32Crockford",
+                Self::_Z32 => "ZBase32",
+                Self::_62 => "Base62",
+                Self::_36 => "Base36",
             }
         )
     }
@@ -66,6 +144,14 @@ impl core::fmt::Display for Base {
                 Self::_32 => "Base32",
                 Self::_32HEX => "Base32HEX",
                 Self::_16 => "Base16",
+                Self::_58 => "Base58",
+                Self::_85 => "Ascii85",
+                Self::_Z85 => "Z85",
+                Self::_85RFC => "Base85RFC",
+                Self::_32CROCKFORD => "Base32Crockford",
+                Self::_Z32 => "ZBase32",
+                Self::_62 => "Base62",
+                Self::_36 => "Base36",
             }
         )
     }
@@ -83,7 +169,7 @@ impl core::fmt::Display for BaseError {
             "{}",
             match self {
                 Self::InvalidStrBaseValue =>
-                    "Received invalid string value for base name, string base name should be one of [64, 64URL, 45, 32, 32HEX, 16]",
+                    "Received invalid string value for base name, string base name should be one of [64, 64URL, 45, 32, 32HEX, 16, 58, ascii85, z85, 85rfc, 32crockford, zbase32, 62, 36]",
             }
         )
     }
@@ -102,335 +188,150 @@ impl core::str::FromStr for Base {
             "32" => Ok(BASE32),
             "32hex" => Ok(BASE32HEX),
             "16" => Ok(BASE16),
+            "58" => Ok(BASE58),
+            "ascii85" => Ok(BASE85),
+            "z85" => Ok(Z85),
+            "85rfc" => Ok(BASE85RFC),
+            "32crockford" => Ok(BASE32CROCKFORD),
+            "zbase32" => Ok(ZBASE32),
+            "62" => Ok(BASE62),
+            "36" => Ok(BASE36),
             _ => Err(BaseError::InvalidStrBaseValue),
         }
     }
 }
 
 impl Base {
-    // first 26 values of base encoding table are the uppercase alphabet letters A -> Z
-    fn alpha_26(&self) -> bool {
-        self == &Self::_64 || self == &Self::_64URL || self == &Self::_32
+    // number of chars in the encoding table of self
+    pub(crate) fn radix(&self) -> u8 {
+        match self {
+            Self::_64 | Self::_64URL => 64,
+            Self::_45 => 45,
+            Self::_32 | Self::_32HEX | Self::_32CROCKFORD | Self::_Z32 => 32,
+            Self::_16 => 16,
+            Self::_58 => 58,
+            Self::_62 => 62,
+            Self::_36 => 36,
+            Self::_85 | Self::_Z85 | Self::_85RFC => 85,
+        }
     }
 
-    // first 16 values in the base encoding table are the base 16 numbers 0 -> F
-    fn hex_16(&self) -> bool {
-        self == &Self::_32HEX || self == &Self::_16
+    // whether the encoded len of self depends on the value and not only on its len;
+    // base58, base62 and base36 encode the whole input as a single big number,
+    // ascii85 abbreviates some quanta into a single char
+    //
+    // such bases always go through their own encode/decode paths
+    pub(crate) fn has_variable_len(&self) -> bool {
+        matches!(self, Self::_58 | Self::_85 | Self::_62 | Self::_36)
     }
 
-    // base is 64 or 64 url
-    fn is_any_64(&self) -> bool {
-        self == &Self::_64 || self == &Self::_64URL
+    // whether self encodes a value as a single number in its radix,
+    // so that integers can be encoded as such, see `Encoder::encode_u128`
+    pub(crate) fn is_radix(&self) -> bool {
+        matches!(self, Self::_58 | Self::_62 | Self::_36)
     }
 
-    // base is strictly 32
-    fn is_32(&self) -> bool {
-        self == &Self::_32
+    // whether self's encoded values can end with padding chars
+    pub(crate) fn is_paddable(&self) -> bool {
+        matches!(self, Self::_64 | Self::_64URL | Self::_32 | Self::_32HEX)
     }
 
-    // base is strictly 32 hex
-    fn is_32_hex(&self) -> bool {
-        self == &Self::_32HEX
+    // whether self is one of the rfc 4648 bases or base45 (rfc 9285),
+    // the only bases with a padding char;
+    // the deduction only reports the other bases when none of these is valid
+    pub(crate) fn is_rfc(&self) -> bool {
+        matches!(
+            self,
+            Self::_64 | Self::_64URL | Self::_32 | Self::_32HEX | Self::_16 | Self::_45
+        )
     }
 
-    fn is_45(&self) -> bool {
-        self == &Self::_45
+    // whether chr is valid in a value of self without being one of its alphabet's chars,
+    // i.e., ascii85's `z`/`y` abbreviations and the '~' of its `<~`/`~>` delimiters
+    pub(crate) fn is_extra_char(&self, chr: u8) -> bool {
+        matches!((self, chr), (Self::_85, b'z' | b'y' | b'~'))
     }
-}
 
-pub(crate) fn char_from_idx(idx: u8, base: &Base) -> char {
-    // NOTE handled by the panic below
-    // if idx > 63 {
-    //     return Err(DecodeError::TableIndexOverflow(idx));
-    // }
-
-    match idx {
-        // alpha
-        0 if base.alpha_26() => 'A',
-        1 if base.alpha_26() => 'B',
-        2 if base.alpha_26() => 'C',
-        3 if base.alpha_26() => 'D',
-        4 if base.alpha_26() => 'E',
-        5 if base.alpha_26() => 'F',
-        6 if base.alpha_26() => 'G',
-        7 if base.alpha_26() => 'H',
-        8 if base.alpha_26() => 'I',
-        9 if base.alpha_26() => 'J',
-        10 if base.alpha_26() => 'K',
-        11 if base.alpha_26() => 'L',
-        12 if base.alpha_26() => 'M',
-        13 if base.alpha_26() => 'N',
-        14 if base.alpha_26() => 'O',
-        15 if base.alpha_26() => 'P',
-        16 if base.alpha_26() => 'Q',
-        17 if base.alpha_26() => 'R',
-        18 if base.alpha_26() => 'S',
-        19 if base.alpha_26() => 'T',
-        20 if base.alpha_26() => 'U',
-        21 if base.alpha_26() => 'V',
-        22 if base.alpha_26() => 'W',
-        23 if base.alpha_26() => 'X',
-        24 if base.alpha_26() => 'Y',
-        25 if base.alpha_26() => 'Z',
-
-        26 if base.is_any_64() => 'a',
-        27 if base.is_any_64() => 'b',
-        28 if base.is_any_64() => 'c',
-        29 if base.is_any_64() => 'd',
-        30 if base.is_any_64() => 'e',
-        31 if base.is_any_64() => 'f',
-        32 if base.is_any_64() => 'g',
-        33 if base.is_any_64() => 'h',
-        34 if base.is_any_64() => 'i',
-        35 if base.is_any_64() => 'j',
-        36 if base.is_any_64() => 'k',
-        37 if base.is_any_64() => 'l',
-        38 if base.is_any_64() => 'm',
-        39 if base.is_any_64() => 'n',
-        40 if base.is_any_64() => 'o',
-        41 if base.is_any_64() => 'p',
-        42 if base.is_any_64() => 'q',
-        43 if base.is_any_64() => 'r',
-        44 if base.is_any_64() => 's',
-        45 if base.is_any_64() => 't',
-        46 if base.is_any_64() => 'u',
-        47 if base.is_any_64() => 'v',
-        48 if base.is_any_64() => 'w',
-        49 if base.is_any_64() => 'x',
-        50 if base.is_any_64() => 'y',
-        51 if base.is_any_64() => 'z',
-        52 if base.is_any_64() => '0',
-        53 if base.is_any_64() => '1',
-        54 if base.is_any_64() => '2',
-        55 if base.is_any_64() => '3',
-        56 if base.is_any_64() => '4',
-        57 if base.is_any_64() => '5',
-        58 if base.is_any_64() => '6',
-        59 if base.is_any_64() => '7',
-        60 if base.is_any_64() => '8',
-        61 if base.is_any_64() => '9',
-
-        // NOTE base 64 is done with this
-        62 if base == &Base::_64 => '+',
-        63 if base == &Base::_64 => '/',
-
-        // NOTE base 64 url is done with this
-        62 if base == &Base::_64URL => '-',
-        63 if base == &Base::_64URL => '_',
-
-        // NOTE base 32 is done with tihs
-        26 if base.is_32() => '2',
-        27 if base.is_32() => '3',
-        28 if base.is_32() => '4',
-        29 if base.is_32() => '5',
-        30 if base.is_32() => '6',
-        31 if base.is_32() => '7',
-
-        // hex
-        // NOTE base 16 is done with this
-        0 if base.hex_16() | base.is_45() => '0',
-        1 if base.hex_16() | base.is_45() => '1',
-        2 if base.hex_16() | base.is_45() => '2',
-        3 if base.hex_16() | base.is_45() => '3',
-        4 if base.hex_16() | base.is_45() => '4',
-        5 if base.hex_16() | base.is_45() => '5',
-        6 if base.hex_16() | base.is_45() => '6',
-        7 if base.hex_16() | base.is_45() => '7',
-        8 if base.hex_16() | base.is_45() => '8',
-        9 if base.hex_16() | base.is_45() => '9',
-        10 if base.hex_16() | base.is_45() => 'A',
-        11 if base.hex_16() | base.is_45() => 'B',
-        12 if base.hex_16() | base.is_45() => 'C',
-        13 if base.hex_16() | base.is_45() => 'D',
-        14 if base.hex_16() | base.is_45() => 'E',
-        15 if base.hex_16() | base.is_45() => 'F',
-
-        // NOTE base 32 hex is done with this
-        16 if base.is_32_hex() | base.is_45() => 'G',
-        17 if base.is_32_hex() | base.is_45() => 'H',
-        18 if base.is_32_hex() | base.is_45() => 'I',
-        19 if base.is_32_hex() | base.is_45() => 'J',
-        20 if base.is_32_hex() | base.is_45() => 'K',
-        21 if base.is_32_hex() | base.is_45() => 'L',
-        22 if base.is_32_hex() | base.is_45() => 'M',
-        23 if base.is_32_hex() | base.is_45() => 'N',
-        24 if base.is_32_hex() | base.is_45() => 'O',
-        25 if base.is_32_hex() | base.is_45() => 'P',
-        26 if base.is_32_hex() | base.is_45() => 'Q',
-        27 if base.is_32_hex() | base.is_45() => 'R',
-        28 if base.is_32_hex() | base.is_45() => 'S',
-        29 if base.is_32_hex() | base.is_45() => 'T',
-        30 if base.is_32_hex() | base.is_45() => 'U',
-        31 if base.is_32_hex() | base.is_45() => 'V',
-
-        32 if base.is_45() => 'W',
-        33 if base.is_45() => 'X',
-        34 if base.is_45() => 'Y',
-        35 if base.is_45() => 'Z',
-        36 if base.is_45() => ' ',
-        37 if base.is_45() => '$',
-        38 if base.is_45() => '%',
-        39 if base.is_45() => '*',
-        40 if base.is_45() => '+',
-        41 if base.is_45() => '-',
-        42 if base.is_45() => '.',
-        43 if base.is_45() => '/',
-        44 if base.is_45() => ':',
-
-        // NOTE let this panic, since this crate is always in control of the u8 that
-        // this function receives, guarenteeing that this match arm is always unreachable
-        idx => unreachable!("got impossile table index {} for base {:?}", idx, base),
+    // whether chr is left out of the values of self wherever it is, i.e., crockford's hyphens
+    pub(crate) fn is_ignored_char(&self, chr: u8) -> bool {
+        matches!((self, chr), (Self::_32CROCKFORD, b'-'))
+    }
+
+    // whether some chars are left out of the values of self, see is_ignored_char
+    pub(crate) fn has_ignored_chars(&self) -> bool {
+        matches!(self, Self::_32CROCKFORD)
+    }
+
+    // number of input bytes in a whole encoding quantum of self
+    pub(crate) fn quantum(&self) -> usize {
+        match self {
+            Self::_64 | Self::_64URL => 3,
+            Self::_45 => 2,
+            Self::_32 | Self::_32HEX | Self::_32CROCKFORD | Self::_Z32 => 5,
+            Self::_16 => 1,
+            Self::_85 | Self::_Z85 | Self::_85RFC => 4,
+            Self::_58 | Self::_62 | Self::_36 => unreachable!("{} has no quanta", self),
+        }
+    }
+
+    // number of encoded chars in a whole encoding quantum of self
+    pub(crate) fn encoded_quantum(&self) -> usize {
+        match self {
+            Self::_64 | Self::_64URL => 4,
+            Self::_45 => 3,
+            Self::_32 | Self::_32HEX | Self::_32CROCKFORD | Self::_Z32 => 8,
+            Self::_16 => 2,
+            Self::_85 | Self::_Z85 | Self::_85RFC => 5,
+            Self::_58 | Self::_62 | Self::_36 => unreachable!("{} has no quanta", self),
+        }
+    }
+
+    // number of encoded chars, padding excluded, of a partial quantum of bytes input bytes
+    pub(crate) fn partial_encoded_len(&self, bytes: usize) -> usize {
+        match self {
+            // every char holds 6 bits
+            Self::_64 | Self::_64URL => (bytes * 8).div_ceil(6),
+            // every char holds 5 bits
+            Self::_32 | Self::_32HEX | Self::_32CROCKFORD | Self::_Z32 => (bytes * 8).div_ceil(5),
+            Self::_45 if bytes == 1 => 2,
+            // a partial quantum of n bytes keeps n + 1 chars of its whole quantum
+            // NOTE z85 only encodes whole quanta, see `Encoder::encode_to_slice`
+            Self::_85 | Self::_Z85 | Self::_85RFC => bytes + 1,
+            Self::_45 | Self::_16 => 0,
+            Self::_58 | Self::_62 | Self::_36 => unreachable!("{} has no quanta", self),
+        }
     }
-}
 
-pub(crate) fn idx_from_char(chr: char, base: &Base) -> Result<u8, DecodeError> {
-    match chr {
-        // alpha
-        'A' if base.alpha_26() => Ok(0),
-        'B' if base.alpha_26() => Ok(1),
-        'C' if base.alpha_26() => Ok(2),
-        'D' if base.alpha_26() => Ok(3),
-        'E' if base.alpha_26() => Ok(4),
-        'F' if base.alpha_26() => Ok(5),
-        'G' if base.alpha_26() => Ok(6),
-        'H' if base.alpha_26() => Ok(7),
-        'I' if base.alpha_26() => Ok(8),
-        'J' if base.alpha_26() => Ok(9),
-        'K' if base.alpha_26() => Ok(10),
-        'L' if base.alpha_26() => Ok(11),
-        'M' if base.alpha_26() => Ok(12),
-        'N' if base.alpha_26() => Ok(13),
-        'O' if base.alpha_26() => Ok(14),
-        'P' if base.alpha_26() => Ok(15),
-        'Q' if base.alpha_26() => Ok(16),
-        'R' if base.alpha_26() => Ok(17),
-        'S' if base.alpha_26() => Ok(18),
-        'T' if base.alpha_26() => Ok(19),
-        'U' if base.alpha_26() => Ok(20),
-        'V' if base.alpha_26() => Ok(21),
-        'W' if base.alpha_26() => Ok(22),
-        'X' if base.alpha_26() => Ok(23),
-        'Y' if base.alpha_26() => Ok(24),
-        'Z' if base.alpha_26() => Ok(25),
-
-        'a' if base.is_any_64() => Ok(26),
-        'b' if base.is_any_64() => Ok(27),
-        'c' if base.is_any_64() => Ok(28),
-        'd' if base.is_any_64() => Ok(29),
-        'e' if base.is_any_64() => Ok(30),
-        'f' if base.is_any_64() => Ok(31),
-        'g' if base.is_any_64() => Ok(32),
-        'h' if base.is_any_64() => Ok(33),
-        'i' if base.is_any_64() => Ok(34),
-        'j' if base.is_any_64() => Ok(35),
-        'k' if base.is_any_64() => Ok(36),
-        'l' if base.is_any_64() => Ok(37),
-        'm' if base.is_any_64() => Ok(38),
-        'n' if base.is_any_64() => Ok(39),
-        'o' if base.is_any_64() => Ok(40),
-        'p' if base.is_any_64() => Ok(41),
-        'q' if base.is_any_64() => Ok(42),
-        'r' if base.is_any_64() => Ok(43),
-        's' if base.is_any_64() => Ok(44),
-        't' if base.is_any_64() => Ok(45),
-        'u' if base.is_any_64() => Ok(46),
-        'v' if base.is_any_64() => Ok(47),
-        'w' if base.is_any_64() => Ok(48),
-        'x' if base.is_any_64() => Ok(49),
-        'y' if base.is_any_64() => Ok(50),
-        'z' if base.is_any_64() => Ok(51),
-        '0' if base.is_any_64() => Ok(52),
-        '1' if base.is_any_64() => Ok(53),
-        '2' if base.is_any_64() => Ok(54),
-        '3' if base.is_any_64() => Ok(55),
-        '4' if base.is_any_64() => Ok(56),
-        '5' if base.is_any_64() => Ok(57),
-        '6' if base.is_any_64() => Ok(58),
-        '7' if base.is_any_64() => Ok(59),
-        '8' if base.is_any_64() => Ok(60),
-        '9' if base.is_any_64() => Ok(61),
-
-        // NOTE base 64 is done with this
-        '+' if base == &Base::_64 => Ok(62),
-        '/' if base == &Base::_64 => Ok(63),
-
-        // NOTE base 64 url is done with this
-        '-' if base == &Base::_64URL => Ok(62),
-        '_' if base == &Base::_64URL => Ok(63),
-
-        // NOTE base 32 is done with this
-        '2' if base.is_32() => Ok(26),
-        '3' if base.is_32() => Ok(27),
-        '4' if base.is_32() => Ok(28),
-        '5' if base.is_32() => Ok(29),
-        '6' if base.is_32() => Ok(30),
-        '7' if base.is_32() => Ok(31),
-
-        // hex
-        // NOTE base 16 is done with this
-        '0' if base.hex_16() | base.is_45() => Ok(0),
-        '1' if base.hex_16() | base.is_45() => Ok(1),
-        '2' if base.hex_16() | base.is_45() => Ok(2),
-        '3' if base.hex_16() | base.is_45() => Ok(3),
-        '4' if base.hex_16() | base.is_45() => Ok(4),
-        '5' if base.hex_16() | base.is_45() => Ok(5),
-        '6' if base.hex_16() | base.is_45() => Ok(6),
-        '7' if base.hex_16() | base.is_45() => Ok(7),
-        '8' if base.hex_16() | base.is_45() => Ok(8),
-        '9' if base.hex_16() | base.is_45() => Ok(9),
-        'A' if base.hex_16() | base.is_45() => Ok(10),
-        'B' if base.hex_16() | base.is_45() => Ok(11),
-        'C' if base.hex_16() | base.is_45() => Ok(12),
-        'D' if base.hex_16() | base.is_45() => Ok(13),
-        'E' if base.hex_16() | base.is_45() => Ok(14),
-        'F' if base.hex_16() | base.is_45() => Ok(15),
-
-        // NOTE base 32 hex is done with this
-        'G' if base.is_32_hex() | base.is_45() => Ok(16),
-        'H' if base.is_32_hex() | base.is_45() => Ok(17),
-        'I' if base.is_32_hex() | base.is_45() => Ok(18),
-        'J' if base.is_32_hex() | base.is_45() => Ok(19),
-        'K' if base.is_32_hex() | base.is_45() => Ok(20),
-        'L' if base.is_32_hex() | base.is_45() => Ok(21),
-        'M' if base.is_32_hex() | base.is_45() => Ok(22),
-        'N' if base.is_32_hex() | base.is_45() => Ok(23),
-        'O' if base.is_32_hex() | base.is_45() => Ok(24),
-        'P' if base.is_32_hex() | base.is_45() => Ok(25),
-        'Q' if base.is_32_hex() | base.is_45() => Ok(26),
-        'R' if base.is_32_hex() | base.is_45() => Ok(27),
-        'S' if base.is_32_hex() | base.is_45() => Ok(28),
-        'T' if base.is_32_hex() | base.is_45() => Ok(29),
-        'U' if base.is_32_hex() | base.is_45() => Ok(30),
-        'V' if base.is_32_hex() | base.is_45() => Ok(31),
-
-        'W' if base.is_45() => Ok(32),
-        'X' if base.is_45() => Ok(33),
-        'Y' if base.is_45() => Ok(34),
-        'Z' if base.is_45() => Ok(35),
-        ' ' if base.is_45() => Ok(36),
-        '$' if base.is_45() => Ok(37),
-        '%' if base.is_45() => Ok(38),
-        '*' if base.is_45() => Ok(39),
-        '+' if base.is_45() => Ok(40),
-        '-' if base.is_45() => Ok(41),
-        '.' if base.is_45() => Ok(42),
-        '/' if base.is_45() => Ok(43),
-        ':' if base.is_45() => Ok(44),
-
-        // _ => panic!("got impossile table char {} for base {:?}", chr, base),
-        ch => Err(DecodeError::InvalidChar {
-            char: ch,
-            base: *base,
-        }),
+    // number of decoded bytes of a partial quantum of chars encoded chars, padding excluded
+    pub(crate) fn partial_decoded_len(&self, chars: usize) -> usize {
+        match self {
+            Self::_64 | Self::_64URL => chars * 6 / 8,
+            Self::_32 | Self::_32HEX | Self::_32CROCKFORD | Self::_Z32 => chars * 5 / 8,
+            Self::_45 if chars == 2 => 1,
+            Self::_85 | Self::_Z85 | Self::_85RFC => chars.saturating_sub(1),
+            Self::_45 | Self::_16 => 0,
+            Self::_58 | Self::_62 | Self::_36 => unreachable!("{} has no quanta", self),
+        }
+    }
+
+    // the standard encoding table of self
+    pub(crate) fn alphabet(&self) -> &'static Alphabet {
+        Alphabet::from_base(self)
     }
 }
 
+#[cfg(feature = "alloc")]
 pub(crate) mod makura_alloc {
-    extern crate alloc;
     pub(crate) use alloc::borrow::Cow;
     pub(crate) use alloc::collections::BTreeSet;
     pub(crate) use alloc::string::String;
-    pub(crate) use alloc::{vec, vec::Vec};
+    pub(crate) use alloc::vec;
+    pub(crate) use alloc::vec::Vec;
+}
+
+#[cfg(feature = "std")]
+pub(crate) mod makura_std {
+    pub(crate) use std::io;
 }
 
 pub(crate) mod makura_core {
@@ -440,68 +341,111 @@ pub(crate) mod makura_core {
 
 #[cfg(test)]
 mod tests {
-    use core::assert;
-
-    use super::char_from_idx;
-    use super::{BASE16, BASE32, BASE45, BASE64};
+    use super::{
+        BASE16, BASE32, BASE32CROCKFORD, BASE32HEX, BASE36, BASE45, BASE58, BASE62, BASE64,
+        BASE64URL, BASE85, BASE85RFC, Z85, ZBASE32,
+    };
 
     #[test]
     #[should_panic]
-    fn fail_char_from_idx64() {
-        char_from_idx(64, &BASE64);
-
-        assert!(true);
+    fn fail_char_at64() {
+        BASE64.alphabet().char_at(64);
     }
 
     #[test]
     #[should_panic]
-    fn fail_char_from_idx45() {
-        char_from_idx(45, &BASE45);
-
-        assert!(true);
+    fn fail_char_at45() {
+        BASE45.alphabet().char_at(45);
     }
 
     #[test]
     #[should_panic]
-    fn fail_char_from_idx32() {
-        char_from_idx(32, &BASE32);
-
-        assert!(true);
+    fn fail_char_at32() {
+        BASE32.alphabet().char_at(32);
     }
 
     #[test]
     #[should_panic]
-    fn fail_char_from_idx16() {
-        char_from_idx(16, &BASE16);
+    fn fail_char_at16() {
+        BASE16.alphabet().char_at(16);
+    }
 
-        assert!(true);
+    #[test]
+    fn test_char_at64() {
+        assert_eq!(BASE64.alphabet().char_at(63), '/');
+        assert_eq!(BASE64URL.alphabet().char_at(63), '_');
     }
 
     #[test]
-    fn test_char_from_idx64() {
-        char_from_idx(63, &BASE64);
+    fn test_char_at45() {
+        assert_eq!(BASE45.alphabet().char_at(44), ':');
+    }
 
-        assert!(true);
+    #[test]
+    fn test_char_at32() {
+        assert_eq!(BASE32.alphabet().char_at(31), '7');
+        assert_eq!(BASE32HEX.alphabet().char_at(31), 'V');
     }
 
     #[test]
-    fn test_char_from_idx45() {
-        char_from_idx(44, &BASE45);
+    fn test_char_at16() {
+        assert_eq!(BASE16.alphabet().char_at(15), 'F');
+    }
 
-        assert!(true);
+    #[test]
+    fn test_char_at58() {
+        assert_eq!(BASE58.alphabet().char_at(0), '1');
+        assert_eq!(BASE58.alphabet().char_at(57), 'z');
     }
 
     #[test]
-    fn test_char_from_idx32() {
-        char_from_idx(31, &BASE32);
+    fn test_char_at62() {
+        assert_eq!(BASE62.alphabet().char_at(10), 'A');
+        assert_eq!(BASE62.alphabet().char_at(61), 'z');
+    }
 
-        assert!(true);
+    #[test]
+    fn test_char_at36() {
+        assert_eq!(BASE36.alphabet().char_at(10), 'a');
+        assert_eq!(BASE36.alphabet().idx_of(b'A'), Some(10));
     }
 
     #[test]
-    fn test_char_from_idx16() {
-        char_from_idx(15, &BASE16);
+    fn test_char_at85() {
+        assert_eq!(BASE85.alphabet().char_at(0), '!');
+        assert_eq!(BASE85.alphabet().char_at(84), 'u');
+        assert_eq!(Z85.alphabet().char_at(0), '0');
+        assert_eq!(Z85.alphabet().char_at(84), '#');
+        assert_eq!(BASE85RFC.alphabet().char_at(0), '0');
+        assert_eq!(BASE85RFC.alphabet().char_at(84), '~');
+    }
 
-        assert!(true);
+    #[test]
+    fn test_tables() {
+        // every char maps back to its own index
+        [
+            BASE64,
+            BASE64URL,
+            BASE45,
+            BASE32,
+            BASE32HEX,
+            BASE16,
+            BASE58,
+            BASE85,
+            Z85,
+            BASE85RFC,
+            BASE32CROCKFORD,
+            ZBASE32,
+            BASE62,
+            BASE36,
+        ]
+        .into_iter()
+        .for_each(|base| {
+            let alphabet = base.alphabet();
+            assert_eq!(alphabet.radix(), base.radix() as usize);
+            (0..base.radix()).for_each(|idx| {
+                assert_eq!(alphabet.idx_of(alphabet.char_at(idx) as u8), Some(idx));
+            });
+        });
     }
 }
@@ -0,0 +1,329 @@
+#![cfg(all(
+    feature = "alloc",
+    feature = "base64",
+    feature = "encoding",
+    feature = "decoding"
+))]
+//! PEM armor (RFC 7468), `-----BEGIN <label>-----` / `-----END <label>-----` blocks
+//! of base64 encoded data, e.g., certificates, keys and CSRs
+//!
+//! the RFC 1421 headers of legacy blocks, e.g., the `Proc-Type` and `DEK-Info` headers
+//! of encrypted OpenSSL keys, are parsed and emitted too
+//!
+//! ```
+//! use makura::LineEnding;
+//! use makura::pem::Pem;
+//!
+//! let pem = Pem::new("CERTIFICATE", b"foobar".to_vec());
+//! let encoded = pem.encode(LineEnding::Lf);
+//! assert_eq!(encoded, "-----BEGIN CERTIFICATE-----\nZm9vYmFy\n-----END CERTIFICATE-----\n");
+//!
+//! let parsed = Pem::parse(&encoded).unwrap();
+//! assert_eq!(parsed.label(), "CERTIFICATE");
+//! assert_eq!(parsed.contents(), b"foobar");
+//! ```
+use crate::makura_alloc::{String, Vec};
+
+use crate::{BASE64, DecodeError, Decoder, Encoder, LineEnding};
+
+pub(crate) const BEGIN: &str = "-----BEGIN ";
+pub(crate) const END: &str = "-----END ";
+pub(crate) const DASHES: &str = "-----";
+
+// the `key: value` headers of a block, in order
+pub(crate) type Headers = Vec<(String, String)>;
+
+// RFC 7468 section 2, base64 lines are exactly 64 chars long, but for the last one
+pub(crate) const LINE_WIDTH: usize = 64;
+
+/// errors that can occur while parsing PEM blocks, or the PEM like blocks of the `armor` module
+#[derive(Debug, Clone, PartialEq)]
+pub enum PemError {
+    /// no `-----BEGIN <label>-----` line was found
+    MissingBegin,
+    /// the block of the given label has no `-----END <label>-----` line
+    MissingEnd { label: String },
+    /// the labels of the begin and end lines are different
+    LabelMismatch { begin: String, end: String },
+    /// the label contains chars that are not printable ascii, or a leading or trailing '-'
+    InvalidLabel(String),
+    /// a header line before the blank line isn't a `Key: Value` pair
+    InvalidHeader(String),
+    /// the base64 body of the block couldn't be decoded,
+    /// or, for an armored block, its checksum line or the checksum itself (`DecodeError::ChecksumMismatch`)
+    Decode(DecodeError),
+}
+
+impl core::fmt::Display for PemError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::MissingBegin => write!(f, "no pem begin line was found"),
+            Self::MissingEnd { label } => write!(f, "pem block {:?} has no end line", label),
+            Self::LabelMismatch { begin, end } => write!(
+                f,
+                "pem begin label {:?} doesn't match end label {:?}",
+                begin, end
+            ),
+            Self::InvalidLabel(label) => write!(f, "invalid pem label {:?}", label),
+            Self::InvalidHeader(line) => write!(f, "invalid pem header line {:?}", line),
+            Self::Decode(e) => write!(f, "invalid pem body: {}", e),
+        }
+    }
+}
+
+impl core::error::Error for PemError {}
+
+impl From<DecodeError> for PemError {
+    fn from(value: DecodeError) -> Self {
+        Self::Decode(value)
+    }
+}
+
+/// a single PEM block; a label, optional RFC 1421 headers and the decoded (DER) contents
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Pem {
+    label: String,
+    headers: Headers,
+    contents: Vec<u8>,
+}
+
+impl Pem {
+    /// creates a new block without headers
+    pub fn new<T: Into<String>>(label: T, contents: Vec<u8>) -> Self {
+        Self {
+            label: label.into(),
+            headers: Vec::new(),
+            contents,
+        }
+    }
+
+    /// appends a `key: value` header to the block's headers
+    pub fn with_header<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.headers.push((key.into(), value.into()));
+
+        self
+    }
+
+    /// returns the label of the block, e.g., `CERTIFICATE`
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// returns the headers of the block, in order
+    pub fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+
+    /// returns the value of the first header with the given key
+    pub fn header(&self, key: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// returns the decoded contents of the block
+    pub fn contents(&self) -> &[u8] {
+        &self.contents
+    }
+
+    /// returns the owned decoded contents of the block
+    pub fn into_contents(self) -> Vec<u8> {
+        self.contents
+    }
+
+    /// parses the first PEM block of value
+    ///
+    /// any text before the begin line and after the end line is ignored
+    ///
+    /// # Error
+    /// returns a `PemError` when no block is found, or when the first block is invalid
+    pub fn parse<T: AsRef<str>>(value: T) -> Result<Self, PemError> {
+        let mut lines = value.as_ref().lines();
+
+        parse_block(&mut lines)?.ok_or(PemError::MissingBegin)
+    }
+
+    /// parses every PEM block of value, e.g., a certificate chain
+    ///
+    /// any text in between blocks is ignored,
+    /// a value without any block results in an empty vec
+    ///
+    /// # Error
+    /// returns a `PemError` at the first invalid block
+    pub fn parse_many<T: AsRef<str>>(value: T) -> Result<Vec<Self>, PemError> {
+        let mut lines = value.as_ref().lines();
+        let mut blocks = Vec::new();
+        while let Some(block) = parse_block(&mut lines)? {
+            blocks.push(block);
+        }
+
+        Ok(blocks)
+    }
+
+    /// encodes the block, wrapping its base64 body at 64 columns
+    ///
+    /// every line, the end line included, is terminated by ending
+    pub fn encode(&self, ending: LineEnding) -> String {
+        let eol = ending.as_str();
+
+        let mut encoded = String::new();
+        encoded.push_str(BEGIN);
+        encoded.push_str(&self.label);
+        encoded.push_str(DASHES);
+        encoded.push_str(eol);
+
+        if !self.headers.is_empty() {
+            self.headers.iter().for_each(|(k, v)| {
+                encoded.push_str(k);
+                encoded.push_str(": ");
+                encoded.push_str(v);
+                encoded.push_str(eol);
+            });
+            encoded.push_str(eol);
+        }
+
+        if !self.contents.is_empty() {
+            let body = Encoder::base64()
+                .with_wrap(LINE_WIDTH, ending)
+                .encode_bytes(&self.contents);
+            encoded.push_str(&body);
+            encoded.push_str(eol);
+        }
+
+        encoded.push_str(END);
+        encoded.push_str(&self.label);
+        encoded.push_str(DASHES);
+        encoded.push_str(eol);
+
+        encoded
+    }
+}
+
+// the label of a begin or end line
+fn boundary_label<'a>(line: &'a str, boundary: &str) -> Option<&'a str> {
+    line.trim_end().strip_prefix(boundary)?.strip_suffix(DASHES)
+}
+
+// RFC 7468 section 3, a label is made of printable ascii chars
+// and neither starts nor ends with a '-' or a space
+fn is_valid_label(label: &str) -> bool {
+    label.chars().all(|c| c.is_ascii() && !c.is_ascii_control())
+        && !label.starts_with(['-', ' '])
+        && !label.ends_with(['-', ' '])
+}
+
+// a block whose begin and end lines and headers were parsed, but whose body is still encoded
+pub(crate) struct RawBlock<'a> {
+    pub(crate) label: &'a str,
+    pub(crate) headers: Headers,
+    // the lines in between the headers and the end line
+    pub(crate) body: Vec<&'a str>,
+}
+
+// parses the next block out of lines, returns None when there are no blocks left
+fn parse_block<'a>(lines: &mut impl Iterator<Item = &'a str>) -> Result<Option<Pem>, PemError> {
+    let Some(RawBlock {
+        label,
+        headers,
+        body,
+    }) = read_block(lines)?
+    else {
+        return Ok(None);
+    };
+    let contents = decode_body(&body)?;
+
+    Ok(Some(Pem {
+        label: label.into(),
+        headers,
+        contents,
+    }))
+}
+
+// reads the next block out of lines, up to its end line,
+// returns None when there are no blocks left
+pub(crate) fn read_block<'a>(
+    lines: &mut impl Iterator<Item = &'a str>,
+) -> Result<Option<RawBlock<'a>>, PemError> {
+    let Some(label) = lines.find_map(|l| boundary_label(l, BEGIN)) else {
+        return Ok(None);
+    };
+    if !is_valid_label(label) {
+        return Err(PemError::InvalidLabel(label.into()));
+    }
+
+    let mut body: Vec<&str> = Vec::new();
+    let end = loop {
+        let Some(line) = lines.next() else {
+            return Err(PemError::MissingEnd {
+                label: label.into(),
+            });
+        };
+
+        match boundary_label(line, END) {
+            Some(end) => break end,
+            None => body.push(line),
+        }
+    };
+    if end != label {
+        return Err(PemError::LabelMismatch {
+            begin: label.into(),
+            end: end.into(),
+        });
+    }
+
+    let (headers, body) =
+        split_headers(&body).map_err(|line| PemError::InvalidHeader(line.into()))?;
+
+    Ok(Some(RawBlock {
+        label,
+        headers,
+        body: body.to_vec(),
+    }))
+}
+
+// decodes the base64 lines of a block's body
+pub(crate) fn decode_body(body: &[&str]) -> Result<Vec<u8>, DecodeError> {
+    Ok(Decoder::new()
+        .with_skip_whitespace(true)
+        .apply(body.concat(), BASE64)?
+        .into_bytes())
+}
+
+// RFC 1421 section 4.4, the headers are the lines up to the first blank line,
+// a line starting with whitespace continues the previous header's value
+//
+// a block without headers starts directly with its base64 body
+//
+// returns the first invalid header line as the error
+fn split_headers<'a, 'b>(lines: &'b [&'a str]) -> Result<(Headers, &'b [&'a str]), &'a str> {
+    // base64 lines never contain a ':'
+    if !lines.first().is_some_and(|l| l.contains(':')) {
+        return Ok((Vec::new(), lines));
+    }
+
+    let blank = lines
+        .iter()
+        .position(|l| l.trim().is_empty())
+        .unwrap_or(lines.len());
+
+    let mut headers: Headers = Vec::new();
+    lines[..blank].iter().try_for_each(|line| {
+        if line.starts_with([' ', '\t']) {
+            let Some((_, value)) = headers.last_mut() else {
+                return Err(*line);
+            };
+            value.push(' ');
+            value.push_str(line.trim());
+        } else {
+            let Some((key, value)) = line.split_once(':') else {
+                return Err(*line);
+            };
+            headers.push((key.trim().into(), value.trim().into()));
+        }
+
+        Ok(())
+    })?;
+
+    Ok((headers, &lines[(blank + 1).min(lines.len())..]))
+}
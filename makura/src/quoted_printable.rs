@@ -0,0 +1,232 @@
+#![cfg(all(
+    feature = "alloc",
+    feature = "quoted_printable",
+    feature = "encoding",
+    feature = "decoding"
+))]
+//! quoted-printable, the rfc 2045 content transfer encoding of mostly ascii text
+//!
+//! the printable ascii chars are kept as is, but '=';
+//! every other byte is escaped as '=' followed by its 2 uppercase base16 digits, e.g., `=3D`
+//!
+//! encoded lines are at most 76 chars long,
+//! longer ones are split by soft line breaks, a '=' right before the line ending,
+//! which are removed by the decoder;
+//! spaces and tabs are escaped when they'd end a line, as trailing whitespace tends to get stripped
+//!
+//! ```
+//! use makura::LineEnding;
+//! use makura::quoted_printable::{self, Mode};
+//!
+//! let encoded = quoted_printable::encode("café = coffee\r\n", LineEnding::CrLf);
+//! assert_eq!(encoded, "caf=C3=A9 =3D coffee\r\n");
+//!
+//! let decoded = quoted_printable::decode(encoded, Mode::Strict).unwrap();
+//! assert_eq!(decoded, "café = coffee\r\n".as_bytes());
+//!
+//! // lowercase escapes, stray '=' and trailing whitespace are all over real world mail
+//! let decoded = quoted_printable::decode("caf=c3=a9 = coffee  \r\n", Mode::Lenient).unwrap();
+//! assert_eq!(decoded, "café = coffee\r\n".as_bytes());
+//! ```
+use crate::makura_alloc::{String, Vec};
+
+use crate::{Alphabet, BASE16, LineEnding};
+
+/// the max number of chars in an encoded line, line ending excluded
+pub const MAX_LINE_LEN: usize = 76;
+
+const ESCAPE: u8 = b'=';
+
+/// how `decode` handles values that break the rfc 2045 rules
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mode {
+    /// every rule violation is an error
+    Strict,
+    /// follows the robustness advices of rfc 2045, for sloppy real world mail;
+    ///
+    /// - lowercase escapes are decoded
+    /// - a '=' that doesn't start a valid escape is kept as is
+    /// - trailing whitespace is removed
+    /// - long lines, control chars and non ascii bytes are kept as is
+    Lenient,
+}
+
+/// errors that can occur while decoding a quoted-printable value in `Mode::Strict`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QpError {
+    /// a byte of the given line, 1 based, is neither printable ascii, a space nor a tab
+    InvalidChar { char: char, line: usize },
+    /// a '=' of the given line, 1 based, isn't followed by 2 uppercase base16 digits
+    InvalidEscape { line: usize },
+    /// the given line, 1 based, is longer than `MAX_LINE_LEN`
+    LineTooLong { line: usize, len: usize },
+    /// the given line, 1 based, ends with a space or a tab
+    TrailingWhitespace { line: usize },
+}
+
+impl core::fmt::Display for QpError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidChar { char, line } => {
+                write!(f, "invalid char {:?} on line {}", char, line)
+            }
+            Self::InvalidEscape { line } => write!(f, "invalid escape on line {}", line),
+            Self::LineTooLong { line, len } => write!(
+                f,
+                "line {} is {} chars long, max is {}",
+                line, len, MAX_LINE_LEN
+            ),
+            Self::TrailingWhitespace { line } => {
+                write!(f, "line {} ends with whitespace", line)
+            }
+        }
+    }
+}
+
+impl core::error::Error for QpError {}
+
+/// encodes data into quoted-printable
+///
+/// the line breaks of data that match ending are kept as hard line breaks,
+/// any other CR or LF is escaped, so decoding the output gives back data as is
+pub fn encode<T: AsRef<[u8]>>(data: T, ending: LineEnding) -> String {
+    let data = data.as_ref();
+    let eol = ending.as_str();
+    let hex = Alphabet::from_base(&BASE16);
+    let mut encoded = String::with_capacity(data.len() + data.len() / 2);
+
+    // the number of chars of the current encoded line
+    let mut len = 0;
+    let mut idx = 0;
+    while idx < data.len() {
+        if data[idx..].starts_with(eol.as_bytes()) {
+            encoded.push_str(eol);
+            len = 0;
+            idx += eol.len();
+            continue;
+        }
+
+        let byte = data[idx];
+        idx += 1;
+        let last = idx == data.len() || data[idx..].starts_with(eol.as_bytes());
+        let literal = is_literal(byte) || (is_whitespace(byte) && !last);
+        let width = if literal { 1 } else { 3 };
+
+        // NOTE the soft line break's '=' takes a column too, unless the line ends right here
+        if len + width > MAX_LINE_LEN - usize::from(!last) {
+            encoded.push(ESCAPE as char);
+            encoded.push_str(eol);
+            len = 0;
+        }
+
+        if literal {
+            encoded.push(byte as char);
+        } else {
+            encoded.push(ESCAPE as char);
+            encoded.push(hex.char_at(byte >> 4));
+            encoded.push(hex.char_at(byte & 0x0f));
+        }
+        len += width;
+    }
+
+    encoded
+}
+
+/// decodes a quoted-printable value, removing its soft line breaks
+///
+/// the hard line breaks, either LF or CRLF, are kept as they are in value
+///
+/// # Error
+/// in `Mode::Strict`, returns a `QpError` for the first line that breaks the rfc 2045 rules;
+/// `Mode::Lenient` never fails
+pub fn decode<T: AsRef<[u8]>>(value: T, mode: Mode) -> Result<Vec<u8>, QpError> {
+    let strict = mode == Mode::Strict;
+    let hex = Alphabet::from_base(&BASE16);
+    let mut decoded = Vec::with_capacity(value.as_ref().len());
+
+    for (line, idx) in value.as_ref().split_inclusive(|chr| *chr == b'\n').zip(1..) {
+        let (mut line, eol) = split_eol(line);
+
+        if strict && line.len() > MAX_LINE_LEN {
+            return Err(QpError::LineTooLong {
+                line: idx,
+                len: line.len(),
+            });
+        }
+        if let [.., last] = line
+            && is_whitespace(*last)
+        {
+            if strict {
+                return Err(QpError::TrailingWhitespace { line: idx });
+            }
+            line = line.trim_ascii_end();
+        }
+
+        let (line, soft) = match line.strip_suffix(&[ESCAPE]) {
+            Some(line) => (line, true),
+            None => (line, false),
+        };
+
+        let mut chars = line.iter().enumerate();
+        while let Some((pos, chr)) = chars.next() {
+            match *chr {
+                ESCAPE => {
+                    let digits = line.get(pos + 1..pos + 3).and_then(|digits| {
+                        let (high, low) = if strict {
+                            (digits[0], digits[1])
+                        } else {
+                            (
+                                digits[0].to_ascii_uppercase(),
+                                digits[1].to_ascii_uppercase(),
+                            )
+                        };
+                        Some(hex.idx_of(high)? << 4 | hex.idx_of(low)?)
+                    });
+
+                    match digits {
+                        Some(byte) => {
+                            decoded.push(byte);
+                            chars.nth(1);
+                        }
+                        None if strict => return Err(QpError::InvalidEscape { line: idx }),
+                        // NOTE rfc 2045 advises to keep the '=' and what follows as is
+                        None => decoded.push(ESCAPE),
+                    }
+                }
+                chr if strict && !is_literal(chr) && !is_whitespace(chr) => {
+                    return Err(QpError::InvalidChar {
+                        char: chr as char,
+                        line: idx,
+                    });
+                }
+                chr => decoded.push(chr),
+            }
+        }
+
+        if !soft {
+            decoded.extend_from_slice(eol);
+        }
+    }
+
+    Ok(decoded)
+}
+
+// the printable ascii chars that are never escaped
+fn is_literal(chr: u8) -> bool {
+    matches!(chr, b'!'..=b'~') && chr != ESCAPE
+}
+
+fn is_whitespace(chr: u8) -> bool {
+    matches!(chr, b' ' | b'\t')
+}
+
+// splits a line from its line ending, the last line may have none
+fn split_eol(line: &[u8]) -> (&[u8], &[u8]) {
+    let len = match line {
+        [.., b'\r', b'\n'] => 2,
+        [.., b'\n'] => 1,
+        _ => 0,
+    };
+
+    line.split_at(line.len() - len)
+}
@@ -0,0 +1,155 @@
+#![cfg(all(
+    feature = "alloc",
+    feature = "base58check",
+    feature = "encoding",
+    feature = "decoding"
+))]
+// a minimal SHA-256 (FIPS 180-4), only what base58check's double hash checksum needs
+//
+// NOTE this is not meant to be a general purpose or constant time hashing implementation,
+// the checksum guards against typos, not against an attacker
+
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+const H: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+// hashes data into its 32 bytes digest
+pub(crate) fn sha256(data: &[u8]) -> [u8; 32] {
+    let mut state = H;
+
+    let mut blocks = data.chunks_exact(64);
+    blocks
+        .by_ref()
+        .for_each(|block| compress(&mut state, block));
+
+    // the last partial block, the 0x80 end marker and the 64 bits len,
+    // which take a second block when they don't fit in the first
+    let rest = blocks.remainder();
+    let mut last = [0u8; 128];
+    last[..rest.len()].copy_from_slice(rest);
+    last[rest.len()] = 0x80;
+    let end = if rest.len() < 56 { 64 } else { 128 };
+    last[end - 8..end].copy_from_slice(&((data.len() as u64) * 8).to_be_bytes());
+    last[..end]
+        .chunks_exact(64)
+        .for_each(|block| compress(&mut state, block));
+
+    let mut digest = [0u8; 32];
+    digest
+        .chunks_exact_mut(4)
+        .zip(state)
+        .for_each(|(d, s)| d.copy_from_slice(&s.to_be_bytes()));
+
+    digest
+}
+
+// processes a single 64 bytes block into state
+fn compress(state: &mut [u32; 8], block: &[u8]) {
+    let mut w = [0u32; 64];
+    block
+        .chunks_exact(4)
+        .zip(w.iter_mut())
+        .for_each(|(b, w)| *w = u32::from_be_bytes([b[0], b[1], b[2], b[3]]));
+    (16..64).for_each(|i| {
+        let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+        let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+        w[i] = w[i - 16]
+            .wrapping_add(s0)
+            .wrapping_add(w[i - 7])
+            .wrapping_add(s1);
+    });
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+    (0..64).for_each(|i| {
+        let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+        let ch = (e & f) ^ (!e & g);
+        let t1 = h
+            .wrapping_add(s1)
+            .wrapping_add(ch)
+            .wrapping_add(K[i])
+            .wrapping_add(w[i]);
+        let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let t2 = s0.wrapping_add(maj);
+
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(t1);
+        d = c;
+        c = b;
+        b = a;
+        a = t1.wrapping_add(t2);
+    });
+
+    state
+        .iter_mut()
+        .zip([a, b, c, d, e, f, g, h])
+        .for_each(|(s, v)| *s = s.wrapping_add(v));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sha256;
+    use crate::{BASE16, Encoder};
+
+    fn hex(digest: [u8; 32]) -> crate::makura_alloc::String {
+        Encoder::from(BASE16).encode_bytes(digest).to_lowercase()
+    }
+
+    #[test]
+    fn test_vectors() {
+        [
+            (
+                &b""[..],
+                "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+            ),
+            (
+                b"abc",
+                "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            ),
+            (
+                b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+                "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1",
+            ),
+        ]
+        .into_iter()
+        .for_each(|(input, output)| assert_eq!(hex(sha256(input)), output));
+    }
+
+    #[test]
+    fn test_block_boundaries() {
+        // the len fits in the last block up to 55 bytes, past that it takes a block of its own
+        [
+            (
+                55,
+                "9f4390f8d30c2dd92ec9f095b65e2b9ae9b0a925a5258e241c9f1e910f734318",
+            ),
+            (
+                56,
+                "b35439a4ac6f0948b6d6f9e3c6af0f5f590ce20f1bde7090ef7970686ec6738a",
+            ),
+            (
+                64,
+                "ffe054fe7ae0cb6dc65c3af9b61d5209f439851db43d0ba5997337df154668eb",
+            ),
+            (
+                1000,
+                "41edece42d63e8d9bf515a9ba6932e1c20cbc9f5a5d134645adb5db1b9737ea3",
+            ),
+        ]
+        .into_iter()
+        .for_each(|(len, output)| assert_eq!(hex(sha256(&[b'a'; 1000][..len])), output));
+    }
+}
@@ -0,0 +1,256 @@
+#![cfg(all(
+    feature = "alloc",
+    feature = "uuencode",
+    feature = "encoding",
+    feature = "decoding"
+))]
+//! uuencode and xxencode, files framed by a `begin <mode> <name>` line and an `end` line
+//!
+//! every line holds up to 45 bytes, prefixed with their count as a char of the alphabet,
+//! then encoded like base64 in whole quanta of 4 chars;
+//! a line of 0 bytes closes the data, right before the `end` line
+//!
+//! uuencode's alphabet is the ascii chars from ' ' to '_',
+//! most encoders write a '`' instead of the spaces, since trailing spaces tend to get stripped;
+//! xxencode only differs by its alphabet, `+-`, the digits then the letters
+//!
+//! ```
+//! use makura::LineEnding;
+//! use makura::uuencode::{Dialect, UuFile};
+//!
+//! let file = UuFile::new("cat.txt", 0o644, b"Cat".to_vec());
+//! let encoded = file.encode(Dialect::UuBackticks, LineEnding::Lf);
+//! assert_eq!(encoded, "begin 644 cat.txt\n#0V%T\n`\nend\n");
+//!
+//! let parsed = UuFile::parse(&encoded, Dialect::UuBackticks).unwrap();
+//! assert_eq!(parsed.name(), "cat.txt");
+//! assert_eq!(parsed.mode(), 0o644);
+//! assert_eq!(parsed.data(), b"Cat");
+//! ```
+use crate::makura_alloc::{String, Vec};
+use core::fmt::Write;
+
+use crate::{Alphabet, BASE64, DecodeError, Decoder, Encoder, LineEnding};
+
+/// the max number of bytes in a single line
+pub const LINE_BYTES: usize = 45;
+
+const BEGIN: &str = "begin ";
+const END: &str = "end";
+
+// NOTE none of these have padding, the pads are chars out of the alphabets that are never used
+static UU: Alphabet = Alphabet::from_table(
+    b" !\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_",
+    b'~',
+);
+static UU_BACKTICKS: Alphabet = Alphabet::from_table(
+    b"`!\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_",
+    b'~',
+);
+static XX: Alphabet = Alphabet::from_table(
+    b"+-0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz",
+    b'=',
+);
+
+/// the alphabet of an encoded file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dialect {
+    /// uuencode, 0 values are encoded as spaces
+    Uu,
+    /// uuencode, 0 values are encoded as '`', e.g., GNU sharutils' uuencode
+    ///
+    /// both uuencode dialects decode both spaces and '`'
+    UuBackticks,
+    /// xxencode
+    Xx,
+}
+
+impl Dialect {
+    // the alphabet the lines are encoded with
+    fn alphabet(self) -> &'static Alphabet {
+        match self {
+            Self::Uu => &UU,
+            Self::UuBackticks => &UU_BACKTICKS,
+            Self::Xx => &XX,
+        }
+    }
+}
+
+/// errors that can occur while parsing an encoded file
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum UuError {
+    /// no `begin <mode> <name>` line was found
+    MissingBegin,
+    /// the begin line has no name, or its mode isn't an octal number
+    InvalidBegin(String),
+    /// the data has no `end` line after its 0 bytes line
+    MissingEnd,
+    /// a char of the given line, 1 based, is out of the alphabet
+    InvalidChar { char: char, line: usize },
+    /// the given line, 1 based, has less chars than its len prefix calls for
+    InvalidLen { line: usize },
+}
+
+impl core::fmt::Display for UuError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::MissingBegin => write!(f, "no begin line was found"),
+            Self::InvalidBegin(line) => write!(f, "invalid begin line {:?}", line),
+            Self::MissingEnd => write!(f, "the data has no end line"),
+            Self::InvalidChar { char, line } => {
+                write!(f, "invalid char {:?} on line {}", char, line)
+            }
+            Self::InvalidLen { line } => write!(f, "line {} is shorter than its len", line),
+        }
+    }
+}
+
+impl core::error::Error for UuError {}
+
+/// an encoded file; its name, unix permissions mode and data
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UuFile {
+    name: String,
+    mode: u32,
+    data: Vec<u8>,
+}
+
+impl UuFile {
+    /// creates a new file, mode is usually written in octal, e.g., `0o644`
+    pub fn new<T: Into<String>>(name: T, mode: u32, data: Vec<u8>) -> Self {
+        Self {
+            name: name.into(),
+            mode,
+            data,
+        }
+    }
+
+    /// returns the name of the file
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// returns the unix permissions mode of the file
+    pub fn mode(&self) -> u32 {
+        self.mode
+    }
+
+    /// returns the data of the file
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// returns the owned data of the file
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+
+    /// parses the first encoded file of value
+    ///
+    /// any text before the begin line and after the end line is ignored,
+    /// so are the chars past the len prefix of a line, e.g., legacy line checksums
+    ///
+    /// # Error
+    /// returns a `UuError` when no file is found, or when the first file is invalid
+    pub fn parse<T: AsRef<str>>(value: T, dialect: Dialect) -> Result<Self, UuError> {
+        let mut lines = value.as_ref().lines().zip(1..);
+        let (mode, name) = lines
+            .find_map(|(line, _)| line.strip_prefix(BEGIN))
+            .ok_or(UuError::MissingBegin)
+            .and_then(|begin| {
+                begin
+                    .split_once(' ')
+                    .filter(|(_, name)| !name.is_empty())
+                    .and_then(|(mode, name)| Some((u32::from_str_radix(mode, 8).ok()?, name)))
+                    .ok_or_else(|| UuError::InvalidBegin([BEGIN, begin].concat()))
+            })?;
+
+        let mut data = Vec::new();
+        loop {
+            let (line, idx) = lines.next().ok_or(UuError::MissingEnd)?;
+            // NOTE the 0 bytes line of the spaces dialect is a lone space, which may have been stripped
+            if line.is_empty() || decode_line(line, idx, dialect, &mut data)? == 0 {
+                break;
+            }
+        }
+        if lines.next().is_none_or(|(line, _)| line.trim_end() != END) {
+            return Err(UuError::MissingEnd);
+        }
+
+        Ok(Self {
+            name: name.into(),
+            mode,
+            data,
+        })
+    }
+
+    /// encodes the file into lines of up to `LINE_BYTES` bytes, framed by its begin and end lines
+    ///
+    /// every line, the end line included, is terminated by ending
+    pub fn encode(&self, dialect: Dialect, ending: LineEnding) -> String {
+        let eol = ending.as_str();
+        let alphabet = dialect.alphabet();
+        let enc = Encoder::custom(*alphabet);
+
+        let mut encoded = String::new();
+        // NOTE writing into a String never fails
+        let _ = write!(encoded, "{}{:o} {}{}", BEGIN, self.mode, self.name, eol);
+
+        self.data.chunks(LINE_BYTES).for_each(|line| {
+            encoded.push(alphabet.char_at(line.len() as u8));
+
+            // NOTE the lines are encoded in whole quanta only
+            let mut quanta = [0u8; LINE_BYTES];
+            quanta[..line.len()].copy_from_slice(line);
+            encoded.push_str(&enc.encode_bytes(&quanta[..line.len().div_ceil(3) * 3]));
+            encoded.push_str(eol);
+        });
+
+        encoded.push(alphabet.char_at(0));
+        encoded.push_str(eol);
+        encoded.push_str(END);
+        encoded.push_str(eol);
+
+        encoded
+    }
+}
+
+// decodes the idx-th line of a file, appending its bytes to data,
+// returns the number of bytes of the line
+fn decode_line(
+    line: &str,
+    idx: usize,
+    dialect: Dialect,
+    data: &mut Vec<u8>,
+) -> Result<usize, UuError> {
+    // NOTE both uuencode dialects are decoded with backticks
+    let (line, alphabet) = match dialect {
+        Dialect::Uu | Dialect::UuBackticks => (line.replace(' ', "`"), &UU_BACKTICKS),
+        Dialect::Xx => (line.into(), &XX),
+    };
+    let line = line.as_bytes();
+
+    let len = alphabet.idx_of(line[0]).ok_or(UuError::InvalidChar {
+        char: line[0] as char,
+        line: idx,
+    })? as usize;
+    let chars = &line[1..];
+    let needed = len.div_ceil(3) * 4;
+    if chars.len() < needed {
+        return Err(UuError::InvalidLen { line: idx });
+    }
+
+    let start = data.len();
+    data.resize(start + needed / 4 * 3, 0);
+    Decoder::new()
+        .with_alphabet(*alphabet)
+        .decode_to_slice(&chars[..needed], BASE64, &mut data[start..])
+        .map_err(|e| match e {
+            DecodeError::InvalidChar { char, .. } => UuError::InvalidChar { char, line: idx },
+            _ => UuError::InvalidLen { line: idx },
+        })?;
+    // the padding bytes of the last quantum
+    data.truncate(start + len);
+
+    Ok(len)
+}
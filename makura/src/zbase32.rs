@@ -0,0 +1,59 @@
+#![cfg(all(
+    feature = "alloc",
+    feature = "zbase32",
+    feature = "encoding",
+    feature = "decoding"
+))]
+//! z-base-32 values of any number of bits
+//!
+//! unlike the other bases, z-base-32 can encode a value that isn't made of whole bytes,
+//! into as many chars as its bits need, e.g., a single bit is a single char;
+//! the number of bits is not part of the encoded value, both sides have to agree on it
+//!
+//! whole bytes values can also go through `Encoder::zbase32` and `Decoder` with `ZBASE32`
+//!
+//! ```
+//! use makura::zbase32;
+//!
+//! // the first 20 bits of 0x8b8880
+//! assert_eq!(zbase32::encode_bits([0x8b, 0x88, 0x80], 20).unwrap(), "tqre");
+//! assert_eq!(zbase32::decode_bits("tqre", 20).unwrap(), [0x8b, 0x88, 0x80]);
+//! ```
+use crate::makura_alloc::{String, Vec, vec};
+
+use crate::decoders::zbase32_decode_bits;
+use crate::encoders::zbase32_encode_bits;
+use crate::{DecodeError, EncodeError, ZBASE32};
+
+/// encodes the first bits bits of value into bits.div_ceil(5) chars
+///
+/// # Error
+/// * `EncodeError::InvalidLen` when value holds less than bits bits
+pub fn encode_bits<T: AsRef<[u8]>>(value: T, bits: usize) -> Result<String, EncodeError> {
+    let value = value.as_ref();
+    if bits > value.len() * 8 {
+        return Err(EncodeError::InvalidLen {
+            len: value.len(),
+            base: ZBASE32,
+        });
+    }
+
+    let mut encoded = vec![0; bits.div_ceil(5)];
+    zbase32_encode_bits(value, bits, &mut encoded, ZBASE32.alphabet());
+
+    // NOTE every alphabet is made of ascii chars
+    Ok(String::from_utf8(encoded).expect("encoded value is ascii"))
+}
+
+/// decodes a value of bits bits into bits.div_ceil(8) bytes,
+/// the bits of the last byte past bits are 0s
+///
+/// # Error
+/// * `DecodeError::InvalidLen` when value doesn't have bits.div_ceil(5) chars
+/// * `DecodeError::InvalidChar` when value has a char out of the z-base-32 alphabet
+pub fn decode_bits<T: AsRef<[u8]>>(value: T, bits: usize) -> Result<Vec<u8>, DecodeError> {
+    let mut decoded = vec![0; bits.div_ceil(8)];
+    zbase32_decode_bits(value.as_ref(), bits, &mut decoded, ZBASE32.alphabet())?;
+
+    Ok(decoded)
+}
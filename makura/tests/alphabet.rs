@@ -0,0 +1,192 @@
+mod encoder {
+    use makura::{Alphabet, BASE16, BASE32, BASE64, Encoder, Padding};
+
+    const BCRYPT: &str = "./ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+    const CROCKFORD: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+    const HEX_LOWER: &str = "0123456789abcdef";
+
+    #[test]
+    fn test0() {
+        let input = "foobar";
+        let output = "Xk7tWkDw";
+        let enc = Encoder::custom(Alphabet::new(BCRYPT, '=').unwrap());
+
+        assert_eq!(*enc.base(), BASE64);
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test1() {
+        let input = "f";
+        let output = "CR======";
+        let enc = Encoder::custom(Alphabet::new(CROCKFORD, '=').unwrap());
+
+        assert_eq!(*enc.base(), BASE32);
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test2() {
+        let input = [0xdeu8, 0xad, 0xbe, 0xef];
+        let output = "deadbeef";
+        let enc = Encoder::custom(Alphabet::new(HEX_LOWER, '=').unwrap());
+
+        assert_eq!(*enc.base(), BASE16);
+        assert_eq!(enc.encode_bytes(input), output);
+    }
+
+    #[test]
+    fn test3() {
+        let input = "fo";
+        let output = "Xk6*";
+        let enc = Encoder::custom(Alphabet::new(BCRYPT, '*').unwrap());
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test4() {
+        let input = "fo";
+        let output = "Xk6";
+        let enc =
+            Encoder::custom(Alphabet::new(BCRYPT, '*').unwrap()).with_padding(Padding::Omitted);
+
+        assert_eq!(enc.encode(input), output);
+    }
+}
+
+mod decoder {
+    use makura::{Alphabet, BASE16, BASE32, BASE32HEX, BASE64, BASE64URL, Padding};
+    use makura::{DecodeError, Decoder};
+
+    const BCRYPT: &str = "./ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+    const CROCKFORD: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+    const HEX_LOWER: &str = "0123456789abcdef";
+
+    #[test]
+    fn test0() {
+        let input = "foobar";
+        let output = "Xk7tWkDw";
+        let dec = Decoder::new().with_alphabet(Alphabet::new(BCRYPT, '=').unwrap());
+
+        assert_eq!(
+            dec.apply(output, BASE64).unwrap().into_utf8().unwrap(),
+            input
+        );
+    }
+
+    #[test]
+    fn test1() {
+        let input = "f";
+        let output = "CR======";
+        let dec = Decoder::new().with_alphabet(Alphabet::new(CROCKFORD, '=').unwrap());
+
+        assert_eq!(
+            dec.apply(output, BASE32).unwrap().into_utf8().unwrap(),
+            input
+        );
+    }
+
+    #[test]
+    fn test2() {
+        let input = [0xdeu8, 0xad, 0xbe, 0xef];
+        let output = "deadbeef";
+        let dec = Decoder::new().with_alphabet(Alphabet::new(HEX_LOWER, '=').unwrap());
+
+        assert_eq!(dec.apply(output, BASE16).unwrap().into_bytes(), input);
+    }
+
+    #[test]
+    fn test3() {
+        let input = "fo";
+        let output = "Xk6*";
+        let dec = Decoder::new().with_alphabet(Alphabet::new(BCRYPT, '*').unwrap());
+
+        assert_eq!(
+            dec.apply(output, BASE64URL).unwrap().into_utf8().unwrap(),
+            input
+        );
+    }
+
+    #[test]
+    fn test4() {
+        let input = "fo";
+        let output = "Xk6";
+        let dec = Decoder::new()
+            .with_alphabet(Alphabet::new(BCRYPT, '*').unwrap())
+            .with_padding(Padding::Omitted);
+
+        assert_eq!(
+            dec.apply(output, BASE64).unwrap().into_utf8().unwrap(),
+            input
+        );
+    }
+
+    #[test]
+    fn test5() {
+        // 'U' is not part of the crockford alphabet
+        let output = "CU======";
+        let dec = Decoder::new().with_alphabet(Alphabet::new(CROCKFORD, '=').unwrap());
+
+        assert_eq!(
+            dec.apply(output, BASE32HEX).unwrap_err(),
+            DecodeError::InvalidChar {
+                char: 'U',
+                base: BASE32HEX
+            }
+        );
+    }
+
+    #[test]
+    fn test6() {
+        let output = "Xk7tWkDw";
+        let dec = Decoder::new().with_alphabet(Alphabet::new(BCRYPT, '=').unwrap());
+
+        assert_eq!(
+            dec.apply(output, BASE32).unwrap_err(),
+            DecodeError::AlphabetMismatch {
+                radix: 64,
+                base: BASE32
+            }
+        );
+    }
+
+    #[test]
+    fn test7() {
+        // the standard '=' is just another invalid char when the alphabet pads with '*'
+        let output = "Xk6=";
+        let dec = Decoder::new().with_alphabet(Alphabet::new(BCRYPT, '*').unwrap());
+
+        assert_eq!(
+            dec.apply(output, BASE64).unwrap_err(),
+            DecodeError::InvalidChar {
+                char: '=',
+                base: BASE64
+            }
+        );
+    }
+}
+
+mod round_trip {
+    use makura::{Alphabet, Decoder, Encoder};
+
+    #[test]
+    fn test0() {
+        let input = [0u8, 0xff, 0x10, 0x80, 0x7f, 0x00];
+        [
+            "./ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789",
+            "0123456789ABCDEFGHJKMNPQRSTVWXYZ",
+            "ybndrfg8ejkmcpqxot1uwisza345h769",
+            "0123456789abcdef",
+        ]
+        .into_iter()
+        .for_each(|chars| {
+            let alphabet = Alphabet::new(chars, '~').unwrap();
+            let enc = Encoder::custom(alphabet);
+            let output = enc.encode_bytes(input);
+            let dec = Decoder::new().with_alphabet(alphabet);
+
+            assert_eq!(dec.apply(output, *enc.base()).unwrap().into_bytes(), input);
+        });
+    }
+}
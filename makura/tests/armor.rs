@@ -0,0 +1,188 @@
+// 100 bytes of (i * 37 + 11) % 256
+fn contents() -> Vec<u8> {
+    (0..100u32).map(|i| ((i * 37 + 11) % 256) as u8).collect()
+}
+
+const BLOCK: &str = "-----BEGIN PGP MESSAGE-----
+Version: makura
+
+CzBVep/E6Q4zWH2ix+wRNluApcrvFDleg6jN8hc8YYar0PUaP2SJrtP4HUJnjLHW
++yBFao+02f4jSG2St9wBJktwlbrfBClOc5i94gcsUXabwOUKL1R5nsPoDTJXfKHG
+6xA1Wg==
+=z6pb
+-----END PGP MESSAGE-----
+";
+
+mod crc {
+    use makura::armor::crc24;
+
+    #[test]
+    fn test0() {
+        assert_eq!(crc24(b""), 0xB704CE);
+    }
+
+    #[test]
+    fn test1() {
+        // the CRC-24/OPENPGP check value
+        assert_eq!(crc24(b"123456789"), 0x21CF02);
+    }
+}
+
+mod encoder {
+    use makura::LineEnding;
+    use makura::armor::Armor;
+
+    use super::{BLOCK, contents};
+
+    #[test]
+    fn test0() {
+        let armor = Armor::new("PGP MESSAGE", contents()).with_header("Version", "makura");
+
+        assert_eq!(armor.encode(LineEnding::Lf), BLOCK);
+    }
+
+    #[test]
+    fn test1() {
+        let armor = Armor::new("PGP MESSAGE", contents()).with_header("Version", "makura");
+
+        assert_eq!(armor.encode(LineEnding::CrLf), BLOCK.replace('\n', "\r\n"));
+    }
+
+    #[test]
+    fn test2() {
+        let armor = Armor::new("PGP SIGNATURE", Vec::new());
+
+        assert_eq!(
+            armor.encode(LineEnding::Lf),
+            "-----BEGIN PGP SIGNATURE-----\n\n=twTO\n-----END PGP SIGNATURE-----\n"
+        );
+    }
+}
+
+mod decoder {
+    use makura::armor::Armor;
+    use makura::pem::PemError;
+    use makura::{BASE64, DecodeError};
+
+    use super::{BLOCK, contents};
+
+    #[test]
+    fn test0() {
+        let armor = Armor::parse(BLOCK).unwrap();
+
+        assert_eq!(armor.label(), "PGP MESSAGE");
+        assert_eq!(armor.header("Version"), Some("makura"));
+        assert_eq!(armor.contents(), contents());
+    }
+
+    #[test]
+    fn test1() {
+        // the checksum line is optional
+        let input = BLOCK.replace("=z6pb\n", "");
+
+        assert_eq!(Armor::parse(input).unwrap().into_contents(), contents());
+    }
+
+    #[test]
+    fn test2() {
+        // no headers, crlf line endings and text around the block
+        let input = "gpg output\r\n-----BEGIN PGP MESSAGE-----\r\n\r\nZm9vYmFy\r\n=czTe\r\n-----END PGP MESSAGE-----\r\n";
+        let armor = Armor::parse(input).unwrap();
+
+        assert!(armor.headers().is_empty());
+        assert_eq!(armor.contents(), b"foobar");
+    }
+
+    #[test]
+    fn test3() {
+        let input = BLOCK.replace("=z6pb", "=czTe");
+
+        assert_eq!(
+            Armor::parse(input),
+            Err(PemError::Decode(DecodeError::ChecksumMismatch {
+                expected: 0x7334DE,
+                found: 0xCFAA5B
+            }))
+        );
+    }
+
+    #[test]
+    fn test4() {
+        // a corrupted body
+        let input = BLOCK.replace("CzBVep", "CzBVeq");
+
+        assert!(matches!(
+            Armor::parse(input),
+            Err(PemError::Decode(DecodeError::ChecksumMismatch {
+                expected: 0xCFAA5B,
+                ..
+            }))
+        ));
+    }
+
+    #[test]
+    fn test5() {
+        let input = BLOCK.replace("=z6pb", "=z6*b");
+
+        assert_eq!(
+            Armor::parse(input),
+            Err(PemError::Decode(DecodeError::InvalidChar {
+                char: '*',
+                base: BASE64
+            }))
+        );
+    }
+
+    #[test]
+    fn test6() {
+        assert_eq!(Armor::parse("Zm9vYmFy"), Err(PemError::MissingBegin));
+    }
+
+    #[test]
+    fn test7() {
+        let input = "-----BEGIN PGP MESSAGE-----\n\nZm9vYmFy\n-----END PGP SIGNATURE-----\n";
+
+        assert_eq!(
+            Armor::parse(input),
+            Err(PemError::LabelMismatch {
+                begin: "PGP MESSAGE".into(),
+                end: "PGP SIGNATURE".into()
+            })
+        );
+    }
+
+    #[test]
+    fn test8() {
+        let input = "-----BEGIN PGP MESSAGE-----\n\nZm9vYmFy\n=czTe\n";
+
+        assert_eq!(
+            Armor::parse(input),
+            Err(PemError::MissingEnd {
+                label: "PGP MESSAGE".into()
+            })
+        );
+    }
+
+    #[test]
+    fn test9() {
+        // a checksum line made only of pad chars
+        let input = BLOCK.replace("=z6pb", "==");
+
+        assert!(Armor::parse(input).is_err());
+    }
+}
+
+mod properties {
+    use makura::LineEnding;
+    use makura::armor::Armor;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn round_trip(contents in proptest::collection::vec(any::<u8>(), 0..512)) {
+            let armor = Armor::new("PGP MESSAGE", contents).with_header("Comment", "makura");
+
+            prop_assert_eq!(Armor::parse(armor.encode(LineEnding::Lf)).unwrap(), armor);
+        }
+    }
+}
@@ -0,0 +1,174 @@
+mod encoder {
+    use makura::Encoder;
+
+    #[test]
+    fn test0() {
+        let input = "Man is distinguished";
+        let output = "9jqo^BlbD-BleB1DJ+*+F(f,q";
+        let enc = Encoder::ascii85();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test1() {
+        let input = "hello";
+        let output = "<~BOu!rDZ~>";
+        let enc = Encoder::ascii85().with_delimiters(true);
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test2() {
+        // a whole zero quantum is a single 'z'
+        let input = [0, 0, 0, 0, b'h', b'i'];
+        let output = "zBP@";
+        let enc = Encoder::ascii85();
+
+        assert_eq!(enc.encode_bytes(input), output);
+    }
+
+    #[test]
+    fn test3() {
+        // a partial zero quantum is not abbreviated
+        let input = [0, 0];
+        let output = "!!!";
+        let enc = Encoder::ascii85();
+
+        assert_eq!(enc.encode_bytes(input), output);
+    }
+
+    #[test]
+    fn test4() {
+        let input = "    abc";
+        let enc = Encoder::ascii85();
+
+        assert_eq!(enc.encode(input), "+<VdL@:E^");
+        assert_eq!(enc.with_fold_spaces(true).encode(input), "y@:E^");
+    }
+
+    #[test]
+    fn test5() {
+        let enc = Encoder::ascii85().with_delimiters(true);
+
+        assert_eq!(enc.encode(""), "<~~>");
+        assert_eq!(enc.encoded_len(0), 4);
+    }
+}
+
+mod decoder {
+    use makura::{BASE85, DecodeError, Decoder};
+
+    #[test]
+    fn test0() {
+        let input = "9jqo^BlbD-BleB1DJ+*+F(f,q";
+        let output = "Man is distinguished";
+
+        assert_eq!(
+            Decoder::decode(input, BASE85).unwrap().into_utf8().unwrap(),
+            output
+        );
+    }
+
+    #[test]
+    fn test1() {
+        // the delimiters are optional
+        let input = "<~BOu!rDZ~>";
+
+        assert_eq!(Decoder::decode(input, BASE85).unwrap().as_bytes(), b"hello");
+    }
+
+    #[test]
+    fn test2() {
+        let input = "<~zBP@~>";
+
+        assert_eq!(
+            Decoder::decode(input, BASE85).unwrap().into_bytes(),
+            [0, 0, 0, 0, b'h', b'i']
+        );
+    }
+
+    #[test]
+    fn test3() {
+        let input = "y@:E^";
+
+        assert_eq!(
+            Decoder::decode(input, BASE85).unwrap().as_bytes(),
+            b"    abc"
+        );
+    }
+
+    #[test]
+    fn test4() {
+        let input = "<~9jqo^BlbD-\nBleB1DJ+*+\nF(f,q~>\n";
+        let dec = Decoder::new().with_skip_whitespace(true);
+
+        assert_eq!(
+            dec.apply(input, BASE85).unwrap().as_bytes(),
+            b"Man is distinguished"
+        );
+    }
+
+    #[test]
+    fn test5() {
+        // a single char can't hold a byte
+        let input = "BOu!rD";
+
+        assert!(matches!(
+            Decoder::decode(input, BASE85).unwrap_err(),
+            DecodeError::InvalidLen { base: BASE85, .. }
+        ));
+    }
+
+    #[test]
+    fn test6() {
+        // bigger than u32::MAX
+        let input = "s8W-\"";
+
+        assert_eq!(
+            Decoder::decode(input, BASE85).unwrap_err(),
+            DecodeError::Overflow { base: BASE85 }
+        );
+        assert_eq!(
+            Decoder::decode("s8W-!", BASE85).unwrap().into_bytes(),
+            [0xff; 4]
+        );
+    }
+
+    #[test]
+    fn test7() {
+        // 'z' only stands for a whole quantum
+        let input = "BOzu!";
+
+        assert_eq!(
+            Decoder::decode(input, BASE85).unwrap_err(),
+            DecodeError::InvalidChar {
+                char: 'z',
+                base: BASE85
+            }
+        );
+    }
+}
+
+mod properties {
+    use makura::{BASE85, Decoder, Encoder};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn round_trip(
+            input in proptest::collection::vec(any::<u8>(), 0..128),
+            delimiters: bool,
+            fold_spaces: bool,
+        ) {
+            let enc = Encoder::ascii85()
+                .with_delimiters(delimiters)
+                .with_fold_spaces(fold_spaces);
+            let encoded = enc.encode_bytes(&input);
+
+            prop_assert!(encoded.len() <= enc.encoded_len(input.len()));
+            prop_assert_eq!(Decoder::decode(&encoded, BASE85).unwrap().into_bytes(), input);
+        }
+    }
+}
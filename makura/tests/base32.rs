@@ -1,5 +1,5 @@
 mod encoder {
-    use makura::Encoder;
+    use makura::{BASE32CROCKFORD, Encoder};
 
     #[test]
     fn test0() {
@@ -63,10 +63,20 @@ mod encoder {
 
         assert_eq!(enc.encode(input), output);
     }
+
+    #[test]
+    fn test7() {
+        // crockford's base32 is a base variant whether its feature is enabled or not
+        let input = "foobar";
+        let output = "CSQPYRK1E8";
+
+        assert_eq!(Encoder::from(BASE32CROCKFORD).encode(input), output);
+    }
 }
 
 mod decoder {
     use makura::BASE32;
+    use makura::BASE32CROCKFORD;
     use makura::Bases;
     use makura::Decoder;
 
@@ -146,4 +156,19 @@ mod decoder {
             input
         );
     }
+
+    #[test]
+    fn test7() {
+        // same as encoder::test7
+        let input = "foobar";
+        let output = "CSQPYRK1E8";
+
+        assert_eq!(
+            Decoder::decode(output, BASE32CROCKFORD)
+                .unwrap()
+                .into_utf8()
+                .unwrap(),
+            input
+        );
+    }
 }
@@ -0,0 +1,174 @@
+mod encoder {
+    use makura::Encoder;
+
+    #[test]
+    fn test0() {
+        let input = "foobar";
+        let output = "CSQPYRK1E8";
+        let enc = Encoder::base32_crockford();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test1() {
+        // never padded
+        let input = "Hello";
+        let output = "91JPRV3F";
+        let enc = Encoder::base32_crockford();
+
+        assert_eq!(enc.encode(input), output);
+        assert_eq!(enc.encode("H"), "90");
+    }
+
+    #[test]
+    fn test2() {
+        let enc = Encoder::base32_crockford().with_check_symbol(true);
+
+        assert_eq!(enc.encode("Hello"), "91JPRV3FG");
+        assert_eq!(enc.encode_bytes([0, 30]), "00F0U");
+        assert_eq!(enc.encode_bytes([0, 23]), "00BG=");
+        assert_eq!(enc.encode(""), "0");
+    }
+}
+
+mod decoder {
+    use makura::{BASE32CROCKFORD, DecodeError, Decoder};
+
+    #[test]
+    fn test0() {
+        let input = "CSQPYRK1E8";
+
+        assert_eq!(
+            Decoder::decode(input, BASE32CROCKFORD).unwrap().as_bytes(),
+            b"foobar"
+        );
+    }
+
+    #[test]
+    fn test1() {
+        // case insensitive, hyphens are ignored
+        let input = "csqp-yrk1-e8";
+
+        assert_eq!(
+            Decoder::decode(input, BASE32CROCKFORD).unwrap().as_bytes(),
+            b"foobar"
+        );
+    }
+
+    #[test]
+    fn test2() {
+        // I and L are read as 1, O as 0
+        let input = "o9IpRv3F";
+
+        assert_eq!(
+            Decoder::decode(input, BASE32CROCKFORD).unwrap().as_bytes(),
+            Decoder::decode("091PRV3F", BASE32CROCKFORD)
+                .unwrap()
+                .as_bytes()
+        );
+        assert_eq!(
+            Decoder::decode("9L", BASE32CROCKFORD).unwrap().into_bytes(),
+            Decoder::decode("91", BASE32CROCKFORD).unwrap().into_bytes()
+        );
+    }
+
+    #[test]
+    fn test3() {
+        let input = "91JPRV3U";
+
+        assert_eq!(
+            Decoder::decode(input, BASE32CROCKFORD).unwrap_err(),
+            DecodeError::InvalidChar {
+                char: 'U',
+                base: BASE32CROCKFORD
+            }
+        );
+    }
+
+    #[test]
+    fn test4() {
+        // the hyphens don't count towards the len
+        let input = "91JP-RV";
+
+        assert_eq!(
+            Decoder::decode(input, BASE32CROCKFORD).unwrap_err(),
+            DecodeError::InvalidLen {
+                len: 6,
+                base: BASE32CROCKFORD
+            }
+        );
+    }
+
+    #[test]
+    fn test5() {
+        let dec = Decoder::new().with_check_symbol(true);
+
+        assert_eq!(
+            dec.apply("91JP-RV3F-G", BASE32CROCKFORD)
+                .unwrap()
+                .as_bytes(),
+            b"Hello"
+        );
+        assert_eq!(
+            dec.apply("00f0u", BASE32CROCKFORD).unwrap().into_bytes(),
+            [0, 30]
+        );
+        assert_eq!(
+            dec.apply("00BG=", BASE32CROCKFORD).unwrap().into_bytes(),
+            [0, 23]
+        );
+    }
+
+    #[test]
+    fn test6() {
+        let dec = Decoder::new().with_check_symbol(true);
+
+        assert_eq!(
+            dec.apply("91JPRV3FH", BASE32CROCKFORD).unwrap_err(),
+            DecodeError::ChecksumMismatch {
+                expected: 17,
+                found: 16
+            }
+        );
+        assert_eq!(
+            dec.apply("91JPRV3F#", BASE32CROCKFORD).unwrap_err(),
+            DecodeError::InvalidChar {
+                char: '#',
+                base: BASE32CROCKFORD
+            }
+        );
+    }
+
+    #[test]
+    fn test7() {
+        // the pad of a non paddable base is an invalid char, wherever it is
+        ["00#0", "000#"].into_iter().for_each(|input| {
+            assert_eq!(
+                Decoder::decode(input, BASE32CROCKFORD).unwrap_err(),
+                DecodeError::InvalidChar {
+                    char: '#',
+                    base: BASE32CROCKFORD
+                }
+            );
+        });
+    }
+}
+
+mod properties {
+    use makura::{BASE32CROCKFORD, Decoder, Encoder};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn round_trip(input in proptest::collection::vec(any::<u8>(), 0..128), check: bool) {
+            let encoded = Encoder::base32_crockford()
+                .with_check_symbol(check)
+                .encode_bytes(&input);
+            let dec = Decoder::new().with_check_symbol(check);
+
+            prop_assert_eq!(dec.apply(&encoded, BASE32CROCKFORD).unwrap().into_bytes(), input.clone());
+            prop_assert_eq!(dec.apply(encoded.to_lowercase(), BASE32CROCKFORD).unwrap().into_bytes(), input);
+        }
+    }
+}
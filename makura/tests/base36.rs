@@ -0,0 +1,151 @@
+mod encoder {
+    use makura::Encoder;
+
+    #[test]
+    fn test0() {
+        let input = "hello world";
+        let output = "fuvrsivvnfrbjwajo";
+        let enc = Encoder::base36();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test1() {
+        // every leading 0 byte is a leading '0'
+        let input = [0, 0, 0xff];
+        let output = "0073";
+        let enc = Encoder::base36();
+
+        assert_eq!(enc.encode_bytes(input), output);
+    }
+
+    #[test]
+    fn test2() {
+        // same as java's Long.toString(x, 36)
+        let enc = Encoder::base36();
+
+        assert_eq!(enc.encode_u64(0).unwrap(), "0");
+        assert_eq!(enc.encode_u64(35).unwrap(), "z");
+        assert_eq!(enc.encode_u64(i64::MAX as u64).unwrap(), "1y2p0ij32e8e7");
+        assert_eq!(enc.encode_u64(u64::MAX).unwrap(), "3w5e11264sgsf");
+        assert_eq!(
+            enc.encode_u128(u128::MAX).unwrap(),
+            "f5lxx1zz5pnorynqglhzmsp33"
+        );
+    }
+}
+
+mod decoder {
+    use makura::{BASE36, DecodeError, Decoder};
+
+    #[test]
+    fn test0() {
+        let input = "fuvrsivvnfrbjwajo";
+        let output = "hello world";
+
+        assert_eq!(
+            Decoder::decode(input, BASE36).unwrap().into_utf8().unwrap(),
+            output
+        );
+    }
+
+    #[test]
+    fn test1() {
+        // case insensitive
+        let input = "FUVRSIVVNFRBJWAJO";
+        let output = "hello world";
+
+        assert_eq!(
+            Decoder::decode(input, BASE36).unwrap().into_utf8().unwrap(),
+            output
+        );
+        assert_eq!(
+            Decoder::decode("0073", BASE36).unwrap().into_bytes(),
+            [0, 0, 0xff]
+        );
+    }
+
+    #[test]
+    fn test2() {
+        let dec = Decoder::new();
+
+        assert_eq!(dec.decode_u64("1Y2P0IJ32E8E7", BASE36), Ok(i64::MAX as u64));
+        assert_eq!(dec.decode_u64("3w5e11264sgsf", BASE36), Ok(u64::MAX));
+        assert_eq!(
+            dec.decode_u128("f5lxx1zz5pnorynqglhzmsp33", BASE36),
+            Ok(u128::MAX)
+        );
+    }
+
+    #[test]
+    fn test3() {
+        let dec = Decoder::new();
+
+        // u64::MAX + 1
+        assert_eq!(
+            dec.decode_u64("3w5e11264sgsg", BASE36),
+            Err(DecodeError::Overflow { base: BASE36 })
+        );
+        // u128::MAX + 1
+        assert_eq!(
+            dec.decode_u128("f5lxx1zz5pnorynqglhzmsp34", BASE36),
+            Err(DecodeError::Overflow { base: BASE36 })
+        );
+        // leading 0s don't overflow
+        assert_eq!(
+            dec.decode_u64("00000000003w5e11264sgsf", BASE36),
+            Ok(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn test4() {
+        let input = "hello_world";
+
+        assert_eq!(
+            Decoder::decode(input, BASE36).unwrap_err(),
+            DecodeError::InvalidChar {
+                char: '_',
+                base: BASE36
+            }
+        );
+    }
+
+    #[test]
+    fn test5() {
+        // the pad of a non paddable base is an invalid char, wherever it is
+        ["1=1", "11="].into_iter().for_each(|input| {
+            assert_eq!(
+                Decoder::decode(input, BASE36).unwrap_err(),
+                DecodeError::InvalidChar {
+                    char: '=',
+                    base: BASE36
+                }
+            );
+        });
+    }
+}
+
+mod properties {
+    use makura::{BASE36, Decoder, Encoder};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn round_trip(input in any::<Vec<u8>>()) {
+            let encoded = Encoder::base36().encode_bytes(&input);
+
+            prop_assert!(encoded.len() <= Encoder::base36().encoded_len(input.len()));
+            prop_assert_eq!(Decoder::decode(&encoded, BASE36).unwrap().into_bytes(), input.clone());
+            prop_assert_eq!(Decoder::decode(encoded.to_uppercase(), BASE36).unwrap().into_bytes(), input);
+        }
+
+        #[test]
+        fn round_trip_int(input in any::<u64>()) {
+            let encoded = Encoder::base36().encode_u64(input).unwrap();
+
+            prop_assert_eq!(Decoder::new().decode_u64(encoded.to_uppercase(), BASE36), Ok(input));
+        }
+    }
+}
@@ -33,7 +33,6 @@ mod encoder {
 
 mod decoder {
     use makura::BASE45;
-    use makura::Bases;
     use makura::Decoder;
 
     #[test]
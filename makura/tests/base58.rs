@@ -0,0 +1,226 @@
+// hex input -> base58 output, from the bitcoin core base58 test vectors
+const VECTORS: [(&str, &str); 12] = [
+    ("", ""),
+    ("61", "2g"),
+    ("626262", "a3gV"),
+    ("636363", "aPEr"),
+    (
+        "73696d706c792061206c6f6e6720737472696e67",
+        "2cFupjhnEsSn59qHXstmK2ffpLv2",
+    ),
+    (
+        "00eb15231dfceb60925886b67d065299925915aeb172c06647",
+        "1NS17iag9jJgTHD1VXjvLCEnZuQ3rJDE9L",
+    ),
+    ("516b6fcd0f", "ABnLTmg"),
+    ("bf4f89001e670274dd", "3SEo3LWLoPntC"),
+    ("572e4794", "3EFU7m"),
+    ("ecac89cad93923c02321", "EJDM8drfXA6uyA"),
+    ("10c8511e", "Rt5zm"),
+    ("00000000000000000000", "1111111111"),
+];
+
+fn from_hex(hex: &str) -> Vec<u8> {
+    makura::Decoder::decode(hex.to_uppercase(), makura::BASE16)
+        .unwrap()
+        .into_bytes()
+}
+
+mod encoder {
+    use makura::Encoder;
+
+    use super::{VECTORS, from_hex};
+
+    #[test]
+    fn test0() {
+        VECTORS.into_iter().for_each(|(input, output)| {
+            assert_eq!(Encoder::base58().encode_bytes(from_hex(input)), output);
+        });
+    }
+
+    #[test]
+    fn test1() {
+        let input = "Hello World!";
+        let output = "2NEpo7TZRRrLZSi2U";
+        let enc = Encoder::base58();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test2() {
+        let input = "The quick brown fox jumps over the lazy dog.";
+        let output = "USm3fpXnKG5EUBx2ndxBDMPVciP5hGey2Jh4NDv6gmeo1LkMeiKrLJUUBk6Z";
+        let enc = Encoder::base58();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test3() {
+        // encoded_len is an upper bound, encode_to_slice returns the actual len
+        let input = b"Hello World!";
+        let enc = Encoder::base58();
+        let mut out = [0u8; 32];
+
+        assert!(enc.encoded_len(input.len()) >= 17);
+        assert_eq!(enc.encode_to_slice(input, &mut out), Ok(17));
+        assert_eq!(&out[..17], b"2NEpo7TZRRrLZSi2U");
+    }
+
+    #[test]
+    fn test4() {
+        // the ripple alphabet
+        let alphabet = makura::Alphabet::new(
+            "rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz",
+            '=',
+        )
+        .unwrap();
+        let enc = Encoder::custom(alphabet);
+
+        assert_eq!(*enc.base(), makura::BASE58);
+        assert_eq!(enc.encode_bytes([0, 0, 1]), "rrp");
+    }
+}
+
+mod decoder {
+    use makura::{BASE16, BASE45, BASE58, Bases, DecodeError, Decoder};
+
+    use super::{VECTORS, from_hex};
+
+    #[test]
+    fn test0() {
+        VECTORS.into_iter().for_each(|(output, input)| {
+            assert_eq!(
+                Decoder::decode(input, BASE58).unwrap().into_bytes(),
+                from_hex(output)
+            );
+        });
+    }
+
+    #[test]
+    fn test1() {
+        let output = "Hello World!";
+        let input = "2NEpo7TZRRrLZSi2U";
+
+        assert_eq!(
+            Decoder::decode(input, BASE58).unwrap().into_utf8().unwrap(),
+            output
+        );
+    }
+
+    #[test]
+    fn test2() {
+        // no 0, O, I nor l in the alphabet
+        let input = "2NEpo7TZRRrLZSi0U";
+
+        assert_eq!(
+            Decoder::decode(input, BASE58).unwrap_err(),
+            DecodeError::InvalidChar {
+                char: '0',
+                base: BASE58
+            }
+        );
+    }
+
+    #[test]
+    fn test3() {
+        let input = "2g==";
+
+        assert_eq!(
+            Decoder::decode(input, BASE58).unwrap_err(),
+            DecodeError::InvalidChar {
+                char: '=',
+                base: BASE58
+            }
+        );
+    }
+
+    #[test]
+    fn test4() {
+        let input = "2NEpo7TZ\nRRrLZSi2U\n";
+        let dec = Decoder::new().with_skip_whitespace(true);
+
+        assert_eq!(
+            dec.apply(input, BASE58).unwrap().as_bytes(),
+            b"Hello World!"
+        );
+    }
+
+    #[test]
+    fn test5() {
+        let input = "1112g";
+        let mut out = [0u8; 3];
+
+        assert_eq!(
+            Decoder::new().decode_to_slice(input, BASE58, &mut out),
+            Err(DecodeError::OutputTooSmall {
+                len: 3,
+                required: 5
+            })
+        );
+
+        let mut out = [0u8; 4];
+        assert_eq!(
+            Decoder::new().decode_to_slice(input, BASE58, &mut out),
+            Ok(4)
+        );
+        assert_eq!(out, [0, 0, 0, 0x61]);
+    }
+
+    #[test]
+    fn test6() {
+        // a bitcoin p2pkh address, too long for base64 without padding
+        let input = "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa";
+
+        assert_eq!(Bases::deduce_default(input), Ok(BASE58));
+    }
+
+    #[test]
+    fn test7() {
+        // values of the rfc bases that are also valid base58 (and z85) are still deduced as before
+        [("48656C6C6F", BASE16), ("CO8P0", BASE45)]
+            .into_iter()
+            .for_each(|(input, base)| {
+                assert_eq!(Bases::default().deduce_encoding(input), Ok(base));
+                assert_eq!(Bases::deduce_default(input), Ok(base));
+            });
+    }
+
+    #[test]
+    fn test8() {
+        // the pad of a non paddable base is an invalid char, wherever it is
+        ["2=2", "22="].into_iter().for_each(|input| {
+            assert_eq!(
+                Decoder::decode(input, BASE58).unwrap_err(),
+                DecodeError::InvalidChar {
+                    char: '=',
+                    base: BASE58
+                }
+            );
+        });
+    }
+}
+
+mod properties {
+    use makura::{BASE58, Decoder, Encoder};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn round_trip(input in proptest::collection::vec(any::<u8>(), 0..128)) {
+            let encoded = Encoder::base58().encode_bytes(&input);
+
+            prop_assert!(encoded.len() <= Encoder::base58().encoded_len(input.len()));
+            prop_assert_eq!(Decoder::decode(&encoded, BASE58).unwrap().into_bytes(), input);
+        }
+
+        #[test]
+        fn leading_zeros(zeros in 0usize..16, input in proptest::collection::vec(1u8.., 0..32)) {
+            let value = [vec![0; zeros], input].concat();
+            let encoded = Encoder::base58().encode_bytes(&value);
+
+            prop_assert_eq!(encoded.bytes().take_while(|c| *c == b'1').count(), zeros);
+        }
+    }
+}
@@ -0,0 +1,145 @@
+fn from_hex(hex: &str) -> Vec<u8> {
+    makura::Decoder::decode(hex.to_uppercase(), makura::BASE16)
+        .unwrap()
+        .into_bytes()
+}
+
+mod encoder {
+    use makura::base58check::Base58Check;
+
+    use super::from_hex;
+
+    #[test]
+    fn test0() {
+        // p2pkh address
+        let input = Base58Check::new(0, from_hex("62e907b15cbf27d5425399ebf6f0fb50ebb88f18"));
+        let output = "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa";
+
+        assert_eq!(input.encode(), output);
+    }
+
+    #[test]
+    fn test1() {
+        // p2sh address
+        let input = Base58Check::new(5, from_hex("74f209f6ea907e2ea48f74fae05782ae8a665257"));
+        let output = "3CMNFxN1oHBc4R1EpboAL5yzHGgE611Xou";
+
+        assert_eq!(input.encode(), output);
+    }
+
+    #[test]
+    fn test2() {
+        // WIF private key
+        let input = Base58Check::new(
+            0x80,
+            from_hex("0c28fca386c7a227600b2fe50b7cae11ec86d3bf1fbe471be89827e19d72aa1d"),
+        );
+        let output = "5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ";
+
+        assert_eq!(input.encode(), output);
+    }
+
+    #[test]
+    fn test3() {
+        let input = Base58Check::new(0, Vec::new());
+        let output = "1Wh4bh";
+
+        assert_eq!(input.encode(), output);
+    }
+}
+
+mod decoder {
+    use makura::base58check::Base58Check;
+    use makura::{BASE58, DecodeError};
+
+    use super::from_hex;
+
+    #[test]
+    fn test0() {
+        let input = "5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ";
+        let output = Base58Check::decode(input).unwrap();
+
+        assert_eq!(output.version(), 0x80);
+        assert_eq!(
+            output.into_payload(),
+            from_hex("0c28fca386c7a227600b2fe50b7cae11ec86d3bf1fbe471be89827e19d72aa1d")
+        );
+    }
+
+    #[test]
+    fn test1() {
+        let input = "1Wh4bh";
+        let output = Base58Check::decode(input).unwrap();
+
+        assert_eq!(output.version(), 0);
+        assert!(output.payload().is_empty());
+    }
+
+    #[test]
+    fn test2() {
+        // the last char of a valid address was changed
+        let input = "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNb";
+
+        assert!(matches!(
+            Base58Check::decode(input),
+            Err(DecodeError::ChecksumMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test3() {
+        // the checksum of the empty payload is 0x1406e058
+        let input = makura::Encoder::base58().encode_bytes([0, 0x14, 0x06, 0xe0, 0x59]);
+
+        assert_eq!(
+            Base58Check::decode(input),
+            Err(DecodeError::ChecksumMismatch {
+                expected: 0x1406e059,
+                found: 0x1406e058
+            })
+        );
+    }
+
+    #[test]
+    fn test4() {
+        assert_eq!(
+            Base58Check::decode("3yQ"),
+            Err(DecodeError::InvalidLen {
+                len: 3,
+                base: BASE58
+            })
+        );
+    }
+
+    #[test]
+    fn test5() {
+        assert_eq!(
+            Base58Check::decode("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfN0"),
+            Err(DecodeError::InvalidChar {
+                char: '0',
+                base: BASE58
+            })
+        );
+    }
+
+    #[test]
+    fn test6() {
+        // only pad chars
+        assert!(Base58Check::decode("=").is_err());
+        assert!(Base58Check::decode("=====").is_err());
+    }
+}
+
+mod properties {
+    use makura::base58check::Base58Check;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn round_trip(version in any::<u8>(), payload in proptest::collection::vec(any::<u8>(), 0..64)) {
+            let value = Base58Check::new(version, payload);
+
+            prop_assert_eq!(Base58Check::decode(value.encode()).unwrap(), value);
+        }
+    }
+}
@@ -0,0 +1,185 @@
+mod encoder {
+    use makura::{BASE64, EncodeError, Encoder};
+
+    #[test]
+    fn test0() {
+        let input = "hello world";
+        let output = "AAwf93rvy4aWQVw";
+        let enc = Encoder::base62();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test1() {
+        // every leading 0 byte is a leading '0'
+        let input = [0, 0, 1];
+        let output = "001";
+        let enc = Encoder::base62();
+
+        assert_eq!(enc.encode_bytes(input), output);
+    }
+
+    #[test]
+    fn test2() {
+        let input = [0xff; 16];
+        let output = "7n42DGM5Tflk9n8mt7Fhc7";
+        let enc = Encoder::base62();
+
+        assert_eq!(enc.encode_bytes(input), output);
+        assert_eq!(enc.encode_u128(u128::MAX).unwrap(), output);
+    }
+
+    #[test]
+    fn test3() {
+        let enc = Encoder::base62();
+
+        assert_eq!(enc.encode_u64(u64::MAX).unwrap(), "LygHa16AHYF");
+        assert_eq!(enc.encode_u64(1234567890).unwrap(), "1LY7VK");
+        assert_eq!(enc.encode_u64(0).unwrap(), "0");
+    }
+
+    #[test]
+    fn test4() {
+        assert_eq!(
+            Encoder::base64().encode_u64(42),
+            Err(EncodeError::NonRadixEncoding(BASE64))
+        );
+    }
+}
+
+mod decoder {
+    use makura::{BASE62, BASE64, DecodeError, Decoder};
+
+    #[test]
+    fn test0() {
+        let input = "AAwf93rvy4aWQVw";
+        let output = "hello world";
+
+        assert_eq!(
+            Decoder::decode(input, BASE62).unwrap().into_utf8().unwrap(),
+            output
+        );
+    }
+
+    #[test]
+    fn test1() {
+        let input = "001";
+
+        assert_eq!(
+            Decoder::decode(input, BASE62).unwrap().into_bytes(),
+            [0, 0, 1]
+        );
+    }
+
+    #[test]
+    fn test2() {
+        let input = "AAwf93rvy4a-QVw";
+
+        assert_eq!(
+            Decoder::decode(input, BASE62).unwrap_err(),
+            DecodeError::InvalidChar {
+                char: '-',
+                base: BASE62
+            }
+        );
+    }
+
+    #[test]
+    fn test3() {
+        let dec = Decoder::new();
+
+        assert_eq!(dec.decode_u64("LygHa16AHYF", BASE62), Ok(u64::MAX));
+        assert_eq!(dec.decode_u64("0001LY7VK", BASE62), Ok(1234567890));
+        assert_eq!(
+            dec.decode_u128("7n42DGM5Tflk9n8mt7Fhc7", BASE62),
+            Ok(u128::MAX)
+        );
+    }
+
+    #[test]
+    fn test4() {
+        let dec = Decoder::new();
+
+        // u64::MAX + 1
+        assert_eq!(
+            dec.decode_u64("LygHa16AHYG", BASE62),
+            Err(DecodeError::Overflow { base: BASE62 })
+        );
+        // u128::MAX + 1
+        assert_eq!(
+            dec.decode_u128("7n42DGM5Tflk9n8mt7Fhc8", BASE62),
+            Err(DecodeError::Overflow { base: BASE62 })
+        );
+    }
+
+    #[test]
+    fn test5() {
+        let dec = Decoder::new();
+
+        assert_eq!(
+            dec.decode_u64("", BASE62),
+            Err(DecodeError::InvalidLen {
+                len: 0,
+                base: BASE62
+            })
+        );
+        assert_eq!(
+            dec.decode_u64("1LY7_VK", BASE62),
+            Err(DecodeError::InvalidChar {
+                char: '_',
+                base: BASE62
+            })
+        );
+    }
+
+    #[test]
+    fn test6() {
+        // the pad of a non paddable base is an invalid char, wherever it is
+        ["1=1", "11="].into_iter().for_each(|input| {
+            assert_eq!(
+                Decoder::decode(input, BASE62).unwrap_err(),
+                DecodeError::InvalidChar {
+                    char: '=',
+                    base: BASE62
+                }
+            );
+        });
+    }
+
+    #[test]
+    fn test7() {
+        assert_eq!(
+            Decoder::new().decode_u64("42", BASE64),
+            Err(DecodeError::NonRadixEncoding(BASE64))
+        );
+    }
+}
+
+mod properties {
+    use makura::{BASE62, Decoder, Encoder};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn round_trip(input in any::<Vec<u8>>()) {
+            let encoded = Encoder::base62().encode_bytes(&input);
+
+            prop_assert!(encoded.len() <= Encoder::base62().encoded_len(input.len()));
+            prop_assert_eq!(Decoder::decode(&encoded, BASE62).unwrap().into_bytes(), input);
+        }
+
+        #[test]
+        fn round_trip_int(input in any::<u128>()) {
+            let encoded = Encoder::base62().encode_u128(input).unwrap();
+
+            prop_assert_eq!(Decoder::new().decode_u128(&encoded, BASE62), Ok(input));
+            // the same number as the bytes encoding, leading 0 bytes excluded
+            let bytes = input.to_be_bytes();
+            let zeros = bytes.iter().take_while(|b| **b == 0).count();
+            if input != 0 {
+                prop_assert_eq!(encoded, Encoder::base62().encode_bytes(&bytes[zeros..]));
+            }
+        }
+    }
+}
@@ -0,0 +1,94 @@
+mod encoder {
+    use makura::base91;
+
+    #[test]
+    fn test0() {
+        // the reference implementation's vectors
+        [
+            ("test", "fPNKd"),
+            ("Hello, World!", ">OwJh>}AQ;r@@Y?F"),
+            (
+                "May a moody baby doom a yam?\n",
+                "8D9Kc)=/2$WzeFui#G9Km+<{VT2u9MZil}[A",
+            ),
+            ("", ""),
+        ]
+        .into_iter()
+        .for_each(|(input, output)| {
+            assert_eq!(base91::encode(input), output);
+        });
+    }
+
+    #[test]
+    fn test1() {
+        // the last bits take a single char when they fit in it
+        assert_eq!(base91::encode([0]), "AA");
+        assert_eq!(base91::encode("a"), "GB");
+        assert_eq!(base91::encode([0xff; 4]), "B\"B\"#");
+    }
+
+    #[test]
+    fn test2() {
+        let input = (0..20).collect::<Vec<u8>>();
+        let output = ":C#(:C?hVB$MSiVEwndBAMZRB";
+
+        assert_eq!(base91::encode(input), output);
+    }
+}
+
+mod decoder {
+    use makura::base91::{self, Base91Error};
+
+    #[test]
+    fn test0() {
+        [
+            ("fPNKd", "test"),
+            (">OwJh>}AQ;r@@Y?F", "Hello, World!"),
+            (
+                "8D9Kc)=/2$WzeFui#G9Km+<{VT2u9MZil}[A",
+                "May a moody baby doom a yam?\n",
+            ),
+            ("", ""),
+        ]
+        .into_iter()
+        .for_each(|(input, output)| {
+            assert_eq!(base91::decode(input).unwrap(), output.as_bytes());
+        });
+    }
+
+    #[test]
+    fn test1() {
+        let input = "8D9Kc)=/2$WzeFui\n#G9Km+<{VT2u9MZil}[A\n";
+
+        assert_eq!(
+            base91::decode(input).unwrap(),
+            b"May a moody baby doom a yam?\n"
+        );
+    }
+
+    #[test]
+    fn test2() {
+        let input = "fPN-Kd";
+
+        assert_eq!(
+            base91::decode(input),
+            Err(Base91Error::InvalidChar { char: '-', idx: 3 })
+        );
+    }
+}
+
+mod properties {
+    use makura::base91;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn round_trip(input in any::<Vec<u8>>()) {
+            let encoded = base91::encode(&input);
+
+            // every 2 chars hold at least 13 bits
+            prop_assert!(encoded.len() <= (input.len() * 16).div_ceil(13) + 1);
+            prop_assert_eq!(base91::decode(encoded).unwrap(), input);
+        }
+    }
+}
@@ -0,0 +1,236 @@
+mod encoder {
+    use makura::bech32::{self, Bech32, Bech32Error, MAX_LEN, Variant};
+
+    #[test]
+    fn test0() {
+        // BIP 173 p2wpkh address, witness version 0 then the 20 bytes program
+        let program = [
+            0x75, 0x1e, 0x76, 0xe8, 0x19, 0x91, 0x96, 0xd4, 0x54, 0x94, 0x1c, 0x45, 0xd1, 0xb3,
+            0xa3, 0x23, 0xf1, 0x43, 0x3b, 0xd6,
+        ];
+        let groups = [&[0][..], &bech32::to_groups(&program)].concat();
+
+        assert_eq!(
+            bech32::encode_groups("bc", &groups, Variant::Bech32, MAX_LEN).unwrap(),
+            "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4"
+        );
+    }
+
+    #[test]
+    fn test1() {
+        // BIP 350 taproot address, witness version 1
+        let program = [
+            0x79, 0xbe, 0x66, 0x7e, 0xf9, 0xdc, 0xbb, 0xac, 0x55, 0xa0, 0x62, 0x95, 0xce, 0x87,
+            0x0b, 0x07, 0x02, 0x9b, 0xfc, 0xdb, 0x2d, 0xce, 0x28, 0xd9, 0x59, 0xf2, 0x81, 0x5b,
+            0x16, 0xf8, 0x17, 0x98,
+        ];
+        let groups = [&[1][..], &bech32::to_groups(&program)].concat();
+
+        assert_eq!(
+            bech32::encode_groups("bc", &groups, Variant::Bech32m, MAX_LEN).unwrap(),
+            "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0"
+        );
+    }
+
+    #[test]
+    fn test2() {
+        // the hrp is lowercased
+        let value = Bech32::new("ABC", b"makura".to_vec(), Variant::Bech32m);
+
+        assert_eq!(value.encode().unwrap(), "abc1d4skkatjvya4dwky");
+    }
+
+    #[test]
+    fn test3() {
+        let value = Bech32::new("aBc", vec![], Variant::Bech32);
+
+        assert_eq!(value.encode(), Err(Bech32Error::MixedCase));
+    }
+
+    #[test]
+    fn test4() {
+        let value = Bech32::new("lnbc", vec![0; 64], Variant::Bech32);
+
+        assert_eq!(
+            value.encode(),
+            Err(Bech32Error::TooLong { len: 114, max: 90 })
+        );
+        assert_eq!(value.encode_with_limit(1023).unwrap().len(), 114);
+    }
+
+    #[test]
+    fn test5() {
+        let value = Bech32::new("", vec![], Variant::Bech32);
+
+        assert_eq!(value.encode(), Err(Bech32Error::InvalidHrp("".into())));
+    }
+
+    #[test]
+    fn test6() {
+        // "makura1" and the 6 checksum chars leave 77 groups, i.e., 48 bytes
+        let value = Bech32::new("makura", vec![0; 48], Variant::Bech32);
+        assert_eq!(value.encode().unwrap().len(), MAX_LEN);
+
+        let value = Bech32::new("makura", vec![0; 49], Variant::Bech32);
+        assert_eq!(
+            value.encode(),
+            Err(Bech32Error::TooLong { len: 92, max: 90 })
+        );
+    }
+}
+
+mod decoder {
+    use makura::bech32::{self, Bech32, Bech32Error, MAX_LEN, Variant};
+
+    #[test]
+    fn test0() {
+        // BIP 173 valid checksums
+        [
+            "A12UEL5L",
+            "a12uel5l",
+            "an83characterlonghumanreadablepartthatcontainsthenumber1andtheexcludedcharactersbio1tt5tgs",
+            "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw",
+            "11qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqc8247j",
+            "split1checkupstagehandshakeupstreamerranterredcaperred2y9e3w",
+            "?1ezyfcl",
+        ]
+        .into_iter()
+        .for_each(|input| {
+            assert_eq!(
+                bech32::decode_groups(input, MAX_LEN).unwrap().2,
+                Variant::Bech32
+            );
+        });
+    }
+
+    #[test]
+    fn test1() {
+        // BIP 350 valid checksums
+        [
+            "A1LQFN3A",
+            "a1lqfn3a",
+            "an83characterlonghumanreadablepartthatcontainsthetheexcludedcharactersbioandnumber11sg7hg6",
+            "abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx",
+            "11llllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllludsr8",
+            "split1checkupstagehandshakeupstreamerranterredcaperredlc445v",
+            "?1v759aa",
+        ]
+        .into_iter()
+        .for_each(|input| {
+            assert_eq!(
+                bech32::decode_groups(input, MAX_LEN).unwrap().2,
+                Variant::Bech32m
+            );
+        });
+    }
+
+    #[test]
+    fn test2() {
+        let input = "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw";
+        let (hrp, groups, _) = bech32::decode_groups(input, MAX_LEN).unwrap();
+
+        assert_eq!(hrp, "abcdef");
+        assert_eq!(groups, (0..32).collect::<Vec<u8>>());
+    }
+
+    #[test]
+    fn test3() {
+        let input = "BC1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7KV8F3T4";
+        let (hrp, groups, variant) = bech32::decode_groups(input, MAX_LEN).unwrap();
+
+        assert_eq!(hrp, "bc");
+        assert_eq!(variant, Variant::Bech32);
+        assert_eq!(groups[0], 0);
+        assert_eq!(
+            bech32::from_groups(&groups[1..]).unwrap(),
+            [
+                0x75, 0x1e, 0x76, 0xe8, 0x19, 0x91, 0x96, 0xd4, 0x54, 0x94, 0x1c, 0x45, 0xd1, 0xb3,
+                0xa3, 0x23, 0xf1, 0x43, 0x3b, 0xd6,
+            ]
+        );
+    }
+
+    #[test]
+    fn test4() {
+        let input = "abc1d4skkatjvya4dwky";
+        let decoded = Bech32::decode(input).unwrap();
+
+        assert_eq!(decoded.hrp(), "abc");
+        assert_eq!(decoded.data(), b"makura");
+        assert_eq!(decoded.variant(), Variant::Bech32m);
+    }
+
+    #[test]
+    fn test5() {
+        assert_eq!(Bech32::decode("A12UeL5L"), Err(Bech32Error::MixedCase));
+    }
+
+    #[test]
+    fn test6() {
+        let input = "an84characterslonghumanreadablepartthatcontainsthenumber1andtheexcludedcharactersbio1569pvx";
+
+        assert_eq!(
+            bech32::decode_groups(input, MAX_LEN),
+            Err(Bech32Error::TooLong { len: 91, max: 90 })
+        );
+        // hrps are at most 83 chars
+        assert_eq!(
+            bech32::decode_groups(input, 91),
+            Err(Bech32Error::InvalidHrp(input[..84].into()))
+        );
+    }
+
+    #[test]
+    fn test7() {
+        // BIP 173 invalid values
+        [
+            ("pzry9x0s0muk", Bech32Error::MissingSeparator),
+            ("1pzry9x0s0muk", Bech32Error::InvalidHrp("".into())),
+            ("10a06t8", Bech32Error::InvalidHrp("".into())),
+            ("x1b4n0q5v", Bech32Error::InvalidChar { char: 'b', idx: 2 }),
+            ("li1dgmt3", Bech32Error::TooShort { len: 5 }),
+            // the checksum is computed over the uppercase hrp
+            ("A1G7SGD8", Bech32Error::InvalidChecksum),
+            ("\x201nwldj5", Bech32Error::InvalidHrp(" ".into())),
+        ]
+        .into_iter()
+        .for_each(|(input, err)| {
+            assert_eq!(bech32::decode_groups(input, MAX_LEN), Err(err));
+        });
+    }
+
+    #[test]
+    fn test8() {
+        // 5 bits groups that don't add up to whole bytes
+        assert_eq!(bech32::from_groups(&[31]), Err(Bech32Error::InvalidPadding));
+        assert_eq!(
+            bech32::from_groups(&[31, 29]),
+            Err(Bech32Error::InvalidPadding)
+        );
+        assert_eq!(bech32::from_groups(&[31, 28]).unwrap(), [0xff]);
+    }
+}
+
+mod properties {
+    use makura::bech32::{Bech32, Bech32Error, MAX_LEN, Variant};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn round_trip(input in proptest::collection::vec(any::<u8>(), 0..64), m in any::<bool>()) {
+            let variant = if m { Variant::Bech32m } else { Variant::Bech32 };
+            // "makura1", the 5 bits groups then the 6 checksum chars
+            let len = 7 + (input.len() * 8).div_ceil(5) + 6;
+            let value = Bech32::new("makura", input, variant);
+
+            if len > MAX_LEN {
+                prop_assert_eq!(value.encode(), Err(Bech32Error::TooLong { len, max: MAX_LEN }));
+                return Ok(());
+            }
+            let encoded = value.encode().unwrap();
+
+            prop_assert_eq!(Bech32::decode(&encoded).unwrap(), value.clone());
+            prop_assert_eq!(Bech32::decode(encoded.to_uppercase()).unwrap(), value);
+        }
+    }
+}
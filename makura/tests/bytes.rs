@@ -0,0 +1,239 @@
+mod encoder {
+    use makura::Encoder;
+
+    #[test]
+    fn test0() {
+        let input: [u8; 0] = [];
+        let output = "";
+        let enc = Encoder::base64();
+
+        assert_eq!(enc.encode_bytes(input), output);
+    }
+
+    #[test]
+    fn test1() {
+        let input = [0u8, 0, 0];
+        let output = "AAAA";
+        let enc = Encoder::base64();
+
+        assert_eq!(enc.encode_bytes(input), output);
+    }
+
+    #[test]
+    fn test2() {
+        let input = [1u8, 2, 0x40];
+        let output = "AQJA";
+        let enc = Encoder::base64();
+
+        assert_eq!(enc.encode_bytes(input), output);
+    }
+
+    #[test]
+    fn test3() {
+        let input = [0xfbu8, 0xff];
+        let output = "-_8=";
+        let enc = Encoder::base64_url();
+
+        assert_eq!(enc.encode_bytes(input), output);
+    }
+
+    #[test]
+    fn test4() {
+        let input = [0u8; 5];
+        let output = "AAAAAAAA";
+        let enc = Encoder::base32();
+
+        assert_eq!(enc.encode_bytes(input), output);
+    }
+
+    #[test]
+    fn test5() {
+        let input = [0u8];
+        let output = "00======";
+        let enc = Encoder::base32_hex();
+
+        assert_eq!(enc.encode_bytes(input), output);
+    }
+
+    #[test]
+    fn test6() {
+        let input = [0u8, 0, 0xff];
+        let output = "000U5";
+        let enc = Encoder::base45();
+
+        assert_eq!(enc.encode_bytes(input), output);
+    }
+
+    #[test]
+    fn test7() {
+        let input = [0xdeu8, 0xad, 0xbe, 0xef];
+        let output = "DEADBEEF";
+        let enc = Encoder::base16();
+
+        assert_eq!(enc.encode_bytes(input), output);
+    }
+
+    #[test]
+    fn test8() {
+        let input = "foobar";
+        let enc = Encoder::base64();
+
+        assert_eq!(enc.encode_bytes(input.as_bytes()), enc.encode(input));
+    }
+}
+
+mod round_trip {
+    use makura::{BASE16, BASE32, BASE32HEX, BASE45, BASE64, BASE64URL};
+    use makura::{Decoder, Encoder};
+
+    // none of these is valid utf8
+    const INPUTS: [&[u8]; 6] = [
+        &[0xff],
+        &[0xc3, 0x28],
+        &[0x80, 0x81, 0x82],
+        &[0x00, 0xfe, 0xed, 0xfa, 0xce],
+        &[0xf0, 0x28, 0x8c, 0x28, 0x01, 0x02, 0x03],
+        &[
+            0x00, 0x00, 0xff, 0xfe, 0xfd, 0x9f, 0x10, 0x20, 0x30, 0x40, 0x41,
+        ],
+    ];
+
+    #[test]
+    fn test0() {
+        [BASE64, BASE64URL, BASE45, BASE32, BASE32HEX, BASE16]
+            .into_iter()
+            .for_each(|base| {
+                let enc = Encoder::from(base);
+                INPUTS.iter().for_each(|input| {
+                    assert!(core::str::from_utf8(input).is_err());
+
+                    let output = enc.encode_bytes(input);
+                    assert_eq!(
+                        Decoder::decode(&output, base).unwrap().into_bytes(),
+                        *input,
+                        "{:?} round trip of {:?} through {:?}",
+                        base,
+                        input,
+                        output
+                    );
+                });
+            });
+    }
+}
+
+mod trailing_zeroes {
+    use makura::{BASE32, BASE32HEX, BASE64, BASE64URL};
+    use makura::{Decoder, Encoder};
+
+    #[test]
+    fn test0() {
+        let input = [1u8, 0, 0];
+        let enc = Encoder::base64();
+        let output = enc.encode_bytes(input);
+
+        assert_eq!(output, "AQAA");
+        assert_eq!(Decoder::decode(output, BASE64).unwrap().into_bytes(), input);
+    }
+
+    #[test]
+    fn test1() {
+        let input = [0u8];
+        let output = "AA==";
+
+        assert_eq!(
+            Decoder::decode(output, BASE64URL).unwrap().into_bytes(),
+            input
+        );
+    }
+
+    #[test]
+    fn test2() {
+        let input = [0x66u8, 0, 0, 0];
+        let enc = Encoder::base32();
+        let output = enc.encode_bytes(input);
+
+        assert_eq!(output, "MYAAAAA=");
+        assert_eq!(Decoder::decode(output, BASE32).unwrap().into_bytes(), input);
+    }
+
+    #[test]
+    fn test3() {
+        let input = [0u8; 7];
+        let output = "000000000000====";
+
+        assert_eq!(
+            Decoder::decode(output, BASE32HEX).unwrap().into_bytes(),
+            input
+        );
+    }
+}
+
+mod properties {
+    use makura::{BASE16, BASE32, BASE32HEX, BASE45, BASE64, BASE64URL, Base};
+    use makura::{Decoder, Encoder, Padding};
+    use proptest::prelude::*;
+
+    fn round_trip(base: Base, input: &[u8]) -> Vec<u8> {
+        let output = Encoder::from(base).encode_bytes(input);
+
+        Decoder::decode(output, base).unwrap().into_bytes()
+    }
+
+    proptest! {
+        #[test]
+        fn base64(input in proptest::collection::vec(any::<u8>(), 0..256)) {
+            prop_assert_eq!(round_trip(BASE64, &input), input);
+        }
+
+        #[test]
+        fn base64_url(input in proptest::collection::vec(any::<u8>(), 0..256)) {
+            prop_assert_eq!(round_trip(BASE64URL, &input), input);
+        }
+
+        #[test]
+        fn base45(input in proptest::collection::vec(any::<u8>(), 0..256)) {
+            prop_assert_eq!(round_trip(BASE45, &input), input);
+        }
+
+        #[test]
+        fn base32(input in proptest::collection::vec(any::<u8>(), 0..256)) {
+            prop_assert_eq!(round_trip(BASE32, &input), input);
+        }
+
+        #[test]
+        fn base32_hex(input in proptest::collection::vec(any::<u8>(), 0..256)) {
+            prop_assert_eq!(round_trip(BASE32HEX, &input), input);
+        }
+
+        #[test]
+        fn base16(input in proptest::collection::vec(any::<u8>(), 0..256)) {
+            prop_assert_eq!(round_trip(BASE16, &input), input);
+        }
+
+        #[test]
+        fn unpadded(input in proptest::collection::vec(any::<u8>(), 0..256)) {
+            let dec = Decoder::new().with_padding(Padding::Omitted);
+            [BASE64, BASE64URL, BASE32, BASE32HEX]
+                .into_iter()
+                .try_for_each(|base| {
+                    let output = Encoder::from(base).with_padding(Padding::Omitted).encode_bytes(&input);
+                    prop_assert_eq!(dec.apply(output, base).unwrap().into_bytes(), input.clone());
+
+                    Ok(())
+                })?;
+        }
+
+        // inputs made only of 0 bytes are the worst case for the old trailing 0s stripping
+        #[test]
+        fn zeroes(len in 0usize..64) {
+            let input = vec![0u8; len];
+            [BASE64, BASE64URL, BASE45, BASE32, BASE32HEX, BASE16]
+                .into_iter()
+                .try_for_each(|base| {
+                    prop_assert_eq!(round_trip(base, &input), input.clone());
+
+                    Ok(())
+                })?;
+        }
+    }
+}
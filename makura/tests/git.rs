@@ -0,0 +1,77 @@
+use makura::{BASE85RFC, DecodeError, git};
+
+#[test]
+fn test0() {
+    let input = b"hello world\n";
+    let output = "LXk~0{Zy<MXa%^M@\n";
+
+    assert_eq!(git::encode(input), output);
+    assert_eq!(git::decode(output).unwrap(), input);
+}
+
+#[test]
+fn test1() {
+    // a full line of 52 bytes ('z') followed by one of 8 ('H')
+    let input = (0..60).collect::<Vec<u8>>();
+    let output =
+        "z009C61O)~M2nh-c3=Iws5D^j+6crX17#SKH9337XAR!_nBqb&%C@Cr{EG;fCFflSS\nHG&MFiI5|2y\n";
+
+    assert_eq!(git::encode(&input), output);
+    assert_eq!(git::decode(output).unwrap(), input);
+}
+
+#[test]
+fn test2() {
+    assert_eq!(git::encode(""), "");
+    assert_eq!(git::decode("\n").unwrap(), b"");
+}
+
+#[test]
+fn test3() {
+    // crlf line endings
+    let input = "LXk~0{Zy<MXa%^M@\r\n";
+
+    assert_eq!(git::decode(input).unwrap(), b"hello world\n");
+}
+
+#[test]
+fn test4() {
+    // the len prefix is 13 bytes, the line holds 12
+    let input = "MXk~0{Zy<MXa%^M@\n";
+
+    assert_eq!(
+        git::decode(input).unwrap_err(),
+        DecodeError::InvalidLen {
+            len: 15,
+            base: BASE85RFC
+        }
+    );
+}
+
+#[test]
+fn test5() {
+    let input = "0Xk~0{Zy<MXa%^M@\n";
+
+    assert_eq!(
+        git::decode(input).unwrap_err(),
+        DecodeError::InvalidChar {
+            char: '0',
+            base: BASE85RFC
+        }
+    );
+}
+
+mod properties {
+    use makura::git;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn round_trip(input in proptest::collection::vec(any::<u8>(), 0..256)) {
+            let encoded = git::encode(&input);
+
+            prop_assert!(encoded.lines().all(|line| line.len() <= 66));
+            prop_assert_eq!(git::decode(&encoded).unwrap(), input);
+        }
+    }
+}
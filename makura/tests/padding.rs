@@ -0,0 +1,226 @@
+mod encoder {
+    use makura::{Encoder, Padding};
+
+    #[test]
+    fn test0() {
+        let input = "f";
+        let output = "Zg";
+        let enc = Encoder::base64_url().with_padding(Padding::Omitted);
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test1() {
+        let input = "fo";
+        let output = "Zm8";
+        let enc = Encoder::base64().with_padding(Padding::Omitted);
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test2() {
+        let input = "foobar";
+        let output = "MZXW6YTBOI";
+        let enc = Encoder::base32().with_padding(Padding::Omitted);
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test3() {
+        let input = "foobar";
+        let output = "MZXW6YTBOI======";
+        let enc = Encoder::base32().with_padding(Padding::Indifferent);
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test4() {
+        let input = [0xffu8, 0xfe];
+        let output = "__4";
+        let enc = Encoder::base64_url().with_padding(Padding::Omitted);
+
+        assert_eq!(enc.encode_bytes(input), output);
+    }
+}
+
+mod decoder {
+    use makura::{BASE16, BASE32, BASE32CROCKFORD, BASE32HEX, BASE36, BASE45, BASE58, BASE62};
+    use makura::{BASE64, BASE64URL, BASE85, BASE85RFC, Z85, ZBASE32};
+    use makura::{Bases, DecodeError, Decoder, Padding};
+
+    #[test]
+    fn test0() {
+        // RFC 7515 appendix A.1 JWS header
+        let input = "{\"typ\":\"JWT\",\r\n \"alg\":\"HS256\"}";
+        let output = "eyJ0eXAiOiJKV1QiLA0KICJhbGciOiJIUzI1NiJ9";
+        let dec = Decoder::new().with_padding(Padding::Omitted);
+
+        assert_eq!(
+            dec.apply(output, BASE64URL).unwrap().into_utf8().unwrap(),
+            input
+        );
+    }
+
+    #[test]
+    fn test1() {
+        let input = [0xffu8, 0xfe];
+        let output = "__4";
+        let dec = Decoder::new().with_padding(Padding::Indifferent);
+
+        assert_eq!(dec.apply(output, BASE64URL).unwrap().into_bytes(), input);
+    }
+
+    #[test]
+    fn test2() {
+        let input = "fo";
+        let output = "Zm8=";
+        let dec = Decoder::new().with_padding(Padding::Indifferent);
+
+        assert_eq!(
+            dec.apply(output, BASE64).unwrap().into_utf8().unwrap(),
+            input
+        );
+    }
+
+    #[test]
+    fn test3() {
+        let input = "foob";
+        let output = "MZXW6YQ";
+        let dec = Decoder::new().with_padding(Padding::Omitted);
+
+        assert_eq!(
+            dec.apply(output, BASE32).unwrap().into_utf8().unwrap(),
+            input
+        );
+    }
+
+    #[test]
+    fn test4() {
+        let input = "f";
+        let output = "CO";
+        let dec = Decoder::new().with_padding(Padding::Indifferent);
+
+        assert_eq!(
+            dec.apply(output, BASE32HEX).unwrap().into_utf8().unwrap(),
+            input
+        );
+    }
+
+    #[test]
+    fn test5() {
+        let output = "Zm8=";
+        let dec = Decoder::new().with_padding(Padding::Omitted);
+
+        assert_eq!(
+            dec.apply(output, BASE64).unwrap_err(),
+            DecodeError::InvalidPadding {
+                pads: 1,
+                base: BASE64
+            }
+        );
+    }
+
+    #[test]
+    fn test6() {
+        let output = "Zm8";
+
+        assert_eq!(
+            Decoder::decode(output, BASE64).unwrap_err(),
+            DecodeError::InvalidLen {
+                len: 3,
+                base: BASE64
+            }
+        );
+    }
+
+    #[test]
+    fn test7() {
+        let output = "Zm8vY";
+        let dec = Decoder::new().with_padding(Padding::Indifferent);
+
+        assert_eq!(
+            dec.apply(output, BASE64URL).unwrap_err(),
+            DecodeError::InvalidLen {
+                len: 5,
+                base: BASE64
+            }
+        );
+    }
+
+    #[test]
+    fn test8() {
+        // the last char of an unpadded input follows the same rules as a padded one
+        let output = "Zh";
+        let dec = Decoder::new().with_padding(Padding::Omitted);
+
+        assert_eq!(
+            dec.apply(output, BASE64).unwrap_err(),
+            DecodeError::InvalidLastCharForPadding {
+                char: 'h',
+                idx: 33,
+                pads: 2
+            }
+        );
+    }
+
+    #[test]
+    fn test9() {
+        // padded input still has to be made of whole quanta
+        let output = "Zg=";
+        let dec = Decoder::new().with_padding(Padding::Indifferent);
+
+        assert_eq!(
+            dec.apply(output, BASE64).unwrap_err(),
+            DecodeError::InvalidLen {
+                len: 3,
+                base: BASE64
+            }
+        );
+    }
+
+    #[test]
+    fn test10() {
+        // inputs made only of the pad char of a base, which used to underflow
+        [
+            (BASE64, '='),
+            (BASE64URL, '='),
+            (BASE45, '='),
+            (BASE32, '='),
+            (BASE32HEX, '='),
+            (BASE16, '='),
+            (BASE58, '='),
+            (BASE62, '='),
+            (BASE36, '='),
+            (ZBASE32, '='),
+            (BASE32CROCKFORD, '#'),
+            (BASE85, 'v'),
+            (Z85, '~'),
+            (BASE85RFC, '.'),
+        ]
+        .into_iter()
+        .for_each(|(base, pad)| {
+            (1..=10).for_each(|len| {
+                let input = pad.to_string().repeat(len);
+                assert!(
+                    Decoder::decode(&input, base).is_err(),
+                    "{} {:?}",
+                    base,
+                    input
+                );
+                assert!(
+                    Decoder::new()
+                        .with_skip_whitespace(true)
+                        .apply(&input, base)
+                        .is_err()
+                );
+            });
+        });
+
+        assert!(Bases::default().deduce_encoding("=").is_err());
+        assert!(Bases::deduce_default("====").is_err());
+    }
+}
@@ -0,0 +1,146 @@
+mod encoder {
+    use makura::LineEnding;
+    use makura::quoted_printable::{MAX_LINE_LEN, encode};
+
+    #[test]
+    fn test0() {
+        assert_eq!(encode("", LineEnding::CrLf), "");
+        assert_eq!(
+            encode("café = coffee\r\n", LineEnding::CrLf),
+            "caf=C3=A9 =3D coffee\r\n"
+        );
+        assert_eq!(encode("tab\tend\t\n", LineEnding::Lf), "tab\tend=09\n");
+        assert_eq!(encode("end ", LineEnding::Lf), "end=20");
+    }
+
+    #[test]
+    fn test1() {
+        // line breaks other than ending are escaped
+        assert_eq!(encode("a\r\nb\nc\r", LineEnding::Lf), "a=0D\nb\nc=0D");
+        assert_eq!(encode("a\r\nb\nc\r", LineEnding::CrLf), "a\r\nb=0Ac=0D");
+    }
+
+    #[test]
+    fn test2() {
+        // 75 chars then the soft line break's '='
+        let encoded = encode("a".repeat(80), LineEnding::CrLf);
+        assert_eq!(
+            encoded,
+            ["a".repeat(75), "=\r\n".into(), "a".repeat(5)].concat()
+        );
+
+        // a line ending right at the limit needs no soft line break
+        let encoded = encode("a".repeat(MAX_LINE_LEN), LineEnding::CrLf);
+        assert_eq!(encoded, "a".repeat(MAX_LINE_LEN));
+    }
+
+    #[test]
+    fn test3() {
+        // escapes are never split
+        let encoded = encode(["a".repeat(74), "é".into()].concat(), LineEnding::Lf);
+        assert_eq!(encoded, ["a".repeat(74), "=\n=C3=A9".into()].concat());
+
+        // a trailing space that no longer fits
+        let encoded = encode(["x".repeat(74), " \n".into()].concat(), LineEnding::Lf);
+        assert_eq!(encoded, ["x".repeat(74), "=\n=20\n".into()].concat());
+    }
+}
+
+mod decoder {
+    use makura::quoted_printable::{MAX_LINE_LEN, Mode, QpError, decode};
+
+    #[test]
+    fn test0() {
+        [Mode::Strict, Mode::Lenient].into_iter().for_each(|mode| {
+            assert_eq!(decode("", mode).unwrap(), b"");
+            assert_eq!(
+                decode("caf=C3=A9 =3D coffee\r\n", mode).unwrap(),
+                "café = coffee\r\n".as_bytes()
+            );
+            assert_eq!(
+                decode("soft =\r\nbreak\r\n", mode).unwrap(),
+                b"soft break\r\n"
+            );
+            assert_eq!(decode("a=0D\nb\nc=0D", mode).unwrap(), b"a\r\nb\nc\r");
+        });
+    }
+
+    #[test]
+    fn test1() {
+        [
+            ("caf=c3=a9", QpError::InvalidEscape { line: 1 }),
+            ("a\r\n1 = 2\r\n", QpError::InvalidEscape { line: 2 }),
+            ("ends with =A", QpError::InvalidEscape { line: 1 }),
+            ("trailing \r\n", QpError::TrailingWhitespace { line: 1 }),
+            ("soft = \r\nbreak", QpError::TrailingWhitespace { line: 1 }),
+            (
+                "caf\u{e9}",
+                QpError::InvalidChar {
+                    char: '\u{c3}',
+                    line: 1,
+                },
+            ),
+            (
+                "bell\x07",
+                QpError::InvalidChar {
+                    char: '\x07',
+                    line: 1,
+                },
+            ),
+        ]
+        .into_iter()
+        .for_each(|(input, err)| {
+            assert_eq!(decode(input, Mode::Strict), Err(err));
+        });
+
+        let long = "a".repeat(MAX_LINE_LEN + 1);
+        assert_eq!(
+            decode(&long, Mode::Strict),
+            Err(QpError::LineTooLong { line: 1, len: 77 })
+        );
+    }
+
+    #[test]
+    fn test2() {
+        [
+            ("caf=c3=a9", "café"),
+            ("a\r\n1 = 2\r\n", "a\r\n1 = 2\r\n"),
+            ("ends with =A", "ends with =A"),
+            ("trailing \t \r\n", "trailing\r\n"),
+            ("soft = \r\nbreak", "soft break"),
+            ("café\x07", "café\x07"),
+        ]
+        .into_iter()
+        .for_each(|(input, output)| {
+            assert_eq!(decode(input, Mode::Lenient).unwrap(), output.as_bytes());
+        });
+
+        let long = "a".repeat(MAX_LINE_LEN + 1);
+        assert_eq!(decode(&long, Mode::Lenient).unwrap(), long.as_bytes());
+    }
+}
+
+mod properties {
+    use makura::LineEnding;
+    use makura::quoted_printable::{MAX_LINE_LEN, Mode, decode, encode};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn round_trip(input in any::<Vec<u8>>(), crlf in any::<bool>()) {
+            let ending = if crlf { LineEnding::CrLf } else { LineEnding::Lf };
+            let encoded = encode(&input, ending);
+
+            prop_assert!(encoded.lines().all(|line| line.len() <= MAX_LINE_LEN));
+            prop_assert_eq!(&decode(&encoded, Mode::Strict).unwrap(), &input);
+            prop_assert_eq!(decode(&encoded, Mode::Lenient).unwrap(), input);
+        }
+
+        #[test]
+        fn text_round_trip(input in "[ -~\t\n]{0,300}") {
+            let encoded = encode(&input, LineEnding::Lf);
+
+            prop_assert_eq!(decode(&encoded, Mode::Strict).unwrap(), input.as_bytes());
+        }
+    }
+}
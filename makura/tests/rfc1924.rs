@@ -0,0 +1,122 @@
+mod encoder {
+    use makura::Encoder;
+
+    #[test]
+    fn test0() {
+        let input = "hello";
+        let output = "Xk~0{Zv";
+        let enc = Encoder::base85_rfc();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test1() {
+        let input = "Man is distinguished";
+        let output = "O<`^zX>%ZCX>)XGZfA9Ab7*B`";
+        let enc = Encoder::base85_rfc();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test2() {
+        // no 'z' abbreviation
+        let input = [0, 0, 0, 0, 0xff, 0xff, 0xff, 0xff];
+        let output = "00000|NsC0";
+        let enc = Encoder::base85_rfc();
+
+        assert_eq!(enc.encode_bytes(input), output);
+    }
+}
+
+mod decoder {
+    use makura::{BASE85RFC, DecodeError, Decoder};
+
+    #[test]
+    fn test0() {
+        let input = "Xk~0{Zv";
+
+        assert_eq!(
+            Decoder::decode(input, BASE85RFC).unwrap().as_bytes(),
+            b"hello"
+        );
+    }
+
+    #[test]
+    fn test1() {
+        // '<~' is not an ascii85 delimiter here
+        let input = "<~00";
+
+        assert_eq!(
+            Decoder::decode(input, BASE85RFC).unwrap().into_bytes(),
+            [0xe6, 0x34, 0xfc]
+        );
+    }
+
+    #[test]
+    fn test2() {
+        let input = "Xk~0{Z";
+
+        assert_eq!(
+            Decoder::decode(input, BASE85RFC).unwrap_err(),
+            DecodeError::InvalidLen {
+                len: 6,
+                base: BASE85RFC
+            }
+        );
+    }
+
+    #[test]
+    fn test3() {
+        let input = "Xk~0:";
+
+        assert_eq!(
+            Decoder::decode(input, BASE85RFC).unwrap_err(),
+            DecodeError::InvalidChar {
+                char: ':',
+                base: BASE85RFC
+            }
+        );
+    }
+
+    #[test]
+    fn test4() {
+        // bigger than u32::MAX
+        let input = "|NsC1";
+
+        assert_eq!(
+            Decoder::decode(input, BASE85RFC).unwrap_err(),
+            DecodeError::Overflow { base: BASE85RFC }
+        );
+    }
+
+    #[test]
+    fn test5() {
+        // the pad of a non paddable base is an invalid char, wherever it is
+        ["00.00", "0000."].into_iter().for_each(|input| {
+            assert_eq!(
+                Decoder::decode(input, BASE85RFC).unwrap_err(),
+                DecodeError::InvalidChar {
+                    char: '.',
+                    base: BASE85RFC
+                }
+            );
+        });
+    }
+}
+
+mod properties {
+    use makura::{BASE85RFC, Decoder, Encoder};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn round_trip(input in proptest::collection::vec(any::<u8>(), 0..128)) {
+            let encoded = Encoder::base85_rfc().encode_bytes(&input);
+
+            prop_assert_eq!(encoded.len(), Encoder::base85_rfc().encoded_len(input.len()));
+            prop_assert_eq!(Decoder::decode(&encoded, BASE85RFC).unwrap().into_bytes(), input);
+        }
+    }
+}
@@ -0,0 +1,150 @@
+mod encoder {
+    use makura::{EncodeError, Encoder, Padding};
+
+    #[test]
+    fn test0() {
+        let input = b"foobar";
+        let output = b"Zm9vYmFy";
+        let mut out = [0u8; 8];
+
+        assert_eq!(Encoder::base64().encode_to_slice(input, &mut out), Ok(8));
+        assert_eq!(&out, output);
+    }
+
+    #[test]
+    fn test1() {
+        let input = b"fooba";
+        let output = b"Zm9vYmE";
+        let mut out = [0u8; 16];
+        let enc = Encoder::base64().with_padding(Padding::Omitted);
+        let len = enc.encode_to_slice(input, &mut out).unwrap();
+
+        assert_eq!(&out[..len], output);
+        // the rest of out is left untouched
+        assert!(out[len..].iter().all(|b| *b == 0));
+    }
+
+    #[test]
+    fn test2() {
+        let input = b"Hello!!";
+        let output = b"%69 VD92EX0";
+        let mut out = [0u8; 11];
+
+        assert_eq!(Encoder::base45().encode_to_slice(input, &mut out), Ok(11));
+        assert_eq!(&out, output);
+    }
+
+    #[test]
+    fn test3() {
+        let mut out = [0u8; 4];
+
+        assert_eq!(
+            Encoder::base32().encode_to_slice(b"f", &mut out),
+            Err(EncodeError::OutputTooSmall {
+                len: 4,
+                required: 8
+            })
+        );
+        assert_eq!(out, [0; 4]);
+    }
+
+    #[test]
+    fn test4() {
+        let enc = Encoder::base32();
+
+        assert_eq!(enc.encoded_len(0), 0);
+        assert_eq!(enc.encoded_len(1), 8);
+        assert_eq!(enc.with_padding(Padding::Omitted).encoded_len(1), 2);
+        assert_eq!(Encoder::base45().encoded_len(3), 5);
+        assert_eq!(Encoder::base16().encoded_len(3), 6);
+    }
+}
+
+mod decoder {
+    use makura::{BASE16, BASE32, BASE64URL, DecodeError, Decoder, Padding};
+
+    #[test]
+    fn test0() {
+        let input = "MZXW6YTBOI======";
+        let output = b"foobar";
+        let mut out = [0u8; 10];
+        let len = Decoder::new()
+            .decode_to_slice(input, BASE32, &mut out)
+            .unwrap();
+
+        assert_eq!(&out[..len], output);
+    }
+
+    #[test]
+    fn test1() {
+        let input = "__4";
+        let output = [0xff, 0xfe];
+        let mut out = [0u8; 2];
+        let dec = Decoder::new().with_padding(Padding::Omitted);
+
+        assert_eq!(dec.decode_to_slice(input, BASE64URL, &mut out), Ok(2));
+        assert_eq!(out, output);
+    }
+
+    #[test]
+    fn test2() {
+        let mut out = [0u8; 2];
+
+        assert_eq!(
+            Decoder::new().decode_to_slice("666F6F", BASE16, &mut out),
+            Err(DecodeError::OutputTooSmall {
+                len: 2,
+                required: 3
+            })
+        );
+        assert_eq!(out, [0; 2]);
+    }
+
+    #[test]
+    fn test3() {
+        let mut out = [0u8; 1];
+
+        assert_eq!(
+            Decoder::new().decode_to_slice("6G", BASE16, &mut out),
+            Err(DecodeError::InvalidChar {
+                char: 'G',
+                base: BASE16
+            })
+        );
+    }
+}
+
+mod properties {
+    use makura::{BASE16, BASE32, BASE32HEX, BASE45, BASE64, BASE64URL};
+    use makura::{Decoder, Encoder, Padding};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn slices_match_allocating_apis(input in proptest::collection::vec(any::<u8>(), 0..256)) {
+            [BASE64, BASE64URL, BASE45, BASE32, BASE32HEX, BASE16]
+                .into_iter()
+                .try_for_each(|base| {
+                    [Padding::Required, Padding::Omitted].into_iter().try_for_each(|padding| {
+                        let enc = Encoder::from(base).with_padding(padding);
+                        let encoded = enc.encode_bytes(&input);
+                        prop_assert_eq!(enc.encoded_len(input.len()), encoded.len());
+
+                        let mut out = vec![0u8; encoded.len()];
+                        prop_assert_eq!(enc.encode_to_slice(&input, &mut out), Ok(encoded.len()));
+                        prop_assert_eq!(&out, encoded.as_bytes());
+
+                        let dec = Decoder::new().with_padding(padding);
+                        let estimate = dec.decoded_len_estimate(encoded.len(), base);
+                        prop_assert!(estimate >= input.len());
+
+                        let mut out = vec![0u8; estimate];
+                        let len = dec.decode_to_slice(&encoded, base, &mut out).unwrap();
+                        prop_assert_eq!(&out[..len], input.as_slice());
+
+                        Ok(())
+                    })
+                })?;
+        }
+    }
+}
@@ -0,0 +1,474 @@
+mod writer {
+    use std::io::Write;
+
+    use makura::{Encoder, EncoderWriter, LineEnding, Padding};
+
+    #[test]
+    fn test0() {
+        let writer = EncoderWriter::new(Vec::new(), Encoder::base64());
+
+        assert_eq!(writer.finish().unwrap(), b"");
+    }
+
+    #[test]
+    fn test1() {
+        let mut writer = EncoderWriter::new(Vec::new(), Encoder::base64());
+        "foobar"
+            .bytes()
+            .for_each(|b| writer.write_all(&[b]).unwrap());
+
+        assert_eq!(writer.finish().unwrap(), b"Zm9vYmFy");
+    }
+
+    #[test]
+    fn test2() {
+        let mut writer = EncoderWriter::new(Vec::new(), Encoder::base32());
+        writer.write_all(b"foo").unwrap();
+        // only the partial quantum is pending, nothing was written yet
+        assert_eq!(writer.get_ref(), b"");
+        writer.write_all(b"bar").unwrap();
+        assert_eq!(writer.get_ref(), b"MZXW6YTB");
+
+        assert_eq!(writer.finish().unwrap(), b"MZXW6YTBOI======");
+    }
+
+    #[test]
+    fn test3() {
+        let mut output = Vec::new();
+        {
+            let mut writer = EncoderWriter::new(&mut output, Encoder::base45());
+            writer.write_all(b"Hello!!").unwrap();
+        }
+
+        // the last partial quantum is written on drop
+        assert_eq!(output, b"%69 VD92EX0");
+    }
+
+    #[test]
+    fn test4() {
+        let enc = Encoder::base64_url().with_padding(Padding::Omitted);
+        let mut writer = EncoderWriter::new(Vec::new(), enc);
+        writer.write_all(&[0xff, 0xfe]).unwrap();
+
+        assert_eq!(writer.finish().unwrap(), b"__4");
+    }
+
+    #[test]
+    fn test6() {
+        // the lines don't follow the writes
+        let enc = Encoder::base64().with_wrap(6, LineEnding::CrLf);
+        let mut writer = EncoderWriter::new(Vec::new(), enc);
+        [&b"fo"[..], b"ob", b"arbaz", b"!"]
+            .into_iter()
+            .for_each(|b| writer.write_all(b).unwrap());
+
+        assert_eq!(writer.finish().unwrap(), b"Zm9vYm\r\nFyYmF6\r\nIQ==");
+    }
+
+    #[test]
+    fn test5() {
+        // bigger than a single inner write
+        let input = (0..10_000u32).map(|i| i as u8).collect::<Vec<u8>>();
+        let enc = Encoder::base16();
+        let output = enc.encode_bytes(&input);
+        let mut writer = EncoderWriter::new(Vec::new(), enc);
+        writer.write_all(&input).unwrap();
+
+        assert_eq!(writer.finish().unwrap(), output.as_bytes());
+    }
+
+    #[test]
+    fn test7() {
+        // base58 has no quanta, everything is written on finish
+        let mut writer = EncoderWriter::new(Vec::new(), Encoder::base58());
+        writer.write_all(b"Hello ").unwrap();
+        writer.write_all(b"World!").unwrap();
+        assert_eq!(writer.get_ref(), b"");
+
+        assert_eq!(writer.finish().unwrap(), b"2NEpo7TZRRrLZSi2U");
+    }
+
+    #[test]
+    fn test8() {
+        let mut writer = EncoderWriter::new(Vec::new(), Encoder::z85());
+        writer.write_all(&[0x86, 0x4f, 0xd2]).unwrap();
+        assert_eq!(writer.get_ref(), b"");
+        writer.write_all(&[0x6f, 0xb5, 0x59, 0xf7, 0x5b]).unwrap();
+        assert_eq!(writer.finish().unwrap(), b"HelloWorld");
+
+        // z85 has no partial quanta
+        let mut writer = EncoderWriter::new(Vec::new(), Encoder::z85());
+        writer.write_all(b"hello").unwrap();
+        let error = writer.finish().unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    #[cfg(feature = "base32_crockford")]
+    fn test9() {
+        // a single check symbol for the whole stream, whatever the writes are
+        let enc = Encoder::base32_crockford().with_check_symbol(true);
+        let mut writer = EncoderWriter::new(Vec::new(), enc);
+        writer.write_all(&[7; 5]).unwrap();
+        writer.write_all(&[7; 5]).unwrap();
+
+        assert_eq!(writer.finish().unwrap(), b"0W3GE1R70W3GE1R77");
+        assert_eq!(enc.encode_bytes([7; 10]), "0W3GE1R70W3GE1R77");
+
+        let input = b"Hello, World!";
+        (1..=input.len()).for_each(|chunk| {
+            let enc = enc.with_wrap(4, LineEnding::Lf);
+            let mut writer = EncoderWriter::new(Vec::new(), enc);
+            input
+                .chunks(chunk)
+                .for_each(|c| writer.write_all(c).unwrap());
+
+            assert_eq!(
+                writer.finish().unwrap(),
+                enc.encode_bytes(input).into_bytes()
+            );
+        });
+
+        let writer = EncoderWriter::new(Vec::new(), enc);
+        assert_eq!(writer.finish().unwrap(), enc.encode_bytes([]).into_bytes());
+    }
+
+    #[test]
+    fn test10() {
+        // ascii85 is streamed one quantum at a time, only the partial one is pending
+        let enc = Encoder::ascii85().with_delimiters(true);
+        let mut writer = EncoderWriter::new(Vec::new(), enc);
+        writer.write_all(&[b'a'; 4001]).unwrap();
+        let written = format!("<~{}", Encoder::ascii85().encode_bytes([b'a'; 4000]));
+        assert_eq!(writer.get_ref(), written.as_bytes());
+        assert_eq!(
+            writer.finish().unwrap(),
+            enc.encode_bytes([b'a'; 4001]).into_bytes()
+        );
+
+        // an empty input is still delimited
+        let writer = EncoderWriter::new(Vec::new(), enc);
+        assert_eq!(writer.finish().unwrap(), b"<~~>");
+        assert_eq!(enc.encode_bytes([]), "<~~>");
+
+        let input = b"\0\0\0\0    Hello, World!\0\0\0\0";
+        let enc = enc.with_fold_spaces(true).with_wrap(7, LineEnding::CrLf);
+        (1..=input.len()).for_each(|chunk| {
+            let mut writer = EncoderWriter::new(Vec::new(), enc);
+            input
+                .chunks(chunk)
+                .for_each(|c| writer.write_all(c).unwrap());
+
+            assert_eq!(
+                writer.finish().unwrap(),
+                enc.encode_bytes(input).into_bytes()
+            );
+        });
+    }
+}
+
+mod reader {
+    use std::io::{self, Read};
+
+    use makura::StreamDecodeError;
+    use makura::{BASE32, BASE45, BASE58, BASE64, BASE64URL, BASE85, Z85};
+    use makura::{DecodeError, Decoder, DecoderReader, Encoder, LineEnding, Padding};
+
+    // reads at most n bytes per inner read
+    struct Trickle<'a>(&'a [u8], usize);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let len = self.0.len().min(self.1).min(buf.len());
+            buf[..len].copy_from_slice(&self.0[..len]);
+            self.0 = &self.0[len..];
+
+            Ok(len)
+        }
+    }
+
+    fn stream_error(e: io::Error) -> StreamDecodeError {
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+
+        e.into_inner()
+            .unwrap()
+            .downcast::<StreamDecodeError>()
+            .map(|e| *e)
+            .unwrap()
+    }
+
+    #[test]
+    fn test0() {
+        let mut reader = DecoderReader::new(&b""[..], BASE64, Decoder::new());
+        let mut output = Vec::new();
+        reader.read_to_end(&mut output).unwrap();
+
+        assert_eq!(output, b"");
+    }
+
+    #[test]
+    fn test1() {
+        let input = b"MZXW6YTBOI======";
+        let mut reader = DecoderReader::new(Trickle(input, 1), BASE32, Decoder::new());
+        let mut output = Vec::new();
+        reader.read_to_end(&mut output).unwrap();
+
+        assert_eq!(output, b"foobar");
+    }
+
+    #[test]
+    fn test2() {
+        let input = b"%69 VD92EX0";
+        let mut reader = DecoderReader::new(Trickle(input, 2), BASE45, Decoder::new());
+        let mut output = Vec::new();
+        reader.read_to_end(&mut output).unwrap();
+
+        assert_eq!(output, b"Hello!!");
+    }
+
+    #[test]
+    fn test3() {
+        let dec = Decoder::new().with_padding(Padding::Omitted);
+        let mut reader = DecoderReader::new(&b"__4"[..], BASE64URL, dec);
+        let mut output = Vec::new();
+        reader.read_to_end(&mut output).unwrap();
+
+        assert_eq!(output, [0xff, 0xfe]);
+    }
+
+    #[test]
+    fn test4() {
+        // the bad char is far past the first inner read
+        let mut input = "Zm9v".repeat(2000).into_bytes();
+        input[6001] = b'*';
+        let mut reader = DecoderReader::new(&input[..], BASE64, Decoder::new());
+        let mut output = Vec::new();
+        let error = stream_error(reader.read_to_end(&mut output).unwrap_err());
+
+        assert_eq!(error.offset(), 6001);
+        assert!(matches!(
+            error.error(),
+            DecodeError::InvalidChar { char: '*', .. }
+        ));
+        // the reader keeps failing
+        let error = stream_error(reader.read(&mut [0; 8]).unwrap_err());
+        assert_eq!(error.offset(), 6001);
+    }
+
+    #[test]
+    fn test5() {
+        // padding in the middle of the stream
+        let mut reader = DecoderReader::new(&b"Zm8=Zm9v"[..], BASE64, Decoder::new());
+        let error = stream_error(reader.read_to_end(&mut Vec::new()).unwrap_err());
+
+        assert_eq!(error.offset(), 3);
+    }
+
+    #[test]
+    fn test6() {
+        // 'x' can not be followed by padding
+        let mut reader = DecoderReader::new(&b"Zm9vYx=="[..], BASE64, Decoder::new());
+        let error = stream_error(reader.read_to_end(&mut Vec::new()).unwrap_err());
+
+        assert_eq!(error.offset(), 5);
+        assert!(matches!(
+            error.error(),
+            DecodeError::InvalidLastCharForPadding { .. }
+        ));
+    }
+
+    #[test]
+    fn test7() {
+        let mut reader = DecoderReader::new(&b"Zm9vY"[..], BASE64, Decoder::new());
+        let error = stream_error(reader.read_to_end(&mut Vec::new()).unwrap_err());
+
+        assert_eq!(error.offset(), 4);
+        assert!(matches!(error.error(), DecodeError::InvalidLen { .. }));
+    }
+
+    #[test]
+    fn test8() {
+        let input = b"Zm9v\r\nYmFy\r\nYmF6\r\n";
+        let dec = Decoder::new().with_skip_whitespace(true);
+        let mut reader = DecoderReader::new(Trickle(input, 3), BASE64, dec);
+        let mut output = Vec::new();
+        reader.read_to_end(&mut output).unwrap();
+
+        assert_eq!(output, b"foobarbaz");
+    }
+
+    #[test]
+    fn test9() {
+        // the offset is that of the encoded stream, skipped chars included
+        let input = b"Zm9v\nYm*y\nYmE=\n";
+        let dec = Decoder::new().with_skip_whitespace(true);
+        let mut reader = DecoderReader::new(&input[..], BASE64, dec);
+        let error = stream_error(reader.read_to_end(&mut Vec::new()).unwrap_err());
+
+        assert_eq!(error.offset(), 7);
+    }
+
+    #[test]
+    fn test10() {
+        let input = b"Zm9vYmE\n=\n";
+        let dec = Decoder::new().with_skip_whitespace(true);
+        let mut reader = DecoderReader::new(&input[..], BASE64, dec);
+        let mut output = Vec::new();
+        reader.read_to_end(&mut output).unwrap();
+
+        assert_eq!(output, b"fooba");
+    }
+
+    #[test]
+    fn test12() {
+        let input = b"HelloWorld";
+        let mut reader = DecoderReader::new(Trickle(input, 3), Z85, Decoder::new());
+        let mut output = Vec::new();
+        reader.read_to_end(&mut output).unwrap();
+
+        assert_eq!(output, [0x86, 0x4f, 0xd2, 0x6f, 0xb5, 0x59, 0xf7, 0x5b]);
+    }
+
+    #[test]
+    #[cfg(feature = "base32_crockford")]
+    fn test13() {
+        use makura::BASE32CROCKFORD;
+
+        // the hyphens are skipped, the check symbol is only verified at the end of the stream
+        let input = b"91jp-rv3f-g";
+        let dec = Decoder::new().with_check_symbol(true);
+        let mut reader = DecoderReader::new(Trickle(input, 2), BASE32CROCKFORD, dec);
+        let mut output = Vec::new();
+        reader.read_to_end(&mut output).unwrap();
+
+        assert_eq!(output, b"Hello");
+
+        let mut reader = DecoderReader::new(&b"91jp-rv3f-h"[..], BASE32CROCKFORD, dec);
+        let error = stream_error(reader.read_to_end(&mut Vec::new()).unwrap_err());
+        assert!(matches!(
+            error.error(),
+            DecodeError::ChecksumMismatch { .. }
+        ));
+    }
+
+    #[test]
+    fn test11() {
+        let input = b"2NEpo7TZRRrLZSi0U";
+        let mut reader = DecoderReader::new(Trickle(input, 3), BASE58, Decoder::new());
+        let error = stream_error(reader.read_to_end(&mut Vec::new()).unwrap_err());
+
+        assert_eq!(error.offset(), 15);
+        assert_eq!(
+            error.error(),
+            &DecodeError::InvalidChar {
+                char: '0',
+                base: BASE58
+            }
+        );
+    }
+
+    #[test]
+    fn test14() {
+        // ascii85 is decoded one quantum at a time, abbreviations and delimiters included
+        let input = b"\0\0\0\0    Hello, World!";
+        let enc = Encoder::ascii85()
+            .with_delimiters(true)
+            .with_fold_spaces(true)
+            .with_wrap(6, LineEnding::Lf);
+        let encoded = enc.encode_bytes(input);
+        let dec = Decoder::new().with_skip_whitespace(true);
+        (1..=encoded.len()).for_each(|chunk| {
+            let mut reader = DecoderReader::new(Trickle(encoded.as_bytes(), chunk), BASE85, dec);
+            let mut output = Vec::new();
+            reader.read_to_end(&mut output).unwrap();
+
+            assert_eq!(output, input);
+        });
+
+        // the whole quanta are readable before the stream is over
+        struct Broken;
+        impl Read for Broken {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::ErrorKind::BrokenPipe.into())
+            }
+        }
+        let encoded = Encoder::ascii85()
+            .with_delimiters(true)
+            .encode("Hello, World!");
+        let mut reader = DecoderReader::new(encoded.as_bytes().chain(Broken), BASE85, dec);
+        let mut output = [0; 12];
+        reader.read_exact(&mut output).unwrap();
+
+        assert_eq!(&output, b"Hello, World");
+        assert_eq!(
+            reader.read(&mut [0; 1]).unwrap_err().kind(),
+            io::ErrorKind::BrokenPipe
+        );
+    }
+}
+
+mod properties {
+    use std::io::{Read, Write};
+
+    use makura::{BASE16, BASE32, BASE32HEX, BASE45, BASE58, BASE64, BASE64URL, BASE85};
+    use makura::{Decoder, DecoderReader, Encoder, EncoderWriter, LineEnding};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn writer_matches_encoder(
+            input in proptest::collection::vec(any::<u8>(), 0..512),
+            chunk in 1usize..17,
+        ) {
+            [BASE64, BASE64URL, BASE45, BASE32, BASE32HEX, BASE16, BASE58, BASE85]
+                .into_iter()
+                .try_for_each(|base| {
+                    let output = Encoder::from(base).encode_bytes(&input);
+                    let mut writer = EncoderWriter::new(Vec::new(), Encoder::from(base));
+                    input.chunks(chunk).for_each(|c| writer.write_all(c).unwrap());
+
+                    prop_assert_eq!(writer.finish().unwrap(), output.into_bytes());
+
+                    Ok(())
+                })?;
+        }
+
+        #[test]
+        fn wrapped_writer_matches_encoder(
+            input in proptest::collection::vec(any::<u8>(), 0..512),
+            chunk in 1usize..17,
+            width in 1usize..80,
+        ) {
+            [BASE64, BASE32, BASE58]
+                .into_iter()
+                .try_for_each(|base| {
+                    let enc = Encoder::from(base).with_wrap(width, LineEnding::CrLf);
+                    let output = enc.encode_bytes(&input);
+                    let mut writer = EncoderWriter::new(Vec::new(), enc);
+                    input.chunks(chunk).for_each(|c| writer.write_all(c).unwrap());
+
+                    prop_assert_eq!(writer.finish().unwrap(), output.into_bytes());
+
+                    Ok(())
+                })?;
+        }
+
+        #[test]
+        fn reader_matches_decoder(
+            input in proptest::collection::vec(any::<u8>(), 0..512),
+        ) {
+            [BASE64, BASE64URL, BASE45, BASE32, BASE32HEX, BASE16, BASE58, BASE85]
+                .into_iter()
+                .try_for_each(|base| {
+                    let encoded = Encoder::from(base).encode_bytes(&input);
+                    let mut reader = DecoderReader::new(encoded.as_bytes(), base, Decoder::new());
+                    let mut output = Vec::new();
+                    reader.read_to_end(&mut output).unwrap();
+
+                    prop_assert_eq!(output, input.clone());
+
+                    Ok(())
+                })?;
+        }
+    }
+}
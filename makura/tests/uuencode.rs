@@ -0,0 +1,152 @@
+mod encoder {
+    use makura::LineEnding;
+    use makura::uuencode::{Dialect, UuFile};
+
+    #[test]
+    fn test0() {
+        let file = UuFile::new("cat.txt", 0o644, b"Cat".to_vec());
+
+        assert_eq!(
+            file.encode(Dialect::UuBackticks, LineEnding::Lf),
+            "begin 644 cat.txt\n#0V%T\n`\nend\n"
+        );
+        assert_eq!(
+            file.encode(Dialect::Uu, LineEnding::CrLf),
+            "begin 644 cat.txt\r\n#0V%T\r\n \r\nend\r\n"
+        );
+    }
+
+    #[test]
+    fn test1() {
+        // 0 values are spaces or backticks
+        let file = UuFile::new("a", 0o600, vec![0xf3, 0xdf]);
+
+        assert_eq!(
+            file.encode(Dialect::UuBackticks, LineEnding::Lf),
+            "begin 600 a\n\"\\]\\`\n`\nend\n"
+        );
+        assert_eq!(
+            file.encode(Dialect::Uu, LineEnding::Lf),
+            "begin 600 a\n\"\\]\\ \n \nend\n"
+        );
+    }
+
+    #[test]
+    fn test2() {
+        let file = UuFile::new("hello.txt", 0o644, b"hello world\n".to_vec());
+
+        assert_eq!(
+            file.encode(Dialect::UuBackticks, LineEnding::Lf),
+            "begin 644 hello.txt\n,:&5L;&\\@=V]R;&0*\n`\nend\n"
+        );
+        assert_eq!(
+            file.encode(Dialect::Xx, LineEnding::Lf),
+            "begin 644 hello.txt\nAO4JgP4wURqxmP4E8\n+\nend\n"
+        );
+    }
+
+    #[test]
+    fn test3() {
+        // lines of up to 45 bytes, 'M' long
+        let file = UuFile::new("bytes", 0o644, (0..60).collect());
+        let encoded = file.encode(Dialect::UuBackticks, LineEnding::Lf);
+        let lines = encoded.lines().collect::<Vec<&str>>();
+
+        assert_eq!(
+            lines[1],
+            "M``$\"`P0%!@<(\"0H+#`T.#Q`1$A,4%187&!D:&QP='A\\@(2(C)\"4F)R@I*BLL"
+        );
+        assert_eq!(lines[2], "/+2XO,#$R,S0U-C<X.3H[");
+        assert_eq!(lines.len(), 5);
+    }
+}
+
+mod decoder {
+    use makura::uuencode::{Dialect, UuError, UuFile};
+
+    #[test]
+    fn test0() {
+        let input = "some mail text\n\nbegin 644 cat.txt\n#0V%T\n`\nend\n-- \nsignature\n";
+        let file = UuFile::parse(input, Dialect::UuBackticks).unwrap();
+
+        assert_eq!(file.name(), "cat.txt");
+        assert_eq!(file.mode(), 0o644);
+        assert_eq!(file.data(), b"Cat");
+    }
+
+    #[test]
+    fn test1() {
+        // spaces and backticks are both 0s, the lone space line may have been stripped
+        let input = "begin 600 my file\r\n\"\\]\\ \r\n\r\nend\r\n";
+        let file = UuFile::parse(input, Dialect::Uu).unwrap();
+
+        assert_eq!(file.name(), "my file");
+        assert_eq!(file.mode(), 0o600);
+        assert_eq!(file.data(), [0xf3, 0xdf]);
+        assert_eq!(
+            UuFile::parse("begin 600 a\n\"\\]\\`\n`\nend\n", Dialect::Uu)
+                .unwrap()
+                .data(),
+            [0xf3, 0xdf]
+        );
+    }
+
+    #[test]
+    fn test2() {
+        let input = "begin 644 hello.txt\nAO4JgP4wURqxmP4E8\n+\nend\n";
+        let file = UuFile::parse(input, Dialect::Xx).unwrap();
+
+        assert_eq!(file.data(), b"hello world\n");
+    }
+
+    #[test]
+    fn test3() {
+        [
+            ("no file here\n", UuError::MissingBegin),
+            (
+                "begin 8x4 cat.txt\n",
+                UuError::InvalidBegin("begin 8x4 cat.txt".into()),
+            ),
+            ("begin 644\n", UuError::InvalidBegin("begin 644".into())),
+            ("begin 644 cat.txt\n#0V%T\n", UuError::MissingEnd),
+            ("begin 644 cat.txt\n#0V%T\n`\n", UuError::MissingEnd),
+            (
+                "begin 644 cat.txt\n#0V%\n`\nend\n",
+                UuError::InvalidLen { line: 2 },
+            ),
+            (
+                "begin 644 cat.txt\n#0v%T\n`\nend\n",
+                UuError::InvalidChar { char: 'v', line: 2 },
+            ),
+        ]
+        .into_iter()
+        .for_each(|(input, err)| {
+            assert_eq!(UuFile::parse(input, Dialect::UuBackticks), Err(err));
+        });
+    }
+
+    #[test]
+    fn test4() {
+        // '=' is the pad of the xxencode alphabet, never one of its chars
+        let input = "begin 644 cat.txt\n1====\n+\nend\n";
+
+        assert!(UuFile::parse(input, Dialect::Xx).is_err());
+    }
+}
+
+mod properties {
+    use makura::LineEnding;
+    use makura::uuencode::{Dialect, UuFile};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn round_trip(input in any::<Vec<u8>>(), dialect in 0..3) {
+            let dialect = [Dialect::Uu, Dialect::UuBackticks, Dialect::Xx][dialect as usize];
+            let file = UuFile::new("file.bin", 0o644, input);
+            let encoded = file.encode(dialect, LineEnding::Lf);
+
+            prop_assert_eq!(UuFile::parse(encoded, dialect).unwrap(), file);
+        }
+    }
+}
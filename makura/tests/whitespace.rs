@@ -0,0 +1,145 @@
+mod decoder {
+    use makura::{BASE32, BASE45, BASE64, DecodeError, Decoder, Padding};
+
+    #[test]
+    fn test0() {
+        let input = "Zm9vYmFy\r\nYmF6\r\n";
+        let output = "foobarbaz";
+        let dec = Decoder::new().with_skip_whitespace(true);
+
+        assert_eq!(dec.apply(input, BASE64).unwrap().as_utf8().unwrap(), output);
+    }
+
+    #[test]
+    fn test1() {
+        // padding after a line break
+        let input = "MZXW6YTBOI\n======\n";
+        let output = "foobar";
+        let dec = Decoder::new().with_skip_whitespace(true);
+
+        assert_eq!(dec.apply(input, BASE32).unwrap().as_utf8().unwrap(), output);
+    }
+
+    #[test]
+    fn test2() {
+        // the len is checked on the non whitespace chars
+        let input = "Zm9 vYmF\tyYmF";
+        let dec = Decoder::new().with_skip_whitespace(true);
+
+        assert_eq!(
+            dec.apply(input, BASE64).unwrap_err(),
+            DecodeError::InvalidLen {
+                len: 11,
+                base: BASE64
+            }
+        );
+    }
+
+    #[test]
+    fn test3() {
+        let input = "Zm9v\nYmE";
+        let output = "fooba";
+        let dec = Decoder::new()
+            .with_skip_whitespace(true)
+            .with_padding(Padding::Omitted);
+
+        assert_eq!(dec.apply(input, BASE64).unwrap().as_utf8().unwrap(), output);
+    }
+
+    #[test]
+    fn test4() {
+        // base45 spaces are part of the alphabet, only the line break is skipped
+        let input = "%69 VD92EX0\n";
+        let output = "Hello!!";
+        let dec = Decoder::new().with_skip_whitespace(true);
+
+        assert_eq!(dec.apply(input, BASE45).unwrap().as_utf8().unwrap(), output);
+    }
+
+    #[test]
+    fn test5() {
+        let dec = Decoder::new().with_skip_whitespace(true);
+
+        assert_eq!(dec.apply(" \r\n\t", BASE64).unwrap().as_bytes(), b"");
+    }
+
+    #[test]
+    fn test6() {
+        // whitespace is still invalid by default
+        assert_eq!(
+            Decoder::decode("Zm9v\nYmF", BASE64).unwrap_err(),
+            DecodeError::InvalidChar {
+                char: '\n',
+                base: BASE64
+            }
+        );
+    }
+
+    #[test]
+    fn test7() {
+        let input = "Zm9v\r\nYmE=";
+        let mut out = [0u8; 5];
+        let dec = Decoder::new().with_skip_whitespace(true);
+
+        assert_eq!(dec.decode_to_slice(input, BASE64, &mut out), Ok(5));
+        assert_eq!(&out, b"fooba");
+    }
+}
+
+mod deducer {
+    use makura::{BASE32, BASE64, Bases};
+
+    #[test]
+    fn test0() {
+        let input = "MIIBIjANBgkqhkiG9w0BAQEFAAOCAQ8A\nMIIBCgKCAQEAu1SU1LfVLPHCozMxH2Mo\n";
+
+        assert_eq!(
+            Bases::default()
+                .with_skip_whitespace(true)
+                .deduce_sorted(input),
+            Ok(BASE64)
+        );
+    }
+
+    #[test]
+    fn test1() {
+        let input = "MZXW6YTB\r\nOI======\r\n";
+
+        assert_eq!(
+            Bases::default()
+                .with_skip_whitespace(true)
+                .deduce_encoding(input),
+            Ok(BASE32)
+        );
+    }
+}
+
+mod properties {
+    use makura::{BASE16, BASE32, BASE32HEX, BASE64, BASE64URL};
+    use makura::{Decoder, Encoder};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn wrapped_round_trip(
+            input in proptest::collection::vec(any::<u8>(), 0..256),
+            width in 1usize..80,
+        ) {
+            [BASE64, BASE64URL, BASE32, BASE32HEX, BASE16]
+                .into_iter()
+                .try_for_each(|base| {
+                    let encoded = Encoder::from(base).encode_bytes(&input);
+                    let wrapped = encoded
+                        .as_bytes()
+                        .chunks(width)
+                        .flat_map(|line| line.iter().copied().chain(*b"\r\n"))
+                        .collect::<Vec<u8>>();
+                    let dec = Decoder::new().with_skip_whitespace(true);
+
+                    prop_assert_eq!(dec.apply(&wrapped, base).unwrap().into_bytes(), input.clone());
+
+                    Ok(())
+                })?;
+        }
+    }
+}
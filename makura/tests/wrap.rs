@@ -0,0 +1,119 @@
+mod encoder {
+    use makura::{Encoder, LineEnding, Padding};
+
+    #[test]
+    fn test0() {
+        let input = [0u8; 60];
+        let output = format!("{}\r\n{}", "A".repeat(76), "A".repeat(4));
+        let enc = Encoder::base64().with_wrap(76, LineEnding::CrLf);
+
+        assert_eq!(enc.encode_bytes(input), output);
+    }
+
+    #[test]
+    fn test1() {
+        // no line ending after a full last line
+        let input = "foobar";
+        let output = "Zm9v\nYmFy";
+        let enc = Encoder::base64().with_wrap(4, LineEnding::Lf);
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test2() {
+        let input = "foobar";
+        let output = "MZXW6\nYTBOI\n=====\n=";
+        let enc = Encoder::base32().with_wrap(5, LineEnding::Lf);
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test3() {
+        let input = "foobar";
+        let output = "MZXW6YTB\r\nOI";
+        let enc = Encoder::base32()
+            .with_padding(Padding::Omitted)
+            .with_wrap(8, LineEnding::CrLf);
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test4() {
+        let enc = Encoder::base64().with_wrap(64, LineEnding::Lf);
+
+        assert_eq!(enc.encode(""), "");
+        assert_eq!(enc.encoded_len(48), 64);
+        assert_eq!(enc.encoded_len(49), 69);
+    }
+
+    #[test]
+    fn test5() {
+        let input = b"foobar";
+        let output = b"Zm9\r\nvYm\r\nFy";
+        let mut out = [0u8; 12];
+        let enc = Encoder::base64().with_wrap(3, LineEnding::CrLf);
+
+        assert_eq!(enc.encode_to_slice(input, &mut out), Ok(12));
+        assert_eq!(&out, output);
+    }
+
+    #[test]
+    fn test6() {
+        // a 0 width doesn't wrap
+        let enc = Encoder::base64().with_wrap(0, LineEnding::Lf);
+
+        assert_eq!(enc.wrap(), None);
+        assert_eq!(enc.encode("foobar"), "Zm9vYmFy");
+    }
+}
+
+mod properties {
+    use makura::{BASE16, BASE32, BASE32HEX, BASE45, BASE64, BASE64URL};
+    use makura::{Decoder, Encoder, LineEnding};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn wrapped_lines(
+            input in proptest::collection::vec(any::<u8>(), 0..256),
+            width in 1usize..80,
+        ) {
+            [BASE64, BASE64URL, BASE45, BASE32, BASE32HEX, BASE16]
+                .into_iter()
+                .try_for_each(|base| {
+                    let encoded = Encoder::from(base).encode_bytes(&input);
+                    let enc = Encoder::from(base).with_wrap(width, LineEnding::CrLf);
+                    let wrapped = enc.encode_bytes(&input);
+
+                    prop_assert_eq!(enc.encoded_len(input.len()), wrapped.len());
+                    let lines = wrapped.split("\r\n").collect::<Vec<&str>>();
+                    prop_assert!(lines.iter().all(|l| l.len() <= width));
+                    prop_assert_eq!(lines.concat(), encoded);
+
+                    Ok(())
+                })?;
+        }
+
+        #[test]
+        fn wrapped_round_trip(
+            input in proptest::collection::vec(any::<u8>(), 0..256),
+            width in 1usize..80,
+        ) {
+            [BASE64, BASE64URL, BASE32, BASE32HEX, BASE16]
+                .into_iter()
+                .try_for_each(|base| {
+                    let wrapped = Encoder::from(base)
+                        .with_wrap(width, LineEnding::Lf)
+                        .encode_bytes(&input);
+                    let dec = Decoder::new().with_skip_whitespace(true);
+
+                    prop_assert_eq!(dec.apply(&wrapped, base).unwrap().into_bytes(), input.clone());
+
+                    Ok(())
+                })?;
+        }
+    }
+}
@@ -0,0 +1,199 @@
+mod encoder {
+    use makura::{EncodeError, Encoder, Z85};
+
+    #[test]
+    fn test0() {
+        // the ZeroMQ RFC 32 test vector
+        let input = [0x86, 0x4f, 0xd2, 0x6f, 0xb5, 0x59, 0xf7, 0x5b];
+        let output = "HelloWorld";
+        let enc = Encoder::z85();
+
+        assert_eq!(enc.encode_bytes(input), output);
+    }
+
+    #[test]
+    fn test1() {
+        let input = (0..32).collect::<Vec<u8>>();
+        let output = "009c61o!#m2NH?C3>iWS5d]J*6CRx17-skh9337x";
+        let enc = Encoder::z85();
+
+        assert_eq!(enc.encode_bytes(input), output);
+    }
+
+    #[test]
+    fn test2() {
+        // no 'z' abbreviation
+        let input = [0, 0, 0, 0, 0xff, 0xff, 0xff, 0xff];
+        let output = "00000%nSc0";
+        let enc = Encoder::z85();
+
+        assert_eq!(enc.encode_bytes(input), output);
+    }
+
+    #[test]
+    fn test3() {
+        let input = b"hello";
+        let mut out = [0u8; 16];
+
+        assert_eq!(
+            Encoder::z85().encode_to_slice(input, &mut out),
+            Err(EncodeError::InvalidLen { len: 5, base: Z85 })
+        );
+    }
+
+    #[test]
+    fn test4() {
+        // inputs that aren't whole quanta of 4 bytes
+        ["a", "abc", "hello", "hello world"]
+            .into_iter()
+            .for_each(|input| {
+                assert_eq!(
+                    Encoder::z85().try_encode(input),
+                    Err(EncodeError::InvalidLen {
+                        len: input.len(),
+                        base: Z85
+                    })
+                );
+            });
+
+        assert_eq!(
+            Encoder::z85()
+                .try_encode_bytes([0x86, 0x4f, 0xd2, 0x6f])
+                .unwrap(),
+            "Hello"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "can not be encoded")]
+    fn test5() {
+        // never an empty String for a non empty input
+        Encoder::z85().encode("abc");
+    }
+}
+
+mod decoder {
+    use makura::{BASE16, BASE45, Bases, DecodeError, Decoder, Z85};
+
+    #[test]
+    fn test0() {
+        let input = "HelloWorld";
+        let output = [0x86, 0x4f, 0xd2, 0x6f, 0xb5, 0x59, 0xf7, 0x5b];
+
+        assert_eq!(Decoder::decode(input, Z85).unwrap().into_bytes(), output);
+    }
+
+    #[test]
+    fn test1() {
+        let input = "HelloWorl";
+
+        assert_eq!(
+            Decoder::decode(input, Z85).unwrap_err(),
+            DecodeError::InvalidLen { len: 9, base: Z85 }
+        );
+    }
+
+    #[test]
+    fn test2() {
+        let input = "Hello\"orld";
+
+        assert_eq!(
+            Decoder::decode(input, Z85).unwrap_err(),
+            DecodeError::InvalidChar {
+                char: '"',
+                base: Z85
+            }
+        );
+    }
+
+    #[test]
+    fn test3() {
+        // bigger than u32::MAX
+        let input = "%nSc1";
+
+        assert_eq!(
+            Decoder::decode(input, Z85).unwrap_err(),
+            DecodeError::Overflow { base: Z85 }
+        );
+    }
+
+    #[test]
+    fn test4() {
+        // '=' is a z85 char, not padding
+        let input = "0000=";
+
+        assert_eq!(
+            Decoder::decode(input, Z85).unwrap().into_bytes(),
+            [0, 0, 0, 66]
+        );
+    }
+
+    #[test]
+    fn test5() {
+        // a curve public key, only valid as z85
+        let input = "rq:rM>}U?@Lns47E1%kR.o@n%FcmmsL/@{H8]yf7";
+
+        assert_eq!(Bases::deduce_default(input), Ok(Z85));
+        // a trailing '=' isn't taken for padding
+        assert_eq!(Bases::deduce_default("0000="), Ok(Z85));
+    }
+
+    #[test]
+    fn test7() {
+        // z85's alphabet holds every base16 and base45 char, z85 is only deduced when they don't match
+        [("48656C6C6F", BASE16), ("CO8P0", BASE45)]
+            .into_iter()
+            .for_each(|(input, base)| {
+                let mut bases = Bases::from(&[base, Z85][..]);
+                assert_eq!(bases.deduce_encoding(input), Ok(base));
+
+                let mut bases = Bases::from(&[base, Z85][..]);
+                assert_eq!(bases.deduce_sorted(input), Ok(base));
+            });
+        assert_eq!(
+            Bases::from(&[BASE16, Z85][..]).deduce_encoding("48656c6c6f"),
+            Ok(Z85)
+        );
+    }
+
+    #[test]
+    fn test6() {
+        let input = "Hello\nWorld\n";
+        let dec = Decoder::new().with_skip_whitespace(true);
+
+        assert_eq!(
+            dec.apply(input, Z85).unwrap().into_bytes(),
+            [0x86, 0x4f, 0xd2, 0x6f, 0xb5, 0x59, 0xf7, 0x5b]
+        );
+    }
+
+    #[test]
+    fn test8() {
+        // the pad of a non paddable base is an invalid char, wherever it is
+        ["00~00", "0000~"].into_iter().for_each(|input| {
+            assert_eq!(
+                Decoder::decode(input, Z85).unwrap_err(),
+                DecodeError::InvalidChar {
+                    char: '~',
+                    base: Z85
+                }
+            );
+        });
+    }
+}
+
+mod properties {
+    use makura::{Decoder, Encoder, Z85};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn round_trip(input in proptest::collection::vec(any::<[u8; 4]>(), 0..32)) {
+            let input = input.concat();
+            let encoded = Encoder::z85().encode_bytes(&input);
+
+            prop_assert_eq!(encoded.len(), Encoder::z85().encoded_len(input.len()));
+            prop_assert_eq!(Decoder::decode(&encoded, Z85).unwrap().into_bytes(), input);
+        }
+    }
+}
@@ -0,0 +1,177 @@
+mod encoder {
+    use makura::{EncodeError, Encoder, ZBASE32, zbase32};
+
+    #[test]
+    fn test0() {
+        // the z-base-32 spec test vectors
+        [
+            (&[0x00][..], 1, "y"),
+            (&[0x80], 1, "o"),
+            (&[0x40], 2, "e"),
+            (&[0xc0], 2, "a"),
+            (&[0x00, 0x00], 10, "yy"),
+            (&[0x80, 0x80], 10, "on"),
+            (&[0x8b, 0x88, 0x80], 20, "tqre"),
+            (&[0xf0, 0xbf, 0xc7], 24, "6n9hq"),
+            (&[0xd4, 0x7a, 0x04], 24, "4t7ye"),
+            (&[0xf5, 0x57, 0xbb, 0x0c], 30, "6im5sd"),
+        ]
+        .into_iter()
+        .for_each(|(input, bits, output)| {
+            assert_eq!(zbase32::encode_bits(input, bits).unwrap(), output);
+        });
+    }
+
+    #[test]
+    fn test1() {
+        // the bits past bits are left out
+        assert_eq!(zbase32::encode_bits([0xff], 1).unwrap(), "o");
+        assert_eq!(zbase32::encode_bits([0xff, 0xff], 0).unwrap(), "");
+    }
+
+    #[test]
+    fn test2() {
+        let input = [0xf0, 0xbf, 0xc7];
+        let output = "6n9hq";
+        let enc = Encoder::zbase32();
+
+        assert_eq!(enc.encode_bytes(input), output);
+    }
+
+    #[test]
+    fn test3() {
+        let input = "hello";
+        let output = "pb1sa5dx";
+        let enc = Encoder::zbase32();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test4() {
+        // more bits than value holds
+        assert_eq!(
+            zbase32::encode_bits([0xff], 9).unwrap_err(),
+            EncodeError::InvalidLen {
+                len: 1,
+                base: ZBASE32
+            }
+        );
+        assert!(zbase32::encode_bits([], 1).is_err());
+    }
+}
+
+mod decoder {
+    use makura::{DecodeError, Decoder, ZBASE32, zbase32};
+
+    #[test]
+    fn test0() {
+        [
+            ("y", 1, &[0x00][..]),
+            ("o", 1, &[0x80]),
+            ("e", 2, &[0x40]),
+            ("a", 2, &[0xc0]),
+            ("yy", 10, &[0x00, 0x00]),
+            ("on", 10, &[0x80, 0x80]),
+            ("tqre", 20, &[0x8b, 0x88, 0x80]),
+            ("6n9hq", 24, &[0xf0, 0xbf, 0xc7]),
+            ("4t7ye", 24, &[0xd4, 0x7a, 0x04]),
+            ("6im5sd", 30, &[0xf5, 0x57, 0xbb, 0x0c]),
+        ]
+        .into_iter()
+        .for_each(|(input, bits, output)| {
+            assert_eq!(zbase32::decode_bits(input, bits).unwrap(), output);
+        });
+    }
+
+    #[test]
+    fn test1() {
+        // the bits of the last char past bits are dropped
+        assert_eq!(zbase32::decode_bits("9", 1).unwrap(), [0x80]);
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(
+            zbase32::decode_bits("tqre", 24),
+            Err(DecodeError::InvalidLen {
+                len: 4,
+                base: ZBASE32
+            })
+        );
+        assert_eq!(
+            zbase32::decode_bits("tqrv", 20),
+            Err(DecodeError::InvalidChar {
+                char: 'v',
+                base: ZBASE32
+            })
+        );
+    }
+
+    #[test]
+    fn test3() {
+        let input = "pb1sa5dx";
+
+        assert_eq!(
+            Decoder::decode(input, ZBASE32)
+                .unwrap()
+                .into_utf8()
+                .unwrap(),
+            "hello"
+        );
+    }
+
+    #[test]
+    fn test4() {
+        let input = "pb1sa5";
+
+        assert_eq!(
+            Decoder::decode(input, ZBASE32).unwrap_err(),
+            DecodeError::InvalidLen {
+                len: 6,
+                base: ZBASE32
+            }
+        );
+    }
+
+    #[test]
+    fn test5() {
+        // the pad of a non paddable base is an invalid char, wherever it is
+        ["yy=y", "yyy="].into_iter().for_each(|input| {
+            assert_eq!(
+                Decoder::decode(input, ZBASE32).unwrap_err(),
+                DecodeError::InvalidChar {
+                    char: '=',
+                    base: ZBASE32
+                }
+            );
+        });
+    }
+}
+
+mod properties {
+    use makura::{Decoder, Encoder, ZBASE32, zbase32};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn round_trip(input in any::<Vec<u8>>()) {
+            let encoded = Encoder::zbase32().encode_bytes(&input);
+            prop_assert_eq!(Decoder::decode(&encoded, ZBASE32).unwrap().into_bytes(), input);
+        }
+
+        #[test]
+        fn round_trip_bits(input in any::<Vec<u8>>(), bits in any::<usize>()) {
+            let bits = bits % (input.len() * 8 + 1);
+            let decoded = zbase32::decode_bits(zbase32::encode_bits(&input, bits).unwrap(), bits).unwrap();
+
+            prop_assert_eq!(decoded.len(), bits.div_ceil(8));
+            // the bits past bits are 0s
+            let mut masked = input[..bits.div_ceil(8)].to_vec();
+            if bits % 8 != 0 {
+                *masked.last_mut().unwrap() &= 0xff << (8 - bits % 8);
+            }
+            prop_assert_eq!(decoded, masked);
+        }
+    }
+}
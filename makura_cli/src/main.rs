@@ -1,4 +1,4 @@
-use std::io::{BufRead, IsTerminal, Write, stdin};
+use std::io::{BufRead, IsTerminal, Read, Write, stdin};
 use std::path::PathBuf;
 
 use clap::{Args, Parser};
@@ -23,9 +23,9 @@ fn main() -> Result<(), CLIError> {
 #[derive(Debug)]
 enum CLIError {
     CouldNotOpenFileForReading,
-    NeedABaseToEncode,
     DecodeFailed,
     DeduceFailed,
+    EncodeFailed,
     IOError,
 }
 
@@ -44,7 +44,7 @@ impl From<CLIError> for std::io::Error {
 }
 
 impl From<std::io::Error> for CLIError {
-    fn from(value: std::io::Error) -> Self {
+    fn from(_value: std::io::Error) -> Self {
         Self::IOError
     }
 }
@@ -89,7 +89,18 @@ fn read_file_str(f: std::path::PathBuf) -> std::io::Result<String> {
 // TODO return a Result
 // handle lines() errors
 fn pipe_input() -> String {
-    stdin().lock().lines().flatten().collect::<String>()
+    stdin()
+        .lock()
+        .lines()
+        .map_while(Result::ok)
+        .collect::<String>()
+}
+
+fn pipe_input_bytes() -> std::io::Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    stdin().lock().read_to_end(&mut bytes)?;
+
+    Ok(bytes)
 }
 
 fn extract_input(f: Option<PathBuf>, d: Option<String>) -> Result<String, CLIError> {
@@ -107,7 +118,22 @@ fn extract_input(f: Option<PathBuf>, d: Option<String>) -> Result<String, CLIErr
     } else if let Some(d) = d {
         Ok(d)
     } else {
-        return Err(CLIError::CouldNotOpenFileForReading);
+        Err(CLIError::CouldNotOpenFileForReading)
+    }
+}
+
+// same as extract_input, but doesn't require the input to be valid utf8
+fn extract_input_bytes(f: Option<PathBuf>, d: Option<String>) -> Result<Vec<u8>, CLIError> {
+    if !stdin().is_terminal() {
+        return Ok(pipe_input_bytes()?);
+    }
+
+    if let Some(f) = f {
+        read_file(f).map_err(|_| CLIError::CouldNotOpenFileForReading)
+    } else if let Some(d) = d {
+        Ok(d.into_bytes())
+    } else {
+        Err(CLIError::CouldNotOpenFileForReading)
     }
 }
 
@@ -118,11 +144,11 @@ impl CommandLauncher for Decode {
         if let Some(base) = self.base {
             Decoder::decode(input, base)
                 .map(|res| res.into_utf8().unwrap())
-                .map_err(|e| CLIError::DecodeFailed)
+                .map_err(|_| CLIError::DecodeFailed)
         } else {
             Decoder::decode_deduce(input)
                 .map(|res| res.into_utf8().unwrap())
-                .map_err(|e| CLIError::DecodeFailed)
+                .map_err(|_| CLIError::DecodeFailed)
         }
     }
 }
@@ -144,19 +170,20 @@ struct Encode {
 
 impl CommandLauncher for Encode {
     fn run(self) -> Result<String, CLIError> {
-        let input = extract_input(self.file, self.input)?;
+        let input = extract_input_bytes(self.file, self.input)?;
         // default to base64 if no base was specified
         let base = self.base.unwrap_or_default();
-        // let Some(base) = self.base else {
-        //     return Err(CLIError::NeedABaseToEncode);
-        // };
 
-        Ok(<Base as Into<Encoder>>::into(base).encode(input))
-        // .map(|res| res.into_utf8().unwrap())
-        // .map_err(|e| CLIError::DecodeFailed)
+        encode(base.into(), &input)
     }
 }
 
+// encodes input, failing on the inputs that enc can not encode, e.g., z85 with a len that isn't a multiple of 4
+fn encode(enc: Encoder, input: &[u8]) -> Result<String, CLIError> {
+    enc.try_encode_bytes(input)
+        .map_err(|_| CLIError::EncodeFailed)
+}
+
 #[derive(Debug, Args)]
 #[command(alias = "ddc")]
 struct Deduce {
@@ -172,7 +199,7 @@ impl CommandLauncher for Deduce {
 
         Bases::default()
             .deduce_encoding(&input)
-            .map_err(|e| CLIError::DeduceFailed)
+            .map_err(|_| CLIError::DeduceFailed)
             .map(|b| b.to_string())
     }
 }
@@ -212,8 +239,7 @@ impl CommandLauncher for Recast {
                 .map_err(|_| CLIError::DecodeFailed);
         }
         let input = input.unwrap().into_utf8().unwrap();
-        let enc: Encoder = self.dest.into();
 
-        Ok(enc.encode(input))
+        encode(self.dest.into(), input.as_bytes())
     }
 }